@@ -1,13 +1,15 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod shift_schedule;
-mod ai_provider;
 
-use scheduler::{JsonStorage, Storage, Schedule, ScheduleChange, Task};
-use chrono::{NaiveDate, Local, TimeZone, NaiveTime};
+use scheduler::{
+    resolve_local, Config, ConflictPolicy, EncryptedStorage, JsonStorage, Storage, Schedule, ScheduleChange, Task, TaskConflict,
+};
+use chrono::{NaiveDate, Local, NaiveTime};
 use serde::{Deserialize, Serialize};
 use shift_schedule::shift_schedule;
-use ai_provider::{AiProvider, AiConfig};
+use scheduler::ai::{self, AiError, AiProvider, AiConfig, TaskSuggestion};
+use scheduler::storage::MAX_RANGE_DAYS;
 
 // Simple DTO for creating tasks from frontend
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,38 +20,90 @@ struct TaskInput {
     tags: Vec<String>,
     notes: Option<String>,
     pomodoro_duration: Option<u32>, // Optional: custom pomodoro duration in minutes
+    long_break_every: Option<u32>, // Optional: long break after every N pomodoros (default 4)
 }
 
-// Task suggestion from Claude
-#[derive(Debug, Serialize, Deserialize)]
-struct TaskSuggestion {
-    suggested_title: String,
-    suggested_start_time: String,
-    suggested_end_time: String,
-    tags: Vec<String>,
-    notes: Option<String>,
-    pomodoro_duration: u32,
-    reasoning: String,
+// Error returned by the add/create commands; conflicts carry both tasks
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum AddTaskError {
+    Conflict { message: String, conflict: TaskConflict },
+    Invalid { message: String },
+}
+
+impl From<String> for AddTaskError {
+    fn from(message: String) -> Self {
+        AddTaskError::Invalid { message }
+    }
+}
+
+impl From<TaskConflict> for AddTaskError {
+    fn from(conflict: TaskConflict) -> Self {
+        AddTaskError::Conflict { message: conflict.message(), conflict }
+    }
+}
+
+// Error returned by the AI commands; `kind` tells the UI what went wrong
+// (e.g. offer the install guide only for "not_installed")
+#[derive(Debug, Serialize)]
+struct AiCommandError {
+    kind: &'static str,
+    message: String,
+}
+
+impl From<AiError> for AiCommandError {
+    fn from(error: AiError) -> Self {
+        AiCommandError { kind: error.kind(), message: error.to_string() }
+    }
+}
+
+impl From<String> for AiCommandError {
+    fn from(message: String) -> Self {
+        AiCommandError { kind: "invalid", message }
+    }
+}
+
+impl TaskInput {
+    fn into_task(self, date: NaiveDate) -> Result<Task, String> {
+        let start = parse_time_on_date(date, &self.start_time)?;
+        let end = parse_time_on_date(date, &self.end_time)?;
+        let mut task = Task::new(self.title, start, end);
+        task.tags = self.tags;
+        task.notes = self.notes;
+        task.custom_pomodoro_duration = self.pomodoro_duration;
+        task.custom_long_break_every = self.long_break_every;
+        Ok(task)
+    }
+}
+
+// Tag with how often it was used, for autocomplete
+#[derive(Debug, Serialize)]
+struct TagCount {
+    tag: String,
+    count: usize,
+}
+
+/// Storage for every command, encrypted when `SCHEDULER_PASSPHRASE` is set (same as the CLI)
+pub(crate) fn open_storage() -> Result<Box<dyn Storage>, String> {
+    // Same file format as the CLI writes, so both read each other's schedules
+    let format = Config::load().unwrap_or_default().storage_format;
+    let storage = JsonStorage::new().map_err(|e| e.to_string())?.with_format(format);
+    EncryptedStorage::from_env(storage).map_err(|e| e.to_string())
 }
 
 fn parse_time_on_date(date: NaiveDate, time_str: &str) -> Result<chrono::DateTime<Local>, String> {
     let time = NaiveTime::parse_from_str(time_str, "%H:%M")
         .map_err(|e| format!("Invalid time format: {}", e))?;
-    let datetime = date.and_time(time);
-    Local.from_local_datetime(&datetime)
-        .single()
-        .ok_or_else(|| "Invalid datetime".to_string())
+    Ok(resolve_local(date.and_time(time)))
 }
 
 // Get schedule for a specific date
 #[tauri::command]
 fn get_schedule(date: String) -> Result<Option<Schedule>, String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+    let storage = open_storage()?;
     let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date format: {}", e))?;
-    let datetime = Local.from_local_datetime(&parsed_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
-        .ok_or("Invalid datetime".to_string())?;
+    let datetime = resolve_local(parsed_date.and_hms_opt(0, 0, 0).unwrap());
 
     let mut schedule = storage.load_schedule(datetime).map_err(|e| e.to_string())?;
 
@@ -63,10 +117,22 @@ fn get_schedule(date: String) -> Result<Option<Schedule>, String> {
     Ok(schedule)
 }
 
+// Get schedules for an inclusive date range (e.g. a week or calendar view)
+#[tauri::command]
+fn get_schedules_range(from: String, to: String) -> Result<Vec<Schedule>, String> {
+    let storage = open_storage()?;
+    let from = NaiveDate::parse_from_str(&from, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date format: {}", e))?;
+    let to = NaiveDate::parse_from_str(&to, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date format: {}", e))?;
+
+    storage.load_range(from, to).map_err(|e| e.to_string())
+}
+
 // Get today's schedule
 #[tauri::command]
 fn get_today_schedule() -> Result<Option<Schedule>, String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+    let storage = open_storage()?;
     let mut schedule = storage.load_today().map_err(|e| e.to_string())?;
 
     // 통계 계산
@@ -81,65 +147,74 @@ fn get_today_schedule() -> Result<Option<Schedule>, String> {
 
 // Create a new schedule
 #[tauri::command]
-fn create_schedule(date: String, tasks: Vec<TaskInput>) -> Result<(), String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+fn create_schedule(
+    date: String,
+    tasks: Vec<TaskInput>,
+    conflict_policy: Option<ConflictPolicy>,
+) -> Result<(), AddTaskError> {
+    let storage = open_storage()?;
     let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date format: {}", e))?;
-    let datetime = Local.from_local_datetime(&parsed_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
-        .ok_or("Invalid datetime".to_string())?;
 
-    let mut schedule = Schedule::new(datetime);
+    let schedule = build_schedule(parsed_date, tasks, conflict_policy.unwrap_or_default())?;
+
+    storage.save_schedule(&schedule).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Build a new schedule, applying the conflict policy to each task in order
+fn build_schedule(
+    date: NaiveDate,
+    tasks: Vec<TaskInput>,
+    policy: ConflictPolicy,
+) -> Result<Schedule, AddTaskError> {
+    let mut schedule = Schedule::new(resolve_local(date.and_hms_opt(0, 0, 0).unwrap()));
 
-    // Convert TaskInput to Task
     for task_input in tasks {
-        let start = parse_time_on_date(parsed_date, &task_input.start_time)?;
-        let end = parse_time_on_date(parsed_date, &task_input.end_time)?;
-        let mut task = Task::new(task_input.title, start, end);
-        task.tags = task_input.tags;
-        task.notes = task_input.notes;
-        task.custom_pomodoro_duration = task_input.pomodoro_duration;
-        schedule.tasks.push(task);
+        let task = task_input.into_task(date)?;
+        schedule.add_task_with_policy(task, policy)?;
     }
 
-    storage.save_schedule(&schedule).map_err(|e| e.to_string())
+    Ok(schedule)
 }
 
 // Add a task to existing schedule
 #[tauri::command]
-fn add_task(date: String, task_input: TaskInput) -> Result<(), String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+fn add_task(
+    date: String,
+    task_input: TaskInput,
+    conflict_policy: Option<ConflictPolicy>,
+) -> Result<(), AddTaskError> {
+    let storage = open_storage()?;
     let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date format: {}", e))?;
-    let datetime = Local.from_local_datetime(&parsed_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
-        .ok_or("Invalid datetime".to_string())?;
+    let datetime = resolve_local(parsed_date.and_hms_opt(0, 0, 0).unwrap());
 
     let mut schedule = storage.load_schedule(datetime)
         .map_err(|e| e.to_string())?
         .unwrap_or_else(|| Schedule::new(datetime));
 
-    // Convert TaskInput to Task
-    let start = parse_time_on_date(parsed_date, &task_input.start_time)?;
-    let end = parse_time_on_date(parsed_date, &task_input.end_time)?;
-    let mut task = Task::new(task_input.title, start, end);
-    task.tags = task_input.tags;
-    task.notes = task_input.notes;
-    task.custom_pomodoro_duration = task_input.pomodoro_duration;
+    let task = task_input.into_task(parsed_date)?;
+    let task_id = task.id.clone();
+    schedule.add_task_with_policy(task, conflict_policy.unwrap_or_default())?;
 
-    schedule.tasks.push(task);
-    storage.save_schedule(&schedule).map_err(|e| e.to_string())
+    // Record creation in change history (with the time after any shift)
+    let added = schedule.find_task(&task_id).unwrap();
+    let time_range = format!("{}-{}", added.start_time.format("%H:%M"), added.end_time.format("%H:%M"));
+    let change = ScheduleChange::task_created(added.title.clone(), time_range);
+    schedule.add_change(change);
+
+    storage.save_schedule(&schedule).map_err(|e| e.to_string())?;
+    Ok(())
 }
 
 // Update a task - simplified version
 #[tauri::command]
 fn update_task(date: String, index: usize, task_input: TaskInput) -> Result<(), String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+    let storage = open_storage()?;
     let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date format: {}", e))?;
-    let datetime = Local.from_local_datetime(&parsed_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
-        .ok_or("Invalid datetime".to_string())?;
+    let datetime = resolve_local(parsed_date.and_hms_opt(0, 0, 0).unwrap());
 
     let mut schedule = storage.load_schedule(datetime)
         .map_err(|e| e.to_string())?
@@ -172,18 +247,16 @@ fn update_task(date: String, index: usize, task_input: TaskInput) -> Result<(),
         schedule.add_change(change);
     }
 
-    storage.save_schedule(&schedule).map_err(|e| e.to_string())
+    storage.save_schedule(&schedule).map(|_| ()).map_err(|e| e.to_string())
 }
 
 // Delete a task
 #[tauri::command]
 fn delete_task(date: String, index: usize) -> Result<(), String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+    let storage = open_storage()?;
     let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date format: {}", e))?;
-    let datetime = Local.from_local_datetime(&parsed_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
-        .ok_or("Invalid datetime".to_string())?;
+    let datetime = resolve_local(parsed_date.and_hms_opt(0, 0, 0).unwrap());
 
     let mut schedule = storage.load_schedule(datetime)
         .map_err(|e| e.to_string())?
@@ -193,19 +266,19 @@ fn delete_task(date: String, index: usize) -> Result<(), String> {
         return Err("Task index out of bounds".to_string());
     }
 
-    schedule.tasks.remove(index);
-    storage.save_schedule(&schedule).map_err(|e| e.to_string())
+    // Recurring instances removed here are not recreated for this day
+    let id = schedule.tasks[index].id.clone();
+    schedule.remove_task(&id);
+    storage.save_schedule(&schedule).map(|_| ()).map_err(|e| e.to_string())
 }
 
 // Start a task
 #[tauri::command]
 fn start_task(date: String, index: usize) -> Result<(), String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+    let storage = open_storage()?;
     let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date format: {}", e))?;
-    let datetime = Local.from_local_datetime(&parsed_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
-        .ok_or("Invalid datetime".to_string())?;
+    let datetime = resolve_local(parsed_date.and_hms_opt(0, 0, 0).unwrap());
 
     let mut schedule = storage.load_schedule(datetime)
         .map_err(|e| e.to_string())?
@@ -216,18 +289,16 @@ fn start_task(date: String, index: usize) -> Result<(), String> {
     }
 
     schedule.tasks[index].start();
-    storage.save_schedule(&schedule).map_err(|e| e.to_string())
+    storage.save_schedule(&schedule).map(|_| ()).map_err(|e| e.to_string())
 }
 
 // Pause a task
 #[tauri::command]
 fn pause_task(date: String, index: usize) -> Result<(), String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+    let storage = open_storage()?;
     let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date format: {}", e))?;
-    let datetime = Local.from_local_datetime(&parsed_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
-        .ok_or("Invalid datetime".to_string())?;
+    let datetime = resolve_local(parsed_date.and_hms_opt(0, 0, 0).unwrap());
 
     let mut schedule = storage.load_schedule(datetime)
         .map_err(|e| e.to_string())?
@@ -238,18 +309,16 @@ fn pause_task(date: String, index: usize) -> Result<(), String> {
     }
 
     schedule.tasks[index].pause();
-    storage.save_schedule(&schedule).map_err(|e| e.to_string())
+    storage.save_schedule(&schedule).map(|_| ()).map_err(|e| e.to_string())
 }
 
 // Resume a task
 #[tauri::command]
 fn resume_task(date: String, index: usize) -> Result<(), String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+    let storage = open_storage()?;
     let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date format: {}", e))?;
-    let datetime = Local.from_local_datetime(&parsed_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
-        .ok_or("Invalid datetime".to_string())?;
+    let datetime = resolve_local(parsed_date.and_hms_opt(0, 0, 0).unwrap());
 
     let mut schedule = storage.load_schedule(datetime)
         .map_err(|e| e.to_string())?
@@ -260,18 +329,16 @@ fn resume_task(date: String, index: usize) -> Result<(), String> {
     }
 
     schedule.tasks[index].resume();
-    storage.save_schedule(&schedule).map_err(|e| e.to_string())
+    storage.save_schedule(&schedule).map(|_| ()).map_err(|e| e.to_string())
 }
 
 // Complete a task with focus score
 #[tauri::command]
-fn complete_task(date: String, index: usize, _focus_score: u8) -> Result<(), String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+fn complete_task(date: String, index: usize, focus_score: u8) -> Result<(), String> {
+    let storage = open_storage()?;
     let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date format: {}", e))?;
-    let datetime = Local.from_local_datetime(&parsed_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
-        .ok_or("Invalid datetime".to_string())?;
+    let datetime = resolve_local(parsed_date.and_hms_opt(0, 0, 0).unwrap());
 
     let mut schedule = storage.load_schedule(datetime)
         .map_err(|e| e.to_string())?
@@ -283,8 +350,16 @@ fn complete_task(date: String, index: usize, _focus_score: u8) -> Result<(), Str
 
     // Use the complete() method from Task
     schedule.tasks[index].complete();
+    schedule.tasks[index].focus_score = Some(focus_score.clamp(
+        *scheduler::models::FOCUS_SCORE_RANGE.start(),
+        *scheduler::models::FOCUS_SCORE_RANGE.end(),
+    ));
+
+    if Config::load().map(|config| config.auto_start_next).unwrap_or(false) {
+        schedule.start_next_task();
+    }
 
-    storage.save_schedule(&schedule).map_err(|e| e.to_string())
+    storage.save_schedule(&schedule).map(|_| ()).map_err(|e| e.to_string())
 }
 
 // Get weekly summary
@@ -309,35 +384,26 @@ fn greet(name: &str) -> String {
 
 // Check AI provider availability
 #[tauri::command]
-fn check_ai_provider(provider: String) -> Result<String, String> {
-    let ai_provider = match provider.as_str() {
-        "copilot" => AiProvider::Copilot,
-        "claude" => AiProvider::Claude,
-        other => return Err(format!("Unknown AI provider: {}", other)),
-    };
+fn check_ai_provider(provider: String) -> Result<String, AiCommandError> {
+    let ai_provider = AiProvider::from_name(&provider)?;
 
-    AiConfig::verify_cli(&ai_provider, None)
+    Ok(AiConfig::verify_cli(&ai_provider, None)?)
 }
 
 // Get AI provider installation guide
 #[tauri::command]
 fn get_ai_installation_guide(provider: String) -> Result<String, String> {
-    let ai_provider = match provider.as_str() {
-        "copilot" => AiProvider::Copilot,
-        "claude" => AiProvider::Claude,
-        other => return Err(format!("Unknown AI provider: {}", other)),
-    };
+    let ai_provider = AiProvider::from_name(&provider)?;
 
     Ok(AiConfig::get_installation_guide(&ai_provider))
 }
 
 // AI Provider integration - Ask AI for advice (supports multiple providers)
 #[tauri::command]
-fn ask_ai(prompt: String, provider: Option<String>) -> Result<String, String> {
+fn ask_ai(prompt: String, provider: Option<String>) -> Result<String, AiCommandError> {
     let ai_provider = match provider.as_deref() {
-        Some("copilot") => AiProvider::Copilot,
-        Some("claude") | None => AiProvider::Claude, // Default to Claude
-        Some(other) => return Err(format!("Unknown AI provider: {}", other)),
+        Some(name) => AiProvider::from_name(name)?,
+        None => saved_ai_provider(), // Fall back to the saved preference
     };
 
     let config = AiConfig {
@@ -346,32 +412,55 @@ fn ask_ai(prompt: String, provider: Option<String>) -> Result<String, String> {
         copilot_path: None,
     };
 
-    config.ask(&prompt)
+    Ok(config.ask(&prompt)?)
+}
+
+// Saved AI provider preference (Claude if unset or unreadable)
+fn saved_ai_provider() -> AiProvider {
+    Config::load()
+        .ok()
+        .and_then(|config| config.ai_provider)
+        .and_then(|name| AiProvider::from_name(&name).ok())
+        .unwrap_or_default()
+}
+
+// Remember the AI provider used when a command doesn't specify one
+#[tauri::command]
+fn set_ai_provider(provider: String) -> Result<(), String> {
+    let ai_provider = AiProvider::from_name(&provider)?;
+
+    let mut config = Config::load().map_err(|e| e.to_string())?;
+    config.ai_provider = Some(ai_provider.name().to_string());
+    config.save().map_err(|e| e.to_string())
+}
+
+// Get the saved AI provider preference
+#[tauri::command]
+fn get_ai_provider() -> String {
+    saved_ai_provider().name().to_string()
 }
 
 // Claude Code integration - Ask Claude for advice (deprecated, use ask_ai instead)
 // Kept for backwards compatibility
 #[tauri::command]
-fn ask_claude(prompt: String) -> Result<String, String> {
+fn ask_claude(prompt: String) -> Result<String, AiCommandError> {
     ask_ai(prompt, Some("claude".to_string()))
 }
 
 // Evaluate today's schedule with Claude
 #[tauri::command]
-fn evaluate_schedule(date: String) -> Result<String, String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+fn evaluate_schedule(date: String) -> Result<String, AiCommandError> {
+    let storage = open_storage()?;
     let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date format: {}", e))?;
-    let datetime = Local.from_local_datetime(&parsed_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
-        .ok_or("Invalid datetime".to_string())?;
+    let datetime = resolve_local(parsed_date.and_hms_opt(0, 0, 0).unwrap());
 
     let schedule = storage.load_schedule(datetime)
         .map_err(|e| e.to_string())?
         .ok_or_else(|| "No schedule found for this date".to_string())?;
 
     if schedule.tasks.is_empty() {
-        return Err("No tasks in schedule".to_string());
+        return Err("No tasks in schedule".to_string().into());
     }
 
     // Build schedule description for Claude
@@ -427,7 +516,7 @@ fn send_notification(title: String, body: String) -> Result<(), String> {
 
 // Get advice for a new task (supports multiple AI providers)
 #[tauri::command]
-fn get_task_advice(title: String, duration_minutes: i64, provider: Option<String>) -> Result<String, String> {
+fn get_task_advice(title: String, duration_minutes: i64, provider: Option<String>) -> Result<String, AiCommandError> {
     let prompt = format!(
         "작업 제목: \"{}\"\n예상 소요 시간: {}분\n\n\
         다음 심리학 원리와 시간 관리 방법론을 기반으로 조언해주세요:\n\n\
@@ -451,98 +540,72 @@ fn get_task_advice(title: String, duration_minutes: i64, provider: Option<String
 
 // Auto-complete task creation with AI (accepts natural language, supports multiple providers)
 #[tauri::command]
-fn suggest_task_completion(date: String, user_input: String, provider: Option<String>) -> Result<TaskSuggestion, String> {
-    let storage = JsonStorage::new().map_err(|e| e.to_string())?;
+fn suggest_task_completion(date: String, user_input: String, provider: Option<String>) -> Result<TaskSuggestion, AiCommandError> {
+    let storage = open_storage()?;
     let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date format: {}", e))?;
-    let datetime = Local.from_local_datetime(&parsed_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
-        .ok_or("Invalid datetime".to_string())?;
+    let datetime = resolve_local(parsed_date.and_hms_opt(0, 0, 0).unwrap());
 
     let schedule = storage.load_schedule(datetime)
         .map_err(|e| e.to_string())?
         .unwrap_or_else(|| Schedule::new(datetime));
 
-    // Build existing schedule description
-    let mut schedule_desc = String::new();
-    if schedule.tasks.is_empty() {
-        schedule_desc.push_str("오늘은 아직 스케줄이 비어있습니다.\n");
-    } else {
-        schedule_desc.push_str("오늘 기존 스케줄:\n");
-        for (i, task) in schedule.tasks.iter().enumerate() {
-            let start = task.start_time.format("%H:%M").to_string();
-            let end = task.end_time.format("%H:%M").to_string();
-            schedule_desc.push_str(&format!(
-                "{}. {} ({}-{}, {}분)\n",
-                i + 1,
-                task.title,
-                start,
-                end,
-                task.estimated_duration_minutes
-            ));
-        }
-    }
-
-    let prompt = format!(
-        "{}\n\
-        사용자 요청: \"{}\"\n\n\
-        IMPORTANT: You must respond with ONLY valid JSON. No explanation, no markdown, just raw JSON.\n\n\
-        ## 심리학 원리 기반 작업 생성:\n\
-        다음 원리들을 고려하여 작업을 제안하세요:\n\
-        1. **자이가르닉 효과**: 작업 제목을 구체적이고 명확하게 정의하세요. 모호한 작업명은 완료 동기를 낮춥니다.\n\
-        2. **파킨슨의 법칙**: 예상 시간을 적절히 타이트하게 설정하세요. 너무 여유로운 시간은 비효율을 초래합니다.\n\
-        3. **포모도로 기법**: 작업을 25-90분 단위로 분할 가능하도록 pomodoro_duration을 선택하세요.\n\
-        4. **희소성 원리**: 하루 24시간이라는 한정된 자원을 고려하여 시간을 배분하세요.\n\n\
-        Output JSON structure:\n\
-        {{\n\
-          \"suggested_title\": \"task title (구체적이고 명확하게)\",\n\
-          \"suggested_start_time\": \"HH:MM\",\n\
-          \"suggested_end_time\": \"HH:MM\",\n\
-          \"tags\": [\"tag1\", \"tag2\"],\n\
-          \"notes\": \"tips or notes for this task\",\n\
-          \"pomodoro_duration\": 25,\n\
-          \"reasoning\": \"why you made these suggestions (심리학 원리 언급)\"\n\
-        }}\n\n\
-        Rules:\n\
-        1. suggested_title: 구체적으로 추출 (e.g., \"조깅 30분\" → \"30분 조깅 (XX 코스)\")\n\
-        2. suggested_start_time and suggested_end_time: 기존 스케줄과 충돌 회피\n\
-           - \"아침\" → 07:00-09:00\n\
-           - \"오전\" → 09:00-12:00\n\
-           - \"오후\" → 13:00-18:00\n\
-           - \"저녁\" → 18:00-21:00\n\
-        3. Duration: 지정된 경우 그대로, 아니면 파킨슨 법칙에 따라 타이트하게\n\
-        4. tags: 1-3개 (운동, 업무, 학습, 건강, 휴식 등)\n\
-        5. notes: 실행 팁 1-2문장 (자이가르닉 효과 활용 - 구체적 실행 방법 제시)\n\
-        6. pomodoro_duration: [5, 15, 25, 45, 60, 90] 중 작업 특성에 맞게 선택\n\
-        7. reasoning: 심리학 원리를 언급하며 1문장으로 설명\n\n\
-        Output ONLY the JSON object, nothing else:",
-        schedule_desc, user_input
-    );
+    let prompt = ai::task_suggestion_prompt(&schedule, &user_input);
 
     let response = ask_ai(prompt, provider)?;
 
     // Log response for debugging
     eprintln!("AI Response: {}", response);
 
-    // Try to extract JSON from response (handles both clean JSON and wrapped responses)
-    let json_str = if let Some(start) = response.find('{') {
-        if let Some(end) = response[start..].rfind('}') {
-            &response[start..start + end + 1]
-        } else {
-            return Err(format!("Incomplete JSON in response: {}", response));
-        }
-    } else {
-        return Err(format!("No JSON object found in response: {}", response));
-    };
+    // Handles clean JSON, prose around it, and several JSON blocks
+    Ok(ai::extract_json(&response)?)
+}
 
-    serde_json::from_str(json_str)
-        .map_err(|e| format!("Failed to parse JSON: {}. Extracted: {}", e, json_str))
+// Tags used in recent days, most frequent first (for autocomplete)
+// The lookback is capped at MAX_RANGE_DAYS so a large value can't scan years of history
+#[tauri::command]
+fn get_known_tags(days: Option<i64>) -> Result<Vec<TagCount>, String> {
+    let storage = open_storage()?;
+    let days = days.unwrap_or(30).clamp(1, MAX_RANGE_DAYS);
+    let tags = storage.known_tags(days).map_err(|e| e.to_string())?;
+    Ok(tags.into_iter().map(|(tag, count)| TagCount { tag, count }).collect())
+}
+
+// Suggest a whole day plan around the existing tasks
+#[tauri::command]
+fn suggest_day_plan(date: String, goals: String, provider: Option<String>) -> Result<Vec<TaskSuggestion>, AiCommandError> {
+    let storage = open_storage()?;
+    let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date format: {}", e))?;
+    let datetime = resolve_local(parsed_date.and_hms_opt(0, 0, 0).unwrap());
+
+    let schedule = storage.load_schedule(datetime)
+        .map_err(|e| e.to_string())?
+        .unwrap_or_else(|| Schedule::new(datetime));
+
+    let prompt = format!(
+        "{}\n\
+        오늘의 목표: \"{}\"\n\n\
+        IMPORTANT: You must respond with ONLY a valid JSON array. No explanation, no markdown, just raw JSON.\n\n\
+        {}\
+        Day plan rules:\n\
+        1. 기존 작업 사이의 빈 시간을 채우세요. 제안끼리도, 기존 작업과도 겹치면 안 됩니다.\n\
+        2. 목표를 이루는 데 필요한 작업만 제안하세요 (보통 2-6개).\n\
+        3. 시작 시간 순으로 정렬하세요.\n\n\
+        Output ONLY a JSON array of task objects, nothing else:",
+        ai::describe_schedule(&schedule), goals, ai::SUGGESTION_GUIDE
+    );
+
+    let response = ask_ai(prompt, provider)?;
+    Ok(ai::parse_day_plan(&response)?)
 }
 
 fn main() {
     use tauri::{CustomMenuItem, SystemTray, SystemTrayMenu, SystemTrayEvent};
     use tauri::Manager;
 
+    scheduler::i18n::set_locale(Config::load().unwrap_or_default().locale);
+
     // Create system tray menu
     let show = CustomMenuItem::new("show".to_string(), "Show Window");
     let hide = CustomMenuItem::new("hide".to_string(), "Hide Window");
@@ -586,6 +649,7 @@ fn main() {
         })
         .invoke_handler(tauri::generate_handler![
             get_schedule,
+            get_schedules_range,
             get_today_schedule,
             create_schedule,
             add_task,
@@ -600,10 +664,14 @@ fn main() {
             check_ai_provider,
             get_ai_installation_guide,
             ask_ai,
+            set_ai_provider,
+            get_ai_provider,
             ask_claude,
             evaluate_schedule,
             get_task_advice,
             suggest_task_completion,
+            suggest_day_plan,
+            get_known_tags,
             send_notification,
             shift_schedule,
             greet,
@@ -611,3 +679,34 @@ fn main() {
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(title: &str, start: &str, end: &str) -> TaskInput {
+        TaskInput {
+            title: title.to_string(),
+            start_time: start.to_string(),
+            end_time: end.to_string(),
+            tags: Vec::new(),
+            notes: None,
+            pomodoro_duration: None,
+            long_break_every: None,
+        }
+    }
+
+    #[test]
+    fn test_create_schedule_rejects_overlap() {
+        let date = NaiveDate::from_ymd_opt(2025, 11, 4).unwrap();
+        let tasks = vec![input("Standup", "09:00", "09:30"), input("Write", "09:15", "10:00")];
+
+        match build_schedule(date, tasks, ConflictPolicy::Reject) {
+            Err(AddTaskError::Conflict { message, .. }) => {
+                assert!(message.contains("Write"));
+                assert!(message.contains("Standup"));
+            }
+            other => panic!("expected a conflict, got {:?}", other.map(|s| s.tasks.len())),
+        }
+    }
+}
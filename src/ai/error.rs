@@ -0,0 +1,117 @@
+use std::io;
+use thiserror::Error;
+
+/// AI 프로바이더 호출 실패 종류 (UI가 종류별로 다르게 안내할 수 있도록)
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AiError {
+    /// CLI 또는 Node.js가 설치되어 있지 않음
+    #[error("{0}")]
+    NotInstalled(String),
+
+    /// 로그인/인증이 필요함
+    #[error("{0}")]
+    AuthRequired(String),
+
+    /// 응답 시간 초과
+    #[error("{0}")]
+    Timeout(String),
+
+    /// 요청이 너무 잦음 (`AI_LIMITER` 또는 프로바이더 제한)
+    #[error("{0}")]
+    RateLimited(String),
+
+    /// 그 밖의 이유로 CLI 실행 실패
+    #[error("{0}")]
+    ExecFailed(String),
+
+    /// 응답을 해석할 수 없음
+    #[error("{0}")]
+    ParseFailed(String),
+}
+
+/// 메시지로 `AiError` 종류를 만드는 생성자
+type AiErrorVariant = fn(String) -> AiError;
+
+/// (에러 출력에 들어 있으면 해당 종류로 보는 문구, 종류) - 위에서부터 검사
+const OUTPUT_PATTERNS: &[(&[&str], AiErrorVariant)] = &[
+    (
+        &["cannot find module", "command not found", "enoent", "is not recognized as"],
+        AiError::NotInstalled,
+    ),
+    (
+        &["not logged in", "login", "log in", "unauthorized", "authenticat", "api key", "401"],
+        AiError::AuthRequired,
+    ),
+    (&["rate limit", "rate_limit", "too many requests", "429", "quota"], AiError::RateLimited),
+    (&["timed out", "timeout", "etimedout"], AiError::Timeout),
+];
+
+impl AiError {
+    /// 프론트엔드에 넘기는 종류 이름
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AiError::NotInstalled(_) => "not_installed",
+            AiError::AuthRequired(_) => "auth_required",
+            AiError::Timeout(_) => "timeout",
+            AiError::RateLimited(_) => "rate_limited",
+            AiError::ExecFailed(_) => "exec_failed",
+            AiError::ParseFailed(_) => "parse_failed",
+        }
+    }
+
+    /// 실패한 CLI의 에러 출력(stderr 또는 에러 응답)을 분류 (`context: output` 형식의 메시지)
+    pub fn from_output(context: &str, output: &str) -> Self {
+        let message = format!("{}: {}", context, output.trim());
+        let lower = output.to_lowercase();
+        let variant = OUTPUT_PATTERNS
+            .iter()
+            .find(|(patterns, _)| patterns.iter().any(|p| lower.contains(p)))
+            .map_or(AiError::ExecFailed as AiErrorVariant, |(_, variant)| *variant);
+        variant(message)
+    }
+
+    /// CLI 프로세스를 띄우지 못한 I/O 에러 분류
+    pub fn from_io(context: &str, err: &io::Error) -> Self {
+        let message = format!("{}: {}", context, err);
+        match err.kind() {
+            io::ErrorKind::NotFound => AiError::NotInstalled(message),
+            io::ErrorKind::TimedOut => AiError::Timeout(message),
+            _ => AiError::ExecFailed(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classifies_cli_output() {
+        let cases = [
+            ("Error: Cannot find module '/usr/lib/node_modules/@anthropic-ai/claude-code/cli.js'", "not_installed"),
+            ("Invalid API key · Please run /login", "auth_required"),
+            ("Error: You are not logged in. Run `gh copilot auth`.", "auth_required"),
+            ("API Error: 429 Too Many Requests", "rate_limited"),
+            ("Request timed out after 120s", "timeout"),
+            ("Segmentation fault (core dumped)", "exec_failed"),
+        ];
+        for (stderr, kind) in cases {
+            assert_eq!(AiError::from_output("Claude error", stderr).kind(), kind, "{}", stderr);
+        }
+
+        let error = AiError::from_output("Copilot error", "  boom\n");
+        assert_eq!(error, AiError::ExecFailed("Copilot error: boom".to_string()));
+    }
+
+    #[test]
+    fn test_classifies_spawn_errors() {
+        let missing = io::Error::new(io::ErrorKind::NotFound, "No such file or directory");
+        assert_eq!(AiError::from_io("Failed to execute Claude", &missing).kind(), "not_installed");
+
+        let slow = io::Error::new(io::ErrorKind::TimedOut, "timed out");
+        assert_eq!(AiError::from_io("Failed to execute Claude", &slow).kind(), "timeout");
+
+        let denied = io::Error::new(io::ErrorKind::PermissionDenied, "denied");
+        assert_eq!(AiError::from_io("Failed to execute Claude", &denied).kind(), "exec_failed");
+    }
+}
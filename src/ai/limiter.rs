@@ -0,0 +1,78 @@
+use std::sync::{Mutex, TryLockError};
+use std::time::{Duration, Instant};
+
+use super::error::AiError;
+
+/// AI 요청 사이 최소 간격
+pub const MIN_AI_REQUEST_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 모든 AI 요청이 공유하는 제한기
+pub static AI_LIMITER: RateLimiter = RateLimiter::new(MIN_AI_REQUEST_INTERVAL);
+
+/// 한 번에 하나의 요청만, 최소 간격을 두고 실행
+///
+/// 진행 중이거나 너무 빨리 다시 호출하면 기다리지 않고 바로 에러를 반환합니다.
+pub struct RateLimiter {
+    min_interval: Duration,
+    last_call: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub const fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last_call: Mutex::new(None),
+        }
+    }
+
+    /// 허용되면 `request`를 실행 (실행하는 동안 다른 요청은 거절)
+    pub fn run<T>(&self, request: impl FnOnce() -> Result<T, AiError>) -> Result<T, AiError> {
+        let mut last_call = match self.last_call.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => {
+                return Err(AiError::RateLimited(
+                    "An AI request is already in progress, please wait".to_string(),
+                ));
+            }
+        };
+
+        if let Some(elapsed) = last_call.map(|at| at.elapsed()) {
+            if elapsed < self.min_interval {
+                let wait = (self.min_interval - elapsed).as_secs_f64().ceil() as u64;
+                return Err(AiError::RateLimited(format!(
+                    "Too many AI requests, please wait {}s",
+                    wait.max(1)
+                )));
+            }
+        }
+
+        *last_call = Some(Instant::now());
+        request()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rapid_second_call_is_rejected() {
+        let limiter = RateLimiter::new(Duration::from_secs(60));
+
+        assert_eq!(limiter.run(|| Ok(1)), Ok(1));
+        let second = limiter.run(|| Ok(2));
+        let error = second.unwrap_err();
+        assert_eq!(error.kind(), "rate_limited");
+        assert!(error.to_string().contains("please wait"));
+    }
+
+    #[test]
+    fn test_call_while_in_flight_is_rejected() {
+        let limiter = RateLimiter::new(Duration::ZERO);
+
+        let nested = limiter.run(|| Ok(limiter.run(|| Ok(()))));
+        assert!(nested.unwrap().unwrap_err().to_string().contains("already in progress"));
+        assert!(limiter.run(|| Ok(())).is_ok());
+    }
+}
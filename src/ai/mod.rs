@@ -0,0 +1,9 @@
+pub mod error;
+pub mod limiter;
+pub mod provider;
+pub mod suggestion;
+
+pub use error::AiError;
+pub use limiter::{RateLimiter, AI_LIMITER};
+pub use provider::{extract_json, AiConfig, AiProvider, CliVersion};
+pub use suggestion::{describe_schedule, parse_day_plan, task_suggestion_prompt, TaskSuggestion, SUGGESTION_GUIDE};
@@ -0,0 +1,372 @@
+use serde::{Deserialize, Serialize};
+use std::process::{Command as StdCommand, Output};
+use std::path::{Path, PathBuf};
+
+use super::error::AiError;
+use super::limiter::AI_LIMITER;
+
+/// AI 프로바이더 종류
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub enum AiProvider {
+    /// Claude Code CLI
+    #[default]
+    Claude,
+    /// GitHub Copilot CLI
+    Copilot,
+}
+
+impl AiProvider {
+    /// 지원하는 모든 프로바이더
+    pub const ALL: [AiProvider; 2] = [AiProvider::Claude, AiProvider::Copilot];
+
+    /// 프로바이더 이름("claude", "copilot") 파싱
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "claude" => Ok(AiProvider::Claude),
+            "copilot" => Ok(AiProvider::Copilot),
+            other => Err(format!("Unknown AI provider: {}", other)),
+        }
+    }
+
+    /// 설정 파일에 저장하는 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            AiProvider::Claude => "claude",
+            AiProvider::Copilot => "copilot",
+        }
+    }
+}
+
+/// CLI 탐지 결과: (경로, 버전) 또는 사용할 수 없는 이유
+pub type CliVersion = Result<(PathBuf, String), String>;
+
+/// AI 프로바이더별 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiConfig {
+    pub provider: AiProvider,
+    /// Claude Code CLI 경로 (옵션)
+    pub claude_path: Option<String>,
+    /// Copilot CLI 경로 (옵션)
+    pub copilot_path: Option<String>,
+}
+
+impl Default for AiConfig {
+    fn default() -> Self {
+        Self {
+            provider: AiProvider::Claude,
+            claude_path: None,
+            copilot_path: None,
+        }
+    }
+}
+
+impl AiConfig {
+    /// CLI 경로 자동 탐지
+    pub fn detect_cli_path(provider: &AiProvider) -> Option<PathBuf> {
+        let mut potential_paths: Vec<PathBuf> = Vec::new();
+
+        match provider {
+            AiProvider::Claude => {
+                // Try environment variable (Windows)
+                if let Ok(appdata) = std::env::var("APPDATA") {
+                    potential_paths.push(PathBuf::from(appdata).join(r"npm\node_modules\@anthropic-ai\claude-code\cli.js"));
+                }
+
+                // Unix-like systems
+                if let Ok(home) = std::env::var("HOME") {
+                    potential_paths.push(PathBuf::from(home).join(".npm/lib/node_modules/@anthropic-ai/claude-code/cli.js"));
+                }
+            },
+            AiProvider::Copilot => {
+                // Try environment variable (Windows)
+                if let Ok(appdata) = std::env::var("APPDATA") {
+                    potential_paths.push(PathBuf::from(appdata).join(r"npm\node_modules\@github\copilot\index.js"));
+                }
+
+                // Unix-like systems
+                if let Ok(home) = std::env::var("HOME") {
+                    potential_paths.push(PathBuf::from(home).join(".npm/lib/node_modules/@github/copilot/index.js"));
+                }
+            },
+        }
+
+        // 존재하는 첫 번째 경로 반환
+        potential_paths.into_iter().find(|path| path.exists())
+    }
+
+    /// CLI 사용 가능 여부 확인
+    pub fn verify_cli(provider: &AiProvider, path: Option<&str>) -> Result<String, AiError> {
+        let cli_path = if let Some(p) = path {
+            PathBuf::from(p)
+        } else {
+            Self::detect_cli_path(provider)
+                .ok_or_else(|| AiError::NotInstalled(format!("{:?} CLI를 찾을 수 없습니다", provider)))?
+        };
+
+        // 파일 존재 여부 확인
+        if !cli_path.exists() {
+            return Err(AiError::NotInstalled(format!("CLI 파일을 찾을 수 없습니다: {:?}", cli_path)));
+        }
+
+        // 간단한 테스트 명령 실행
+        match Self::run_version(&cli_path) {
+            Ok(output) if output.status.success() => {
+                Ok(format!("✓ {:?} CLI 사용 가능 (경로: {:?})", provider, cli_path))
+            },
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                Err(AiError::from_output("CLI 실행 실패", &stderr))
+            },
+            Err(e) => {
+                Err(AiError::from_io("Node.js 실행 실패 (Node.js가 설치되어 있는지 확인하세요)", &e))
+            },
+        }
+    }
+
+    /// 자동 탐지한 CLI의 (경로, `--version` 출력)
+    pub fn cli_version(provider: &AiProvider) -> CliVersion {
+        let cli_path = Self::detect_cli_path(provider).ok_or_else(|| "not found".to_string())?;
+        match Self::run_version(&cli_path) {
+            Ok(output) if output.status.success() => {
+                let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                Ok((cli_path, version))
+            }
+            Ok(output) => Err(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+            Err(e) => Err(format!("node failed: {}", e)),
+        }
+    }
+
+    /// `node <cli> --version` 실행
+    fn run_version(cli_path: &Path) -> std::io::Result<Output> {
+        StdCommand::new("node").arg(cli_path).arg("--version").output()
+    }
+
+    /// 설치 안내 메시지 가져오기
+    pub fn get_installation_guide(provider: &AiProvider) -> String {
+        match provider {
+            AiProvider::Claude => {
+                "Claude Code CLI 설치 방법:\n\n\
+                1. 설치: npm install -g @anthropic-ai/claude-code\n\
+                2. 로그인: claude login\n\n\
+                자세한 내용: https://docs.anthropic.com/claude/docs/claude-code".to_string()
+            },
+            AiProvider::Copilot => {
+                "GitHub Copilot CLI 설치 방법:\n\n\
+                1. 설치: npm install -g @github/copilot\n\
+                2. 로그인: gh copilot auth\n\n\
+                참고: GitHub Copilot 구독이 필요합니다\n\
+                자세한 내용: https://github.com/github/copilot-cli".to_string()
+            },
+        }
+    }
+
+    /// 프로바이더에게 질문하고 응답 받기 (`AI_LIMITER`로 동시 실행과 호출 간격 제한)
+    pub fn ask(&self, question: &str) -> Result<String, AiError> {
+        AI_LIMITER.run(|| match self.provider {
+            AiProvider::Claude => self.ask_claude(question),
+            AiProvider::Copilot => self.ask_copilot(question),
+        })
+    }
+
+    /// Claude Code CLI로 질문
+    fn ask_claude(&self, question: &str) -> Result<String, AiError> {
+        // CLI 경로: 설정값 또는 자동 탐지
+        let claude_path = if let Some(ref path) = self.claude_path {
+            PathBuf::from(path)
+        } else {
+            Self::detect_cli_path(&AiProvider::Claude)
+                .ok_or_else(|| AiError::NotInstalled("Claude Code CLI를 찾을 수 없습니다. 설치 후 다시 시도하세요.".to_string()))?
+        };
+
+        let output = StdCommand::new("node")
+            .arg(claude_path)
+            .arg("--print")
+            .arg("--output-format")
+            .arg("json")
+            .arg(question)
+            .output()
+            .map_err(|e| AiError::from_io("Failed to execute Claude", &e))?;
+
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(AiError::from_output("Claude error", &error));
+        }
+
+        // Claude Code CLI는 JSON 형식으로 응답
+        let response_str = String::from_utf8_lossy(&output.stdout).to_string();
+
+        // JSON 파싱 시도
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&response_str) {
+            if let Some(result) = json.get("result").and_then(|v| v.as_str()) {
+                return Ok(result.to_string());
+            } else if let Some(true) = json.get("is_error").and_then(|v| v.as_bool()) {
+                let error = json.get("result").and_then(|v| v.as_str()).unwrap_or("Unknown error");
+                return Err(AiError::from_output("Claude error", error));
+            }
+        }
+
+        // JSON 파싱 실패 시 원본 반환
+        Ok(response_str.trim().to_string())
+    }
+
+    /// GitHub Copilot CLI로 질문
+    fn ask_copilot(&self, question: &str) -> Result<String, AiError> {
+        // CLI 경로: 설정값 또는 자동 탐지
+        let copilot_path = if let Some(ref path) = self.copilot_path {
+            PathBuf::from(path)
+        } else {
+            Self::detect_cli_path(&AiProvider::Copilot)
+                .ok_or_else(|| AiError::NotInstalled("GitHub Copilot CLI를 찾을 수 없습니다. 설치 후 다시 시도하세요.".to_string()))?
+        };
+
+        let output = StdCommand::new("node")
+            .arg(copilot_path)
+            .arg("-p")
+            .arg(question)
+            .arg("--allow-all-tools")
+            .output()
+            .map_err(|e| AiError::from_io("Failed to execute Copilot", &e))?;
+
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(AiError::from_output("Copilot error", &error));
+        }
+
+        let response = String::from_utf8_lossy(&output.stdout).to_string();
+        Ok(response.trim().to_string())
+    }
+}
+
+/// 응답 안의 최상위 `{...}` 객체 후보 (문자열 안의 중괄호는 무시)
+pub fn json_object_candidates(text: &str) -> Vec<&str> {
+    json_candidates(text, '{', '}')
+}
+
+/// 응답 안의 최상위 `[...]` 배열 후보
+pub fn json_array_candidates(text: &str) -> Vec<&str> {
+    json_candidates(text, '[', ']')
+}
+
+/// `open`/`close`로 짝이 맞는 최상위 구간 (문자열 안의 괄호는 무시)
+fn json_candidates(text: &str, open: char, close: char) -> Vec<&str> {
+    let mut candidates = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in text.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' if depth > 0 => in_string = true,
+            _ if c == open => {
+                if depth == 0 {
+                    start = i;
+                }
+                depth += 1;
+            }
+            _ if c == close && depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    candidates.push(&text[start..=i]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    candidates
+}
+
+/// 응답에서 `T`로 읽히는 첫 JSON 객체 또는 배열 추출
+pub fn extract_json<T: serde::de::DeserializeOwned>(text: &str) -> Result<T, AiError> {
+    let mut candidates = json_object_candidates(text);
+    candidates.extend(json_array_candidates(text));
+    if candidates.is_empty() {
+        return Err(AiError::ParseFailed(format!("No JSON object found in response: {}", text)));
+    }
+
+    let mut last_error = None;
+    for candidate in candidates {
+        match serde_json::from_str(candidate) {
+            Ok(value) => return Ok(value),
+            Err(e) => last_error = Some(format!("{}. Extracted: {}", e, candidate)),
+        }
+    }
+
+    Err(AiError::ParseFailed(format!("Failed to parse JSON: {}", last_error.unwrap_or_default())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_provider() {
+        let config = AiConfig::default();
+        assert_eq!(config.provider, AiProvider::Claude);
+    }
+
+    #[test]
+    fn test_provider_name_round_trip() {
+        for provider in [AiProvider::Claude, AiProvider::Copilot] {
+            assert_eq!(AiProvider::from_name(provider.name()), Ok(provider));
+        }
+        assert!(AiProvider::from_name("ollama").is_err());
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Suggestion {
+        title: String,
+        minutes: u32,
+    }
+
+    #[test]
+    fn test_extract_clean_json() {
+        let parsed: Suggestion = extract_json(r#"{"title": "Run", "minutes": 30}"#).unwrap();
+        assert_eq!(parsed, Suggestion { title: "Run".to_string(), minutes: 30 });
+    }
+
+    #[test]
+    fn test_extract_json_wrapped_in_prose() {
+        let response = r#"Sure! Here is an example {like this}, and the real one:
+{"title": "Run", "minutes": 30}
+Let me know if {anything} else is needed."#;
+        let parsed: Suggestion = extract_json(response).unwrap();
+        assert_eq!(parsed.title, "Run");
+    }
+
+    #[test]
+    fn test_extract_json_with_brace_in_string() {
+        let response = r#"{"title": "Fix } and \" {bug", "minutes": 45}"#;
+        let parsed: Suggestion = extract_json(response).unwrap();
+        assert_eq!(parsed.title, "Fix } and \" {bug");
+        assert_eq!(parsed.minutes, 45);
+
+        let error = extract_json::<Suggestion>("no json here").unwrap_err();
+        assert_eq!(error.kind(), "parse_failed");
+    }
+
+    #[test]
+    fn test_copilot_provider() {
+        let config = AiConfig {
+            provider: AiProvider::Copilot,
+            claude_path: None,
+            copilot_path: None, // Auto-detect from environment
+        };
+        assert_eq!(config.provider.name(), "copilot");
+
+        // 실제 Copilot CLI가 설치되어 있어야 통과
+        // let result = config.ask("What is 2+2?");
+        // assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,167 @@
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use super::error::AiError;
+use super::provider::extract_json;
+use crate::models::{Schedule, Task};
+use crate::time::resolve_local;
+
+/// AI가 제안한 작업 하나
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskSuggestion {
+    pub suggested_title: String,
+    pub suggested_start_time: String,
+    pub suggested_end_time: String,
+    pub tags: Vec<String>,
+    pub notes: Option<String>,
+    pub pomodoro_duration: u32,
+    pub reasoning: String,
+}
+
+impl TaskSuggestion {
+    /// `date`의 작업으로 변환 (시간은 "HH:MM")
+    pub fn into_task(self, date: NaiveDate) -> Result<Task, String> {
+        let parse = |time: &str| {
+            NaiveTime::parse_from_str(time, "%H:%M")
+                .map(|t| resolve_local(date.and_time(t)))
+                .map_err(|e| format!("Invalid time format '{}': {}", time, e))
+        };
+        let start = parse(&self.suggested_start_time)?;
+        let end = parse(&self.suggested_end_time)?;
+        if end <= start {
+            return Err(format!(
+                "Suggested end time {} is not after start time {}",
+                self.suggested_end_time, self.suggested_start_time
+            ));
+        }
+
+        let mut task = Task::new(self.suggested_title, start, end);
+        task.tags = self.tags;
+        task.notes = self.notes.filter(|n| !n.trim().is_empty());
+        task.custom_pomodoro_duration = Some(self.pomodoro_duration);
+        Ok(task)
+    }
+}
+
+/// 자연어 요청 하나로 작업 제안을 받는 프롬프트
+pub fn task_suggestion_prompt(schedule: &Schedule, user_input: &str) -> String {
+    format!(
+        "{}\n\
+        사용자 요청: \"{}\"\n\n\
+        IMPORTANT: You must respond with ONLY valid JSON. No explanation, no markdown, just raw JSON.\n\n\
+        {}\
+        Output ONLY the JSON object, nothing else:",
+        describe_schedule(schedule),
+        user_input,
+        SUGGESTION_GUIDE
+    )
+}
+
+/// AI 응답에서 제안 목록 추출 (배열 대신 객체 하나만 오면 그것만)
+pub fn parse_day_plan(response: &str) -> Result<Vec<TaskSuggestion>, AiError> {
+    extract_json::<Vec<TaskSuggestion>>(response).or_else(|err| {
+        extract_json::<TaskSuggestion>(response)
+            .map(|suggestion| vec![suggestion])
+            .map_err(|_| err)
+    })
+}
+
+/// AI 프롬프트에 넣을 기존 스케줄 설명
+pub fn describe_schedule(schedule: &Schedule) -> String {
+    let mut schedule_desc = String::new();
+    if schedule.tasks.is_empty() {
+        schedule_desc.push_str("오늘은 아직 스케줄이 비어있습니다.\n");
+    } else {
+        schedule_desc.push_str("오늘 기존 스케줄:\n");
+        for (i, task) in schedule.tasks.iter().enumerate() {
+            let start = task.start_time.format("%H:%M").to_string();
+            let end = task.end_time.format("%H:%M").to_string();
+            schedule_desc.push_str(&format!(
+                "{}. {} ({}-{}, {}분)\n",
+                i + 1,
+                task.title,
+                start,
+                end,
+                task.estimated_duration_minutes
+            ));
+        }
+    }
+    schedule_desc
+}
+
+/// 작업 제안 JSON 형식과 규칙 (단일 제안, 하루 계획 공용)
+pub const SUGGESTION_GUIDE: &str = "\
+        ## 심리학 원리 기반 작업 생성:\n\
+        다음 원리들을 고려하여 작업을 제안하세요:\n\
+        1. **자이가르닉 효과**: 작업 제목을 구체적이고 명확하게 정의하세요. 모호한 작업명은 완료 동기를 낮춥니다.\n\
+        2. **파킨슨의 법칙**: 예상 시간을 적절히 타이트하게 설정하세요. 너무 여유로운 시간은 비효율을 초래합니다.\n\
+        3. **포모도로 기법**: 작업을 25-90분 단위로 분할 가능하도록 pomodoro_duration을 선택하세요.\n\
+        4. **희소성 원리**: 하루 24시간이라는 한정된 자원을 고려하여 시간을 배분하세요.\n\n\
+        Output JSON structure (one task):\n\
+        {\n\
+          \"suggested_title\": \"task title (구체적이고 명확하게)\",\n\
+          \"suggested_start_time\": \"HH:MM\",\n\
+          \"suggested_end_time\": \"HH:MM\",\n\
+          \"tags\": [\"tag1\", \"tag2\"],\n\
+          \"notes\": \"tips or notes for this task\",\n\
+          \"pomodoro_duration\": 25,\n\
+          \"reasoning\": \"why you made these suggestions (심리학 원리 언급)\"\n\
+        }\n\n\
+        Rules:\n\
+        1. suggested_title: 구체적으로 추출 (e.g., \"조깅 30분\" → \"30분 조깅 (XX 코스)\")\n\
+        2. suggested_start_time and suggested_end_time: 기존 스케줄과 충돌 회피\n\
+           - \"아침\" → 07:00-09:00\n\
+           - \"오전\" → 09:00-12:00\n\
+           - \"오후\" → 13:00-18:00\n\
+           - \"저녁\" → 18:00-21:00\n\
+        3. Duration: 지정된 경우 그대로, 아니면 파킨슨 법칙에 따라 타이트하게\n\
+        4. tags: 1-3개 (운동, 업무, 학습, 건강, 휴식 등)\n\
+        5. notes: 실행 팁 1-2문장 (자이가르닉 효과 활용 - 구체적 실행 방법 제시)\n\
+        6. pomodoro_duration: [5, 15, 25, 45, 60, 90] 중 작업 특성에 맞게 선택\n\
+        7. reasoning: 심리학 원리를 언급하며 1문장으로 설명\n\n\
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::at;
+
+    #[test]
+    fn test_suggestion_into_task() {
+        let response = r#"{"suggested_title": "30분 조깅 (한강 코스)", "suggested_start_time": "07:00",
+            "suggested_end_time": "07:30", "tags": ["운동"], "notes": "운동화를 미리 꺼내두세요",
+            "pomodoro_duration": 25, "reasoning": "구체적인 목표"}"#;
+        let suggestion: TaskSuggestion = extract_json(response).unwrap();
+        let date = NaiveDate::from_ymd_opt(2025, 11, 4).unwrap();
+
+        let task = suggestion.clone().into_task(date).unwrap();
+        assert_eq!(task.title, "30분 조깅 (한강 코스)");
+        assert_eq!(task.start_time, at(4, 7, 0));
+        assert_eq!(task.estimated_duration_minutes, 30);
+        assert_eq!(task.tags, vec!["운동".to_string()]);
+        assert_eq!(task.custom_pomodoro_duration, Some(25));
+
+        let backwards = TaskSuggestion {
+            suggested_end_time: "06:00".to_string(),
+            ..suggestion
+        };
+        assert!(backwards.into_task(date).is_err());
+    }
+
+    #[test]
+    fn test_parse_day_plan_two_suggestions() {
+        let response = r#"Here is your plan:
+[
+  {"suggested_title": "30분 조깅", "suggested_start_time": "07:00", "suggested_end_time": "07:30",
+   "tags": ["운동"], "notes": "코스를 미리 정하세요", "pomodoro_duration": 25, "reasoning": "구체적인 목표"},
+  {"suggested_title": "보고서 초안 {1장}", "suggested_start_time": "09:00", "suggested_end_time": "10:30",
+   "tags": ["업무"], "notes": null, "pomodoro_duration": 45, "reasoning": "파킨슨의 법칙"}
+]
+Good luck!"#;
+
+        let plan = parse_day_plan(response).unwrap();
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].suggested_title, "30분 조깅");
+        assert_eq!(plan[1].suggested_start_time, "09:00");
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+
+use super::ScheduleContext;
+use crate::config::Config;
+use crate::models::Schedule;
+
+/// 캐시한 컨텍스트를 다시 쓰는 시간 (초)
+pub const CONTEXT_CACHE_SECONDS: i64 = 120;
+
+/// 파일에 저장하는 `ScheduleContext` 캐시
+///
+/// 스케줄, git HEAD, 작업 디렉터리, git 관련 설정이 같고 `CONTEXT_CACHE_SECONDS` 안이면
+/// git 명령을 다시 실행하지 않고 저장된 컨텍스트를 씁니다.
+pub struct ContextCache {
+    path: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    key: String,
+    created_at: DateTime<Local>,
+    context: ScheduleContext,
+}
+
+impl ContextCache {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// 캐시된 컨텍스트 또는 새로 모은 컨텍스트와, 캐시를 썼는지 여부
+    pub fn collect(&self, schedule: &Schedule, config: &Config) -> (ScheduleContext, bool) {
+        let key = cache_key(schedule, config, git_head().as_deref());
+        let now = Local::now();
+
+        if let Some(mut context) = self.lookup(&key, now) {
+            context.current_time = now.format("%Y-%m-%d %H:%M").to_string();
+            return (context, true);
+        }
+
+        let context = ScheduleContext::collect(schedule, config);
+        if let Err(e) = self.store(&key, now, &context) {
+            log::debug!("Failed to write context cache {}: {}", self.path.display(), e);
+        }
+        (context, false)
+    }
+
+    /// 캐시 파일 삭제 (없으면 아무것도 안 함)
+    pub fn clear(&self) -> anyhow::Result<()> {
+        if self.path.exists() {
+            fs::remove_file(&self.path)?;
+        }
+        Ok(())
+    }
+
+    fn lookup(&self, key: &str, now: DateTime<Local>) -> Option<ScheduleContext> {
+        let content = fs::read_to_string(&self.path).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        let fresh = now - entry.created_at < Duration::seconds(CONTEXT_CACHE_SECONDS)
+            && entry.created_at <= now;
+        (entry.key == key && fresh).then_some(entry.context)
+    }
+
+    fn store(&self, key: &str, now: DateTime<Local>, context: &ScheduleContext) -> anyhow::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let entry = serde_json::json!({ "key": key, "created_at": now, "context": context });
+        fs::write(&self.path, entry.to_string())?;
+        Ok(())
+    }
+}
+
+/// 컨텍스트 내용을 바꾸는 입력들의 해시
+fn cache_key(schedule: &Schedule, config: &Config, git_head: Option<&str>) -> String {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(schedule).unwrap_or_default().hash(&mut hasher);
+    git_head.hash(&mut hasher);
+    std::env::current_dir().ok().hash(&mut hasher);
+    config.git_context_commits.hash(&mut hasher);
+    config.git_context_include_status.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// 현재 git HEAD 커밋 (git 저장소가 아니면 None)
+fn git_head() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Task;
+
+    #[test]
+    fn test_cache_hits_until_schedule_changes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = ContextCache::new(temp_dir.path().join("cache").join("context.json"));
+        let config = Config::default();
+        let mut schedule = Schedule::today();
+
+        let (first, hit) = cache.collect(&schedule, &config);
+        assert!(!hit);
+        let (second, hit) = cache.collect(&schedule, &config);
+        assert!(hit);
+        assert_eq!(second.today_schedule.total_tasks, first.today_schedule.total_tasks);
+
+        let start = Local::now();
+        schedule
+            .add_task(Task::new("Review".to_string(), start, start + Duration::hours(1)))
+            .unwrap();
+        let (changed, hit) = cache.collect(&schedule, &config);
+        assert!(!hit);
+        assert_eq!(changed.today_schedule.total_tasks, 1);
+
+        assert_ne!(
+            cache_key(&schedule, &config, Some("abc")),
+            cache_key(&schedule, &config, Some("def"))
+        );
+        cache.clear().unwrap();
+        assert!(!cache.collect(&schedule, &config).1);
+    }
+}
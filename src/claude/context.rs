@@ -1,6 +1,7 @@
 use chrono::Local;
 use serde::{Deserialize, Serialize};
 
+use crate::config::Config;
 use crate::models::{Schedule, Task, TaskStatus};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -21,6 +22,8 @@ pub struct TaskInfo {
     pub status: String,
     pub elapsed_minutes: Option<i64>,
     pub estimated_duration: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -43,7 +46,7 @@ pub struct GitInfo {
 }
 
 impl ScheduleContext {
-    pub fn collect(schedule: &Schedule) -> Self {
+    pub fn collect(schedule: &Schedule, config: &Config) -> Self {
         let current_time = Local::now().format("%Y-%m-%d %H:%M").to_string();
         let working_directory = std::env::current_dir()
             .ok()
@@ -54,7 +57,8 @@ impl ScheduleContext {
         let next_task = schedule.get_next_task().map(TaskInfo::from_task);
 
         let today_schedule = ScheduleSummary::from_schedule(schedule);
-        let git_info = Self::collect_git_info();
+        let git_info =
+            Self::collect_git_info(config.git_context_commits, config.git_context_include_status);
 
         Self {
             current_time,
@@ -66,7 +70,7 @@ impl ScheduleContext {
         }
     }
 
-    fn collect_git_info() -> Option<GitInfo> {
+    fn collect_git_info(commit_count: usize, include_status: bool) -> Option<GitInfo> {
         use std::process::Command;
 
         // Check if git is available and we're in a git repo
@@ -82,20 +86,28 @@ impl ScheduleContext {
                 }
             })?;
 
-        let status = Command::new("git")
-            .args(["status", "--short"])
-            .output()
-            .ok()
-            .and_then(|o| String::from_utf8(o.stdout).ok())
-            .unwrap_or_else(|| "unknown".to_string());
+        let status = if include_status {
+            Command::new("git")
+                .args(["status", "--short"])
+                .output()
+                .ok()
+                .and_then(|o| String::from_utf8(o.stdout).ok())
+                .unwrap_or_else(|| "unknown".to_string())
+        } else {
+            String::new()
+        };
 
-        let recent_commits = Command::new("git")
-            .args(["log", "--oneline", "-5"])
-            .output()
-            .ok()
-            .and_then(|o| String::from_utf8(o.stdout).ok())
-            .map(|s| s.lines().map(String::from).collect())
-            .unwrap_or_else(Vec::new);
+        let recent_commits = if commit_count > 0 {
+            Command::new("git")
+                .args(Self::git_log_args(commit_count))
+                .output()
+                .ok()
+                .and_then(|o| String::from_utf8(o.stdout).ok())
+                .map(|s| s.lines().map(String::from).collect())
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
 
         Some(GitInfo {
             branch,
@@ -104,6 +116,11 @@ impl ScheduleContext {
         })
     }
 
+    /// 최근 커밋 `count`개를 가져오는 `git log` 인자
+    fn git_log_args(count: usize) -> Vec<String> {
+        vec!["log".to_string(), "--oneline".to_string(), format!("-{}", count)]
+    }
+
     pub fn to_json(&self) -> anyhow::Result<String> {
         Ok(serde_json::to_string_pretty(self)?)
     }
@@ -126,13 +143,20 @@ impl ScheduleContext {
                     elapsed, task.estimated_duration
                 ));
             }
-            md.push_str("\n");
+            if let Some(ref link) = task.link {
+                md.push_str(&format!("- **Link**: {}\n", link));
+            }
+            md.push('\n');
         }
 
         if let Some(ref task) = self.next_task {
             md.push_str("## Next Task\n\n");
             md.push_str(&format!("- **Title**: {}\n", task.title));
-            md.push_str(&format!("- **Time**: {} - {}\n\n", task.start_time, task.end_time));
+            md.push_str(&format!("- **Time**: {} - {}\n", task.start_time, task.end_time));
+            if let Some(ref link) = task.link {
+                md.push_str(&format!("- **Link**: {}\n", link));
+            }
+            md.push('\n');
         }
 
         md.push_str("## Today's Schedule\n\n");
@@ -178,6 +202,7 @@ impl TaskInfo {
             status: format!("{:?}", task.status),
             elapsed_minutes: task.elapsed_minutes(),
             estimated_duration: task.estimated_duration_minutes,
+            link: task.link.clone(),
         }
     }
 }
@@ -227,3 +252,14 @@ impl ScheduleSummary {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_git_log_args_uses_commit_count() {
+        assert_eq!(ScheduleContext::git_log_args(3), vec!["log", "--oneline", "-3"]);
+        assert_eq!(ScheduleContext::git_log_args(Config::default().git_context_commits)[2], "-5");
+    }
+}
@@ -1,5 +1,7 @@
+pub mod cache;
 pub mod context;
 pub mod prompts;
 
+pub use cache::ContextCache;
 pub use context::ScheduleContext;
 pub use prompts::PromptTemplate;
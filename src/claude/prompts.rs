@@ -88,6 +88,17 @@ Consider:
         )
     }
 
+    pub fn day_recap() -> Self {
+        Self::new(
+            r#"Here are the facts about my day:
+
+{summary}
+
+Write a short, warm journal-style recap (one paragraph) based only on these facts.
+Mention what went well, one thing to improve tomorrow, and keep it under 120 words."#,
+        )
+    }
+
     pub fn daily_planning() -> Self {
         Self::new(
             r#"Help me plan my day:
@@ -1,61 +1,143 @@
-use chrono::{Local, NaiveTime, TimeZone};
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveTime};
 use colored::Colorize;
+use regex::Regex;
 use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io::IsTerminal;
+use std::path::PathBuf;
 
-use crate::claude::{PromptTemplate, ScheduleContext};
+use crate::ai::{self, AiConfig, AiProvider, CliVersion, TaskSuggestion};
+use crate::claude::{ContextCache, PromptTemplate, ScheduleContext};
+use crate::config::{Config, DurationStyle, ReportPrecision, WakingHours};
 use crate::daemon::{DaemonProcess, TimeTracker};
-use crate::models::{Schedule, Task, TaskStatus};
-use crate::storage::{JsonStorage, Storage};
+use crate::error::SchedError;
+use crate::i18n::{self, tr, tr_with};
+use crate::models::{
+    habit_candidates, EstimateIssue, EventType, Priority, Recurrence, Schedule, ScheduleChange, ScheduleEvent, ScheduleTemplate, ScheduleWarning, SimChange, StreakInfo,
+    Task, TaskStatus, WeeklyGoal, WeeklyGoals, HABIT_MIN_DAYS,
+};
+use crate::storage::{backup, ics, EncryptedStorage, JsonStorage, Storage};
+use crate::time::resolve_local;
 
 use super::output;
-use super::{ClaudeAction, Commands, DaemonAction};
-
-pub fn execute_command(command: Commands) -> anyhow::Result<()> {
-    let storage = JsonStorage::new()?;
+use super::spinner::with_spinner;
+use super::{
+    AddArgs, ClaudeAction, Commands, DaemonAction, EditArgs, GoalAction, ProfileAction, ReportArgs,
+    TagAction, TemplateAction,
+};
+
+pub fn execute_command(command: Commands, profile: Option<&str>, dry_run: bool, verbose: bool) -> anyhow::Result<()> {
+    crate::config::set_dry_run(dry_run);
+    let config = match profile {
+        Some(_) => Config::load()?,
+        None => Config::load().unwrap_or_default(),
+    };
+    let store = EncryptedStorage::from_env(
+        match profile {
+            Some(_) => JsonStorage::for_profile(&config, profile)?,
+            None => JsonStorage::new()?,
+        }
+        .with_format(config.storage_format)
+        .with_dry_run(dry_run),
+    )?;
+    let storage: &dyn Storage = store.as_ref();
+    output::set_accessible_symbols(config.accessible_symbols);
+    output::set_overdue_grace_minutes(config.overdue_grace_minutes);
+    i18n::set_locale(config.locale);
+    log::debug!("Data directory: {}", storage.data_dir().display());
+
+    if command.mutates_today() {
+        start_of_day(storage, &config)?;
+    }
 
     match command {
-        Commands::Add {
-            title,
-            start,
-            end,
-            tags,
-            notes,
-        } => add_task(&storage, title, start, end, tags, notes),
+        Commands::Add(args) => add_task(storage, args, &config),
+
+        Commands::Edit(args) => edit_task(storage, args, &config),
+
+        Commands::List { compact } => list_tasks(storage, compact, &config),
+
+        Commands::Start { id, no_input } => start_task(storage, id, no_input),
+
+        Commands::Pause => pause_task(storage),
 
-        Commands::List => list_tasks(&storage),
+        Commands::Complete { focus } => complete_task(storage, focus, config.auto_start_next),
 
-        Commands::Start { id } => start_task(&storage, id),
+        Commands::Status => show_status(storage),
 
-        Commands::Pause => pause_task(&storage),
+        Commands::Now { format } => now_command(storage, &format),
 
-        Commands::Complete => complete_task(&storage),
+        Commands::Delete { id, no_input } => delete_task(storage, id, no_input),
 
-        Commands::Status => show_status(&storage),
+        Commands::Progress { id, percent } => progress_task(storage, id, percent),
 
-        Commands::Delete { id } => delete_task(&storage, id),
+        Commands::Interrupt { id, reason, pause } => interrupt_task(storage, &id, reason, pause),
 
-        Commands::Daemon { action } => daemon_command(action, storage),
-        Commands::Widget => widget_command(),
-        Commands::Ui => ui_command(storage),
-        Commands::Stats { week } => stats_command(&storage, week),
-        Commands::Streak => streak_command(&storage),
-        Commands::Pomodoro { action } => pomodoro_command(&storage, action),
-        Commands::Claude { action } => claude_command(&storage, action),
-        Commands::Report { week, month } => report_command(&storage, week, month),
-        Commands::Efficiency { days } => efficiency_command(&storage, days),
+        Commands::Skip { id } => skip_task(storage, id),
+
+        Commands::Block { id, reason } => block_task(storage, id, reason),
+
+        Commands::Unblock { id } => unblock_task(storage, id),
+
+        Commands::Focus { id } => focus_task(storage, id),
+
+        Commands::Merge { first, second } => merge_command(storage, &first, &second),
+
+        Commands::Rebalance { start, end } => rebalance_command(storage, &start, &end),
+
+        Commands::Carryover => carryover_command(storage),
+
+        Commands::Tag { action } => tag_command(storage, action),
+        Commands::Retitle { pattern, replacement, regex, days } => {
+            retitle_command(storage, &pattern, &replacement, regex, days)
+        }
+        Commands::Repair { check } => repair_command(storage, check),
+        Commands::Goal { action } => goal_command(storage, action),
+        Commands::Profile { action } => profile_command(storage, profile, action, &config),
+        Commands::Open { config } => open_command(storage, config),
+        Commands::Version => version_command(storage, verbose),
+        Commands::Template { action } => template_command(storage, action),
+
+        Commands::Export { format, date, out } => export_command(storage, format, date, out),
+
+        Commands::Import { file } => import_command(storage, file),
+
+        Commands::Backup { out } => backup_command(storage, out),
+
+        Commands::Restore {
+            archive,
+            data_dir,
+            yes,
+        } => restore_command(storage, archive, data_dir, yes),
+
+        Commands::Daemon { action } => daemon_command(action, store),
+        Commands::Serve { port, bind } => crate::server::serve(storage, &bind, port),
+        Commands::Widget => widget_command(store),
+        Commands::Ui => ui_command(store),
+        Commands::Stats { week, heatmap, days } => stats_command(storage, week, heatmap.then_some(days), &config),
+        Commands::SuggestHabits { days } => suggest_habits_command(storage, days),
+        Commands::Calendar { month } => calendar_command(storage, month.as_deref(), config.idle_window()),
+        Commands::Streak { format, oneline } => streak_command(storage, &format, oneline),
+        Commands::Pomodoro { action } => pomodoro_command(storage, action),
+        Commands::Claude { no_cache, action } => claude_command(storage, action, no_cache, &config),
+        Commands::Report(args) => report_command(storage, args, &config),
+        Commands::Expand { date } => expand_command(storage, date),
+        Commands::Digest { week, out } => digest_command(storage, week.as_deref(), out, config.idle_window()),
+        Commands::Efficiency { days, sparkline } => efficiency_command(storage, days, sparkline, &config),
+        Commands::Summary { ai } => summary_command(storage, ai),
+        Commands::Simulate { changes } => simulate_command(storage, &changes),
     }
 }
 
-fn daemon_command(action: DaemonAction, storage: JsonStorage) -> anyhow::Result<()> {
-    let daemon = DaemonProcess::new()?;
+fn daemon_command(action: DaemonAction, storage: Box<dyn Storage>) -> anyhow::Result<()> {
+    let daemon = DaemonProcess::new()?.with_dry_run(storage.is_dry_run());
 
     match action {
         DaemonAction::Start => {
             daemon.start()?;
             output::success("Daemon started");
 
-            // Tracker 실행
-            env_logger::init();
+            // Tracker 실행 (로거는 main에서 초기화됨)
             let mut tracker = TimeTracker::new(storage);
             
             // CTRL+C 핸들러 (간단한 버전)
@@ -86,53 +168,444 @@ fn daemon_command(action: DaemonAction, storage: JsonStorage) -> anyhow::Result<
 
 fn parse_time(time_str: &str) -> anyhow::Result<NaiveTime> {
     NaiveTime::parse_from_str(time_str, "%H:%M")
-        .map_err(|_| anyhow::anyhow!("Invalid time format. Use HH:MM (e.g., 14:30)"))
+        .map_err(|_| SchedError::validation("Invalid time format. Use HH:MM (e.g., 14:30)").into())
 }
 
-fn add_task(
-    storage: &JsonStorage,
-    title: String,
-    start_str: String,
-    end_str: String,
-    tags: Option<String>,
-    notes: Option<String>,
-) -> anyhow::Result<()> {
+fn parse_date(date_str: &str) -> anyhow::Result<NaiveDate> {
+    NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+        .map_err(|_| SchedError::validation("Invalid date format. Use YYYY-MM-DD (e.g., 2025-11-04)").into())
+}
+
+/// 마감 시각 파싱 ("HH:MM"은 `date` 기준, 또는 "YYYY-MM-DD HH:MM")
+fn parse_deadline(deadline_str: &str, date: NaiveDate) -> anyhow::Result<DateTime<Local>> {
+    if let Ok(naive) = chrono::NaiveDateTime::parse_from_str(deadline_str, "%Y-%m-%d %H:%M") {
+        return Ok(resolve_local(naive));
+    }
+
+    Ok(resolve_local(date.and_time(parse_time(deadline_str)?)))
+}
+
+fn add_task(storage: &dyn Storage, args: AddArgs, config: &Config) -> anyhow::Result<()> {
+    if args.ai {
+        return ai_add_task(storage, &args, config);
+    }
+
+    let AddArgs {
+        title,
+        start: start_str,
+        end: end_str,
+        tags,
+        notes,
+        link,
+        date,
+        deadline,
+        force,
+        enforce_break,
+        fixed,
+        priority,
+        repeat,
+        repeat_until,
+        ai: _,
+    } = args;
+
+    let (Some(start_str), Some(end_str)) = (start_str, end_str) else {
+        return Err(SchedError::validation("--start and --end are required").into());
+    };
     let start_time = parse_time(&start_str)?;
     let end_time = parse_time(&end_str)?;
 
     let today = Local::now().date_naive();
-    let start_datetime = Local.from_local_datetime(&today.and_time(start_time)).unwrap();
-    let end_datetime = Local.from_local_datetime(&today.and_time(end_time)).unwrap();
+    let date = match date {
+        Some(date_str) => parse_date(&date_str)?,
+        None => today,
+    };
+
+    if date < today && !force {
+        return Err(SchedError::validation(format!(
+            "{} is in the past; its tasks would count as wasted. Use --force to add anyway.",
+            date.format("%Y-%m-%d")
+        ))
+        .into());
+    }
+
+    let start_datetime = resolve_local(date.and_time(start_time));
+    let end_datetime = resolve_local(date.and_time(end_time));
 
     if end_datetime <= start_datetime {
-        anyhow::bail!("End time must be after start time");
+        return Err(SchedError::validation("End time must be after start time").into());
     }
 
     let mut task = Task::new(title.clone(), start_datetime, end_datetime);
 
     if let Some(tags_str) = tags {
-        task.tags = tags_str.split(',').map(|s| s.trim().to_string()).collect();
+        task.tags = parse_tags(&tags_str);
     }
+    config.apply_tag_policy(&mut task.tags)?;
 
     task.notes = notes;
+    task.link = link;
+    task.fixed = fixed;
+    if let Some(priority) = priority {
+        task.priority = Priority::parse(&priority).map_err(SchedError::Validation)?;
+    }
+    task.deadline = deadline.map(|d| parse_deadline(&d, date)).transpose()?;
+    if let Some(rule) = repeat {
+        make_recurring(&mut task, Recurrence::parse(&rule).map_err(SchedError::Validation)?);
+        task.recurrence_until = repeat_until.map(|d| parse_date(&d)).transpose()?;
+    }
+    let task_id = task.id.clone();
+
+    let mut schedule = if date == today {
+        storage.load_today()?.unwrap_or_else(Schedule::today)
+    } else {
+        let day = resolve_local(date.and_hms_opt(0, 0, 0).unwrap());
+        storage.load_schedule(day)?.unwrap_or_else(|| Schedule::new(day))
+    };
+
+    if let Some(conflict) = schedule.conflicting_task(&task) {
+        let conflict_title = conflict.title.clone();
+        let duration = task.estimated_duration_minutes;
+
+        let (slot_start, slot_end) = schedule
+            .suggest_slot(task.start_time, duration)
+            .ok_or_else(|| SchedError::validation(format!("Time conflict with task: {}", conflict_title)))?;
+
+        let suggestion = format!(
+            "Conflicts with '{}'. Next free {}-min slot: {}–{}.",
+            conflict_title,
+            duration,
+            slot_start.format("%H:%M"),
+            slot_end.format("%H:%M")
+        );
+
+        if !std::io::stdin().is_terminal() || !output::confirm(&format!("{} Add there?", suggestion))? {
+            return Err(SchedError::validation(suggestion).into());
+        }
+
+        task.start_time = slot_start;
+        task.end_time = slot_end;
+    }
+
+    let time_range = format!(
+        "{}-{}",
+        task.start_time.format("%H:%M"),
+        task.end_time.format("%H:%M")
+    );
+    let one_off = task.recurrence.is_none();
+    schedule.add_task(task).map_err(SchedError::Validation)?;
+    schedule.add_change(ScheduleChange::task_created(title.clone(), time_range));
+    schedule.sort_by_time();
+    if one_off {
+        offer_habit(storage, &mut schedule, &task_id)?;
+    }
+
+    if enforce_break {
+        let min_break = config.min_break_minutes as i64;
+        let shifted = schedule.enforce_breaks(min_break).map_err(SchedError::Validation)?;
+        if shifted > 0 {
+            output::info(&format!("Shifted tasks {} time(s) to keep {}-min breaks", shifted, min_break));
+        }
+    }
+
+    storage.save_schedule(&schedule)?;
+    storage.append_event(ScheduleEvent::new(EventType::TaskAdded, &task_id, &title))?;
+    if let Some(task) = schedule.tasks.iter().find(|t| t.id == task_id) {
+        remember_recurring(storage, task)?;
+    }
+
+    output::success(&format!("Task '{}' added successfully", title));
+    Ok(())
+}
+
+/// 쉼표로 구분한 태그 목록 (빈 항목 제외)
+fn parse_tags(tags_str: &str) -> Vec<String> {
+    tags_str
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// 오늘 작업을 ID를 유지한 채 수정 (시간이 겹치면 저장하지 않음)
+fn edit_task(storage: &dyn Storage, args: EditArgs, config: &Config) -> anyhow::Result<()> {
+    let EditArgs {
+        id,
+        title,
+        start,
+        end,
+        tags,
+        notes,
+    } = args;
+
+    if title.is_none() && start.is_none() && end.is_none() && tags.is_none() && notes.is_none() {
+        return Err(SchedError::validation("Nothing to change: pass --title, --start, --end, --tags or --notes").into());
+    }
+
+    let mut schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+    let original = schedule
+        .find_task(&id)
+        .ok_or_else(|| SchedError::not_found("Task not found"))?;
+    let mut task = original.clone();
+    let old_time = format!("{}-{}", task.start_time.format("%H:%M"), task.end_time.format("%H:%M"));
+    let old_title = task.title.clone();
+
+    if let Some(title) = title {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(SchedError::validation("Title cannot be empty").into());
+        }
+        task.title = title.to_string();
+    }
+
+    let day = task.start_time.date_naive();
+    if let Some(start) = start {
+        task.start_time = resolve_local(day.and_time(parse_time(&start)?));
+    }
+    if let Some(end) = end {
+        task.end_time = resolve_local(day.and_time(parse_time(&end)?));
+    }
+    if task.end_time <= task.start_time {
+        return Err(SchedError::validation("End time must be after start time").into());
+    }
+
+    let new_time = format!("{}-{}", task.start_time.format("%H:%M"), task.end_time.format("%H:%M"));
+    if new_time != old_time {
+        task.estimated_duration_minutes = (task.end_time - task.start_time).num_minutes();
+        if let Some(conflict) = schedule.conflicting_task(&task) {
+            return Err(SchedError::validation(format!("Time conflict with task: {}", conflict.title)).into());
+        }
+    }
+
+    if let Some(tags) = tags {
+        task.tags = parse_tags(&tags);
+        config.apply_tag_policy(&mut task.tags)?;
+    }
+    if let Some(notes) = notes {
+        task.notes = Some(notes).filter(|n| !n.trim().is_empty());
+    }
+
+    let task_title = task.title.clone();
+    *schedule.find_task_mut(&id).unwrap() = task;
+    if new_time != old_time {
+        schedule.add_change(ScheduleChange::task_updated(old_title, old_time.clone(), new_time.clone()));
+        schedule.sort_by_time();
+    }
+    storage.save_schedule(&schedule)?;
+
+    output::success(&format!("Updated task: {}", task_title));
+    if new_time != old_time {
+        output::info(&format!("Time: {} → {}", old_time, new_time));
+    }
+    Ok(())
+}
+
+/// 작업을 자기 자신을 원본으로 하는 반복 작업으로 만듦
+fn make_recurring(task: &mut Task, rule: Recurrence) {
+    task.recurrence = Some(rule);
+    task.recurrence_id = Some(task.id.clone());
+}
+
+/// 방금 추가한 작업이 며칠째 같은 시간이면 매일 반복으로 바꿀지 물음
+fn offer_habit(storage: &dyn Storage, schedule: &mut Schedule, task_id: &str) -> anyhow::Result<()> {
+    let date = schedule.date.date_naive();
+    let mut schedules = load_schedules_between(
+        storage,
+        date - chrono::Duration::days(HABIT_MIN_DAYS as i64 - 1),
+        date - chrono::Duration::days(1),
+    );
+    schedules.push(schedule.clone());
+
+    let Some(habit) = habit_candidates(&schedules, HABIT_MIN_DAYS)
+        .into_iter()
+        .find(|c| c.task_id == task_id)
+    else {
+        return Ok(());
+    };
+
+    if !std::io::stdin().is_terminal() {
+        output::info(&format!(
+            "'{}' was added {} days in a row; use --repeat daily to make it recurring",
+            habit.title, habit.days
+        ));
+        return Ok(());
+    }
+    let prompt = format!(
+        "'{}' was added {} days in a row. This looks like a daily habit — make it recurring?",
+        habit.title, habit.days
+    );
+    if output::confirm(&prompt)? {
+        if let Some(task) = schedule.find_task_mut(task_id) {
+            make_recurring(task, Recurrence::Daily);
+        }
+    }
+    Ok(())
+}
+
+fn suggest_habits_command(storage: &dyn Storage, days: i64) -> anyhow::Result<()> {
+    let days = days.max(1);
+    let candidates = habit_candidates(&load_recent_schedules(storage, days), HABIT_MIN_DAYS);
+    if candidates.is_empty() {
+        output::info(&format!(
+            "No task repeated at the same time {}+ days in a row in the last {} days",
+            HABIT_MIN_DAYS, days
+        ));
+        return Ok(());
+    }
+
+    let interactive = std::io::stdin().is_terminal();
+    for habit in candidates {
+        println!(
+            "{} {}  {} days in a row (last {})",
+            habit.title.bold(),
+            format!("{}–{}", habit.start.format("%H:%M"), habit.end.format("%H:%M")).cyan(),
+            habit.days,
+            habit.last_date.format("%m/%d")
+        );
+        if !interactive || !output::confirm("This looks like a daily habit — make it recurring?")? {
+            continue;
+        }
+
+        let day = resolve_local(habit.last_date.and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule = storage
+            .load_schedule(day)?
+            .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+        if let Some(task) = schedule.find_task_mut(&habit.task_id) {
+            make_recurring(task, Recurrence::Daily);
+            let task = task.clone();
+            storage.save_schedule(&schedule)?;
+            remember_recurring(storage, &task)?;
+            output::success(&format!("'{}' now repeats daily", habit.title));
+        }
+    }
+    if !interactive {
+        output::info("Run in a terminal to make these recurring, or re-add them with --repeat daily");
+    }
+    Ok(())
+}
+
+/// 자연어 요청을 AI 제안으로 바꿔 확인 후 오늘 스케줄에 추가
+fn ai_add_task(storage: &dyn Storage, args: &AddArgs, config: &Config) -> anyhow::Result<()> {
+    // 확인할 수 없으면 AI를 부르기 전에 중단
+    if !std::io::stdin().is_terminal() {
+        return Err(SchedError::validation("Confirming an AI suggestion needs an interactive terminal").into());
+    }
 
     let mut schedule = storage.load_today()?.unwrap_or_else(Schedule::today);
-    
-    schedule.add_task(task).map_err(|e| anyhow::anyhow!(e))?;
+
+    let provider = config
+        .ai_provider
+        .as_deref()
+        .and_then(|name| AiProvider::from_name(name).ok())
+        .unwrap_or_default();
+    let ai_config = AiConfig {
+        provider,
+        claude_path: None,
+        copilot_path: None,
+    };
+
+    let waiting = match ai_config.provider {
+        AiProvider::Claude => "Waiting for Claude",
+        AiProvider::Copilot => "Waiting for Copilot",
+    };
+    let prompt = ai::task_suggestion_prompt(&schedule, &args.title);
+    let response = with_spinner(waiting, || ai_config.ask(&prompt))?;
+    let suggestion: TaskSuggestion = ai::extract_json(&response)?;
+    let reasoning = suggestion.reasoning.clone();
+
+    let mut task = suggestion
+        .into_task(Local::now().date_naive())
+        .map_err(SchedError::Validation)?;
+    apply_explicit_add_flags(&mut task, args)?;
+    config.apply_tag_policy(&mut task.tags)?;
+
+    println!(
+        "{} {} {}",
+        "Suggested:".bold(),
+        task.title,
+        format!("{}–{}", task.start_time.format("%H:%M"), task.end_time.format("%H:%M")).cyan()
+    );
+    if !task.tags.is_empty() {
+        println!("  Tags: {}", task.tags.join(", "));
+    }
+    if let Some(notes) = &task.notes {
+        println!("  Notes: {}", notes);
+    }
+    println!("  {}", reasoning.dimmed());
+
+    if let Some(conflict) = schedule.conflicting_task(&task) {
+        return Err(SchedError::validation(format!("Suggested time conflicts with '{}'", conflict.title)).into());
+    }
+
+    if !output::confirm("Add this task?")? {
+        output::info("Not added");
+        return Ok(());
+    }
+
+    let title = task.title.clone();
+    let task_id = task.id.clone();
+    let time_range = format!("{}-{}", task.start_time.format("%H:%M"), task.end_time.format("%H:%M"));
+    schedule.add_task(task).map_err(SchedError::Validation)?;
+    schedule.add_change(ScheduleChange::task_created(title.clone(), time_range));
     schedule.sort_by_time();
+
     storage.save_schedule(&schedule)?;
+    storage.append_event(ScheduleEvent::new(EventType::TaskAdded, &task_id, &title))?;
 
     output::success(&format!("Task '{}' added successfully", title));
     Ok(())
 }
 
-fn list_tasks(storage: &JsonStorage) -> anyhow::Result<()> {
+/// `--ai` 제안 위에 직접 준 플래그(태그, 메모, 링크, 우선순위, 마감, 고정)를 덮어씀
+fn apply_explicit_add_flags(task: &mut Task, args: &AddArgs) -> anyhow::Result<()> {
+    if let Some(tags_str) = &args.tags {
+        task.tags = parse_tags(tags_str);
+    }
+    if let Some(notes) = &args.notes {
+        task.notes = Some(notes.clone());
+    }
+    if let Some(link) = &args.link {
+        task.link = Some(link.clone());
+    }
+    if let Some(priority) = &args.priority {
+        task.priority = Priority::parse(priority).map_err(SchedError::Validation)?;
+    }
+    if let Some(deadline) = &args.deadline {
+        task.deadline = Some(parse_deadline(deadline, task.start_time.date_naive())?);
+    }
+    task.fixed |= args.fixed;
+    Ok(())
+}
+
+fn list_tasks(storage: &dyn Storage, compact: bool, config: &Config) -> anyhow::Result<()> {
     let schedule = storage.load_today()?;
 
     match schedule {
         Some(mut s) => {
             s.sort_by_time();
-            output::print_schedule(&s);
+            if s.has_conflicts {
+                output::warning("Schedule has overlapping tasks.");
+            }
+            if compact {
+                output::print_schedule_compact(&s);
+            } else {
+                output::print_schedule(&s);
+
+                for warning in s.validate(config.min_break_minutes as i64, Local::now()) {
+                    match &warning {
+                        ScheduleWarning::Estimate(w) if w.issue == EstimateIssue::NegativeActual => {
+                            output::warning(&warning.message())
+                        }
+                        ScheduleWarning::Estimate(_) if !config.warn_uneven_estimates => {}
+                        ScheduleWarning::Estimate(_) | ScheduleWarning::Break(_) => {
+                            output::info(&warning.message())
+                        }
+                        ScheduleWarning::Deadline(_) => output::error(&warning.message()),
+                    }
+                }
+            }
         }
         None => {
             output::info("No schedule for today. Use 'sched add' to create tasks.");
@@ -142,42 +615,102 @@ fn list_tasks(storage: &JsonStorage) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn start_task(storage: &JsonStorage, id: Option<String>) -> anyhow::Result<()> {
+/// 대화형 선택기를 띄울 수 있는지 (`--no-input`이 아니고 TTY인 경우)
+fn can_prompt(no_input: bool) -> bool {
+    !no_input && std::io::stdin().is_terminal() && std::io::stdout().is_terminal()
+}
+
+/// 선택기에서 고른 인덱스를 작업 ID로 변환
+fn resolve_selection(candidates: &[&Task], choice: Option<usize>) -> Option<String> {
+    choice
+        .and_then(|i| candidates.get(i))
+        .map(|task| task.id.clone())
+}
+
+/// 후보 작업 중 하나를 대화형으로 선택 (취소 시 에러)
+fn pick_task_id(prompt: &str, candidates: &[&Task]) -> anyhow::Result<String> {
+    let choice = output::pick_task(prompt, candidates)?;
+    resolve_selection(candidates, choice).ok_or_else(|| SchedError::validation("No task selected").into())
+}
+
+fn start_task(storage: &dyn Storage, id: Option<String>, no_input: bool) -> anyhow::Result<()> {
     let mut schedule = storage
         .load_today()?
-        .ok_or_else(|| anyhow::anyhow!("No schedule found"))?;
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+    let startable: Vec<&Task> = schedule
+        .tasks
+        .iter()
+        .filter(|t| matches!(t.status, TaskStatus::Pending | TaskStatus::Paused))
+        .collect();
 
     let task_id = if let Some(id) = id {
         id
+    } else if can_prompt(no_input) && !startable.is_empty() {
+        pick_task_id("Start which task?", &startable)?
     } else {
         schedule
             .get_next_task()
-            .ok_or_else(|| anyhow::anyhow!("No pending tasks"))?
+            .ok_or_else(|| SchedError::not_found("No pending tasks"))?
             .id
             .clone()
     };
 
     let task = schedule
         .find_task_mut(&task_id)
-        .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+        .ok_or_else(|| SchedError::not_found("Task not found"))?;
     
     let task_title = task.title.clone();
     task.start();
     
     storage.save_schedule(&schedule)?;
+    storage.append_event(ScheduleEvent::new(EventType::TaskStarted, &task_id, &task_title))?;
 
     output::success(&format!("Started task: {}", task_title));
     Ok(())
 }
 
-fn pause_task(storage: &JsonStorage) -> anyhow::Result<()> {
+fn rebalance_command(storage: &dyn Storage, start: &str, end: &str) -> anyhow::Result<()> {
+    let mut schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+    let today = Local::now().date_naive();
+    let window_start = resolve_local(today.and_time(parse_time(start)?));
+    let window_end = resolve_local(today.and_time(parse_time(end)?));
+    if window_end <= window_start {
+        return Err(SchedError::validation("End time must be after start time").into());
+    }
+
+    // 이미 지난 시간에는 배치하지 않음 (분 단위로 맞춤)
+    let now = {
+        use chrono::Timelike;
+        let now = Local::now();
+        now.with_second(0).and_then(|t| t.with_nanosecond(0)).unwrap_or(now)
+    };
+    let moved = schedule
+        .rebalance(window_start.max(now), window_end)
+        .map_err(SchedError::Validation)?;
+
+    if moved == 0 {
+        output::info("No unstarted tasks to rebalance");
+        return Ok(());
+    }
+
+    storage.save_schedule(&schedule)?;
+    output::success(&format!("Rebalanced {} task(s)", moved));
+    output::print_schedule_compact(&schedule);
+    Ok(())
+}
+
+fn pause_task(storage: &dyn Storage) -> anyhow::Result<()> {
     let mut schedule = storage
         .load_today()?
-        .ok_or_else(|| anyhow::anyhow!("No schedule found"))?;
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
 
     let current_id = schedule
         .get_current_task()
-        .ok_or_else(|| anyhow::anyhow!("No task is currently in progress"))?
+        .ok_or_else(|| SchedError::not_found("No task is currently in progress"))?
         .id
         .clone();
 
@@ -186,32 +719,46 @@ fn pause_task(storage: &JsonStorage) -> anyhow::Result<()> {
     task.pause();
     
     storage.save_schedule(&schedule)?;
+    storage.append_event(ScheduleEvent::new(EventType::TaskPaused, &current_id, &task_title))?;
 
     output::success(&format!("Paused task: {}", task_title));
     Ok(())
 }
 
-fn complete_task(storage: &JsonStorage) -> anyhow::Result<()> {
+fn complete_task(storage: &dyn Storage, focus: Option<u8>, auto_start_next: bool) -> anyhow::Result<()> {
     use crate::models::TimeAccountability;
 
     let mut schedule = storage
         .load_today()?
-        .ok_or_else(|| anyhow::anyhow!("No schedule found"))?;
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
 
     let current_id = schedule
         .get_current_task()
-        .ok_or_else(|| anyhow::anyhow!("No task is currently in progress"))?
+        .ok_or_else(|| SchedError::not_found("No task is currently in progress"))?
         .id
         .clone();
 
     let task = schedule.find_task_mut(&current_id).unwrap();
     let task_title = task.title.clone();
     task.complete();
+    task.focus_score = focus;
 
     // Calculate time accountability
     let accountability = TimeAccountability::from_task(task);
 
+    let auto_started = if auto_start_next {
+        schedule
+            .start_next_task()
+            .map(|next| (next.id.clone(), next.title.clone()))
+    } else {
+        None
+    };
+
     storage.save_schedule(&schedule)?;
+    storage.append_event(ScheduleEvent::new(EventType::TaskCompleted, &current_id, &task_title))?;
+    if let Some((next_id, next_title)) = &auto_started {
+        storage.append_event(ScheduleEvent::new(EventType::TaskStarted, next_id, next_title))?;
+    }
 
     output::success(&format!("Completed task: {}", task_title));
 
@@ -229,13 +776,97 @@ fn complete_task(storage: &JsonStorage) -> anyhow::Result<()> {
     let completion_rate = schedule.completion_rate();
     output::info(&format!("Today's completion: {:.1}%", completion_rate));
 
+    if auto_start_next {
+        match auto_started {
+            Some((_, next_title)) => output::success(&format!("Auto-started: {}", next_title)),
+            None => output::info("No pending tasks left — the day is done"),
+        }
+    }
+
+    Ok(())
+}
+
+/// `sched now` 한 줄 상태
+#[derive(Debug, serde::Serialize)]
+struct NowStatus {
+    /// "current", "next", "idle"
+    state: &'static str,
+    title: Option<String>,
+    /// current: 남은 분 (음수면 초과), next: 시작까지 남은 분
+    minutes: Option<i64>,
+    line: String,
+}
+
+fn now_command(storage: &dyn Storage, format: &str) -> anyhow::Result<()> {
+    let schedule = storage.load_today()?;
+    let status = now_status(schedule.as_ref(), Local::now());
+
+    match format.to_lowercase().as_str() {
+        "json" => println!("{}", serde_json::to_string(&status)?),
+        "text" if std::io::stdout().is_terminal() => {
+            let line = match status.state {
+                "current" => status.line.green().to_string(),
+                "next" => status.line.yellow().to_string(),
+                _ => status.line.dimmed().to_string(),
+            };
+            println!("{}", line);
+        }
+        "text" => println!("{}", status.line),
+        _ => {
+            return Err(SchedError::validation(format!(
+                "Unknown format: {} (supported: text, json)",
+                format
+            ))
+            .into())
+        }
+    }
+
     Ok(())
 }
 
-fn show_status(storage: &JsonStorage) -> anyhow::Result<()> {
+/// 지금 가장 중요한 한 가지: 진행 중 작업, 다음 작업, 없으면 idle
+fn now_status(schedule: Option<&Schedule>, now: DateTime<Local>) -> NowStatus {
+    if let Some(task) = schedule.and_then(|s| s.get_current_task()) {
+        let worked = task.active_minutes_at(now).unwrap_or(0);
+        let remaining = task.estimated_duration_minutes - worked;
+        let left = if remaining >= 0 {
+            format!("{} left", format_minutes(remaining))
+        } else {
+            format!("{} over", format_minutes(-remaining))
+        };
+
+        return NowStatus {
+            state: "current",
+            title: Some(task.title.clone()),
+            minutes: Some(remaining),
+            line: format!("▶ {} · {}", task.title, left),
+        };
+    }
+
+    if let Some(task) = schedule.and_then(|s| s.get_next_task()) {
+        let until = (task.start_time - now).num_minutes().max(0);
+        let when = if until == 0 { "now".to_string() } else { format!("in {}", format_minutes(until)) };
+
+        return NowStatus {
+            state: "next",
+            title: Some(task.title.clone()),
+            minutes: Some(until),
+            line: format!("⏱ {} {}", task.title, when),
+        };
+    }
+
+    NowStatus {
+        state: "idle",
+        title: None,
+        minutes: None,
+        line: "idle".to_string(),
+    }
+}
+
+fn show_status(storage: &dyn Storage) -> anyhow::Result<()> {
     let schedule = storage
         .load_today()?
-        .ok_or_else(|| anyhow::anyhow!("No schedule found"))?;
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
 
     if let Some(current) = schedule.get_current_task() {
         println!("\n{}", "Current Task:".bold());
@@ -249,6 +880,16 @@ fn show_status(storage: &JsonStorage) -> anyhow::Result<()> {
         output::print_task(next);
     }
 
+    let now = Local::now();
+    if let Some(missed) = schedule.first_unstarted_overdue(now) {
+        println!();
+        output::warning(&format!(
+            "Should have started: {} at {}.",
+            missed.title,
+            missed.start_time.format("%H:%M")
+        ));
+    }
+
     let completion_rate = schedule.completion_rate();
     println!(
         "\n{}: {:.1}%",
@@ -256,589 +897,3295 @@ fn show_status(storage: &JsonStorage) -> anyhow::Result<()> {
         completion_rate
     );
 
+    if let Some(finish) = schedule.projected_finish_time(now) {
+        let format = if finish.date_naive() == now.date_naive() { "%H:%M" } else { "%m-%d %H:%M" };
+        println!("At this rate you finish at {}.", finish.format(format));
+    }
+
+    if let Some(score) = schedule.efficiency_score_so_far(now) {
+        println!(
+            "{}: {:.1}% {} · {:.1}% full day",
+            "Efficiency".bold(),
+            score,
+            "(so far)".dimmed(),
+            schedule.efficiency_score()
+        );
+    }
+
     Ok(())
 }
 
-fn delete_task(storage: &JsonStorage, id: String) -> anyhow::Result<()> {
+fn delete_task(storage: &dyn Storage, id: Option<String>, no_input: bool) -> anyhow::Result<()> {
     let mut schedule = storage
         .load_today()?
-        .ok_or_else(|| anyhow::anyhow!("No schedule found"))?;
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+    let id = match id {
+        Some(id) => id,
+        None if can_prompt(no_input) && !schedule.tasks.is_empty() => {
+            let tasks: Vec<&Task> = schedule.tasks.iter().collect();
+            pick_task_id("Delete which task?", &tasks)?
+        }
+        None => return Err(SchedError::validation("Task id required").into()),
+    };
 
     let task = schedule
         .remove_task(&id)
-        .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+        .ok_or_else(|| SchedError::not_found("Task not found"))?;
 
     storage.save_schedule(&schedule)?;
+    storage.append_event(ScheduleEvent::new(EventType::TaskDeleted, &task.id, &task.title))?;
 
     output::success(&format!("Deleted task: {}", task.title));
     Ok(())
 }
 
-fn widget_command() -> anyhow::Result<()> {
-    crate::tui::run_widget()
-}
-
-fn ui_command(storage: JsonStorage) -> anyhow::Result<()> {
-    use crossterm::{
-        execute,
-        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-    };
-    use ratatui::{backend::CrosstermBackend, Terminal};
-    use std::io;
+fn progress_task(storage: &dyn Storage, id: String, percent: f64) -> anyhow::Result<()> {
+    if !(0.0..=100.0).contains(&percent) {
+        return Err(SchedError::validation("Progress must be between 0 and 100").into());
+    }
 
-    // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    let mut schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
 
-    // Create app and run
-    let mut app = crate::tui::App::new(storage)?;
-    let res = app.run(&mut terminal);
+    let task = schedule
+        .find_task_mut(&id)
+        .ok_or_else(|| SchedError::not_found("Task not found"))?;
 
-    // Restore terminal
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
+    task.set_progress(percent / 100.0).map_err(SchedError::Validation)?;
+    let task_title = task.title.clone();
 
-    res
-}
+    storage.save_schedule(&schedule)?;
 
-fn stats_command(storage: &JsonStorage, week: bool) -> anyhow::Result<()> {
-    if week {
-        show_weekly_stats(storage)
-    } else {
-        show_daily_stats(storage)
-    }
+    output::success(&format!("{}: {:.0}% done", task_title, percent));
+    Ok(())
 }
 
-fn show_daily_stats(storage: &JsonStorage) -> anyhow::Result<()> {
-    let schedule = storage
+fn interrupt_task(storage: &dyn Storage, id: &str, reason: String, pause: bool) -> anyhow::Result<()> {
+    let mut schedule = storage
         .load_today()?
-        .ok_or_else(|| anyhow::anyhow!("No schedule found"))?;
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
 
-    println!("\n{}", "📊 Daily Statistics".bold());
-    println!("{}\n", Local::now().format("%Y-%m-%d (%A)").to_string().cyan());
+    let task = schedule
+        .find_task_mut(id)
+        .ok_or_else(|| SchedError::not_found("Task not found"))?;
 
-    let completion = schedule.completion_rate();
-    let completed = schedule.tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
-    let total = schedule.tasks.len();
+    if reason.trim().is_empty() {
+        return Err(SchedError::validation("Describe what interrupted the task").into());
+    }
 
-    println!("{}: {}/{} ({:.1}%)", 
-        "Tasks Completed".bold(), 
-        completed, 
-        total, 
-        completion
-    );
+    task.log_interruption(reason.trim().to_string(), Local::now());
+    let paused = pause && task.status == TaskStatus::InProgress;
+    if paused {
+        task.pause();
+    }
+    let task_title = task.title.clone();
+    let count = task.interruptions.len();
+
+    storage.save_schedule(&schedule)?;
+    if paused {
+        storage.append_event(ScheduleEvent::new(EventType::TaskPaused, id, &task_title))?;
+    }
+
+    output::success(&format!("Logged interruption #{} on {}: {}", count, task_title, reason.trim()));
+    if paused {
+        output::info(&format!("Paused task: {}", task_title));
+    }
+    Ok(())
+}
+
+fn skip_task(storage: &dyn Storage, id: Option<String>) -> anyhow::Result<()> {
+    use crate::models::TimeAccountability;
+
+    let mut schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+    let id = match id {
+        Some(id) => id,
+        None => schedule
+            .get_current_task()
+            .ok_or_else(|| SchedError::not_found("No task is currently in progress"))?
+            .id
+            .clone(),
+    };
+
+    let task = schedule
+        .find_task_mut(&id)
+        .ok_or_else(|| SchedError::not_found("Task not found"))?;
+
+    match task.status {
+        TaskStatus::Completed => return Err(SchedError::validation("Task is already completed").into()),
+        TaskStatus::Skipped => return Err(SchedError::validation("Task is already skipped").into()),
+        _ => {}
+    }
+
+    task.skip();
+    let task_title = task.title.clone();
+    let accountability = TimeAccountability::from_task(task);
+
+    storage.save_schedule(&schedule)?;
+    storage.append_event(ScheduleEvent::new(EventType::TaskSkipped, &id, &task_title))?;
+
+    output::success(&format!("Skipped task: {}", task_title));
+    if let Some(feedback) = accountability.feedback_message() {
+        println!("{}", feedback.yellow());
+    }
+    Ok(())
+}
+
+fn block_task(storage: &dyn Storage, id: String, reason: Option<String>) -> anyhow::Result<()> {
+    let mut schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+    let task = schedule
+        .find_task_mut(&id)
+        .ok_or_else(|| SchedError::not_found("Task not found"))?;
+
+    if task.status == TaskStatus::Completed {
+        return Err(SchedError::validation("Task is already completed").into());
+    }
+
+    task.block(reason);
+    let task_title = task.title.clone();
+
+    storage.save_schedule(&schedule)?;
+    storage.append_event(ScheduleEvent::new(EventType::TaskBlocked, &id, &task_title))?;
+
+    output::success(&format!("Blocked task: {}", task_title));
+    Ok(())
+}
+
+fn unblock_task(storage: &dyn Storage, id: String) -> anyhow::Result<()> {
+    let mut schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+    let task = schedule
+        .find_task_mut(&id)
+        .ok_or_else(|| SchedError::not_found("Task not found"))?;
+
+    if task.status != TaskStatus::Blocked {
+        return Err(SchedError::validation("Task is not blocked").into());
+    }
+
+    task.unblock();
+    let task_title = task.title.clone();
+
+    storage.save_schedule(&schedule)?;
+    storage.append_event(ScheduleEvent::new(EventType::TaskUnblocked, &id, &task_title))?;
+
+    output::success(&format!("Unblocked task: {}", task_title));
+    Ok(())
+}
+
+fn focus_task(storage: &dyn Storage, id: String) -> anyhow::Result<()> {
+    let mut schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+    let task = schedule.focus_task(&id).map_err(SchedError::Validation)?;
+    let task_title = task.title.clone();
+    let started = task.is_current();
+
+    storage.save_schedule(&schedule)?;
+
+    output::success(&format!("Focused task: {}", task_title));
+    if !started {
+        output::info("It will show as the current task once started");
+    }
+    Ok(())
+}
+
+fn merge_command(storage: &dyn Storage, first: &str, second: &str) -> anyhow::Result<()> {
+    let mut schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+    let originals = [first, second]
+        .iter()
+        .map(|id| {
+            schedule
+                .find_task(id)
+                .map(|t| (t.id.clone(), t.title.clone(), format_time_range(t)))
+                .ok_or_else(|| SchedError::not_found(format!("Task not found: {}", id)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let merged_id = schedule.merge_tasks(first, second).map_err(SchedError::Validation)?;
+    let merged = schedule.find_task(&merged_id).expect("merged task was just added");
+    let (title, time_range) = (merged.title.clone(), format_time_range(merged));
+    let old_times = originals.iter().map(|(_, _, range)| range.as_str()).collect::<Vec<_>>().join(", ");
+    schedule.add_change(ScheduleChange::tasks_merged(title.clone(), old_times, time_range.clone()));
+
+    storage.save_schedule(&schedule)?;
+    for (id, original_title, _) in &originals {
+        storage.append_event(ScheduleEvent::new(EventType::TaskDeleted, id, original_title))?;
+    }
+    storage.append_event(ScheduleEvent::new(EventType::TaskAdded, &merged_id, &title))?;
+
+    output::success(&format!("Merged into '{}' ({})", title, time_range));
+    Ok(())
+}
+
+/// 작업 시간대 ("HH:MM-HH:MM")
+fn format_time_range(task: &Task) -> String {
+    format!("{}-{}", task.start_time.format("%H:%M"), task.end_time.format("%H:%M"))
+}
+
+fn carryover_command(storage: &dyn Storage) -> anyhow::Result<()> {
+    let yesterday = Local::now() - chrono::Duration::days(1);
+    let previous = storage
+        .load_schedule(yesterday)?
+        .ok_or_else(|| SchedError::not_found("No schedule found for yesterday"))?;
+
+    let mut schedule = storage.load_today()?.unwrap_or_else(Schedule::today);
+    let existing_ids: Vec<String> = schedule.tasks.iter().map(|t| t.id.clone()).collect();
+
+    let (carried, skipped) = schedule.carry_over_from(&previous);
+    storage.save_schedule(&schedule)?;
+
+    for task in schedule.tasks.iter().filter(|t| !existing_ids.contains(&t.id)) {
+        storage.append_event(ScheduleEvent::new(EventType::TaskAdded, &task.id, &task.title))?;
+    }
+
+    output::success(&format!("Carried over {} task(s) from yesterday", carried));
+    if skipped > 0 {
+        output::info(&format!("Skipped {} task(s) due to time conflicts", skipped));
+    }
+
+    Ok(())
+}
+
+/// 오늘의 첫 실행: 요일별 기본 템플릿 적용 후 어제 작업 이월 제안
+fn start_of_day(storage: &dyn Storage, config: &Config) -> anyhow::Result<()> {
+    pause_left_running(storage)?;
+
+    if storage.load_today()?.is_some() {
+        return Ok(());
+    }
+
+    if let Some(name) = apply_default_template(storage, config, Local::now())? {
+        output::notice(&format!("Applied default template '{}'", name));
+    }
+
+    let added = materialize_recurring(storage, Local::now())?;
+    if added > 0 {
+        output::notice(&format!("Added {} recurring task(s)", added));
+    }
+
+    offer_carryover(storage)
+}
+
+/// 마지막으로 저장한 날(보통 어제)부터 진행 중으로 남은 작업을 일시정지하고 저장
+fn pause_left_running(storage: &dyn Storage) -> anyhow::Result<()> {
+    let Some(current) = storage.load_current()? else {
+        return Ok(());
+    };
+    let mut schedule = storage.load_schedule(current.date)?.unwrap_or(current);
+
+    let stale = schedule.pause_stale_tasks(Local::now().date_naive());
+    if stale.is_empty() {
+        return Ok(());
+    }
+    for (id, title) in &stale {
+        output::notice(&format!("Paused '{}', left running since {}", title, schedule.date.format("%Y-%m-%d")));
+        storage.append_event(ScheduleEvent::new(EventType::TaskPaused, id, title))?;
+    }
+    storage.save_schedule(&schedule)?;
+    Ok(())
+}
+
+/// 최근 인스턴스의 수정 사항을 반복 원본에 반영할 때 거슬러 올라가는 일수
+const RECURRENCE_LOOKBACK_DAYS: i64 = 31;
+
+/// 반복 작업 원본을 recurring.json에 기록 (같은 `recurrence_id`면 교체)
+fn remember_recurring(storage: &dyn Storage, task: &Task) -> anyhow::Result<()> {
+    if task.recurrence.is_none() || task.recurrence_id.is_none() {
+        return Ok(());
+    }
+    let mut sources = storage.load_recurring()?;
+    sources.retain(|s| s.recurrence_id != task.recurrence_id);
+    sources.push(task.clone());
+    storage.save_recurring(&sources)?;
+    Ok(())
+}
+
+/// 반복 작업을 해당 날짜 스케줄에 추가
+///
+/// recurring.json의 원본을 기준으로 하되, 최근 스케줄에 더 새 인스턴스가 있으면
+/// 그 인스턴스를 새 원본으로 기록합니다. 추가한 개수를 반환합니다.
+fn materialize_recurring(storage: &dyn Storage, date: DateTime<Local>) -> anyhow::Result<usize> {
+    let day = date.date_naive();
+    let mut latest: HashMap<String, Task> = storage
+        .load_recurring()?
+        .into_iter()
+        .filter_map(|t| Some((t.recurrence_id.clone()?, t)))
+        .collect();
+    let mut changed = false;
+    for schedule in load_schedules_between(
+        storage,
+        day - chrono::Duration::days(RECURRENCE_LOOKBACK_DAYS),
+        day - chrono::Duration::days(1),
+    ) {
+        for task in schedule.tasks {
+            if let (Some(id), Some(_)) = (task.recurrence_id.clone(), &task.recurrence) {
+                // 같은 날이거나 더 나중 인스턴스가 원본 (그날 수정한 내용도 반영)
+                let newer = latest.get(&id).map_or(true, |known| {
+                    known.start_time.date_naive() <= task.start_time.date_naive()
+                        && serde_json::to_value(known).ok() != serde_json::to_value(&task).ok()
+                });
+                if newer {
+                    latest.insert(id, task);
+                    changed = true;
+                }
+            }
+        }
+    }
+    if changed {
+        let mut sources: Vec<Task> = latest.values().cloned().collect();
+        sources.sort_by_key(|t| t.start_time.time());
+        storage.save_recurring(&sources)?;
+    }
+
+    let mut sources: Vec<Task> = latest.into_values().filter(|t| t.is_due_on(day)).collect();
+    sources.sort_by_key(|t| t.start_time.time());
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let mut schedule = storage.load_schedule(date)?.unwrap_or_else(|| Schedule::new(date));
+    let added = schedule.materialize_recurring(&sources);
+    if added > 0 {
+        storage.save_schedule(&schedule)?;
+    }
+    Ok(added)
+}
+
+/// 지정한 날짜(기본 오늘)에 반복 작업 인스턴스 추가
+fn expand_command(storage: &dyn Storage, date: Option<String>) -> anyhow::Result<()> {
+    let date = match date {
+        Some(date_str) => parse_date(&date_str)?,
+        None => Local::now().date_naive(),
+    };
+
+    let added = materialize_recurring(storage, resolve_local(date.and_hms_opt(0, 0, 0).unwrap()))?;
+    if added == 0 {
+        output::info(&format!("No recurring tasks to add on {}", date.format("%Y-%m-%d")));
+    } else {
+        output::success(&format!("Added {} recurring task(s) to {}", added, date.format("%Y-%m-%d")));
+    }
+
+    Ok(())
+}
+
+/// 해당 날짜에 스케줄이 없으면 요일에 맞는 기본 템플릿으로 생성
+///
+/// 적용한 템플릿 이름을 반환합니다.
+fn apply_default_template(
+    storage: &dyn Storage,
+    config: &Config,
+    date: DateTime<Local>,
+) -> anyhow::Result<Option<String>> {
+    if storage.load_schedule(date)?.is_some() {
+        return Ok(None);
+    }
+
+    let Some(name) = config.template_for(date.weekday()) else {
+        return Ok(None);
+    };
+
+    let Some(template) = storage.load_template(name)? else {
+        output::error(&format!("Default template '{}' not found", name));
+        return Ok(None);
+    };
+
+    let mut schedule = Schedule::new(date);
+    template.apply_to(&mut schedule);
+    storage.save_schedule(&schedule)?;
+
+    for task in &schedule.tasks {
+        storage.append_event(ScheduleEvent::new(EventType::TaskAdded, &task.id, &task.title))?;
+    }
+
+    Ok(Some(template.name))
+}
+
+fn export_command(
+    storage: &dyn Storage,
+    format: String,
+    date: Option<String>,
+    out: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    if !format.eq_ignore_ascii_case("ics") {
+        return Err(SchedError::validation(format!("Unknown export format: {} (supported: ics)", format)).into());
+    }
+
+    let date = match date {
+        Some(date_str) => parse_date(&date_str)?,
+        None => Local::now().date_naive(),
+    };
+    let day = resolve_local(date.and_hms_opt(0, 0, 0).unwrap());
+    let schedule = storage
+        .load_schedule(day)?
+        .ok_or_else(|| SchedError::not_found(format!("No schedule found for {}", date)))?;
+
+    let calendar = ics::export_ics(&schedule);
+    match out {
+        Some(path) if storage.is_dry_run() => {
+            output::info(&format!("Dry run: would write {} task(s) to {}", schedule.tasks.len(), path.display()));
+        }
+        Some(path) => {
+            std::fs::write(&path, calendar)?;
+            output::success(&format!(
+                "Exported {} task(s) to {}",
+                schedule.tasks.len(),
+                path.display()
+            ));
+        }
+        None => print!("{}", calendar),
+    }
+
+    Ok(())
+}
+
+fn import_command(storage: &dyn Storage, file: PathBuf) -> anyhow::Result<()> {
+    let content = std::fs::read_to_string(&file)?;
+    let imported = ics::import_ics(&content);
+
+    let mut added = 0;
+    let mut conflicts = 0;
+    let mut schedules: Vec<Schedule> = Vec::new();
+
+    for task in imported.tasks {
+        let date = task.start_time.date_naive();
+        let index = match schedules.iter().position(|s| s.date.date_naive() == date) {
+            Some(index) => index,
+            None => {
+                let day = resolve_local(date.and_hms_opt(0, 0, 0).unwrap());
+                schedules.push(storage.load_schedule(day)?.unwrap_or_else(|| Schedule::new(day)));
+                schedules.len() - 1
+            }
+        };
+
+        let (task_id, title) = (task.id.clone(), task.title.clone());
+        if schedules[index].add_task(task).is_ok() {
+            storage.append_event(ScheduleEvent::new(EventType::TaskAdded, &task_id, &title))?;
+            added += 1;
+        } else {
+            conflicts += 1;
+        }
+    }
+
+    for schedule in &mut schedules {
+        schedule.sort_by_time();
+        storage.save_schedule(schedule)?;
+    }
+
+    output::success(&format!("Imported {} task(s) from {}", added, file.display()));
+    if conflicts > 0 {
+        output::info(&format!("Skipped {} event(s) due to time conflicts", conflicts));
+    }
+    if imported.skipped > 0 {
+        output::info(&format!(
+            "Skipped {} all-day or untimed event(s)",
+            imported.skipped
+        ));
+    }
+
+    Ok(())
+}
+
+fn backup_command(storage: &dyn Storage, out: Option<PathBuf>) -> anyhow::Result<()> {
+    let out = out.unwrap_or_else(|| {
+        PathBuf::from(format!(
+            "scheduler-backup-{}.zip",
+            Local::now().format("%Y%m%d-%H%M%S")
+        ))
+    });
+
+    if storage.is_dry_run() {
+        output::info(&format!("Dry run: would back up {} to {}", storage.data_dir().display(), out.display()));
+        return Ok(());
+    }
+
+    let config_path = Config::config_path().ok();
+    let count = backup::create_backup(storage.data_dir(), config_path.as_deref(), &out)?;
+
+    output::success(&format!("Backed up {} file(s) to {}", count, out.display()));
+    Ok(())
+}
+
+fn restore_command(
+    storage: &dyn Storage,
+    archive: PathBuf,
+    data_dir: Option<PathBuf>,
+    yes: bool,
+) -> anyhow::Result<()> {
+    if !archive.exists() {
+        return Err(SchedError::not_found(format!("Archive not found: {}", archive.display())).into());
+    }
+
+    // 다른 디렉토리로 복원할 때는 현재 설정 파일을 건드리지 않음
+    let config_path = if data_dir.is_none() { Config::config_path().ok() } else { None };
+    let target = data_dir.unwrap_or_else(|| storage.data_dir().to_path_buf());
+
+    if storage.is_dry_run() {
+        output::info(&format!("Dry run: would restore {} into {}", archive.display(), target.display()));
+        return Ok(());
+    }
+
+    if !yes {
+        if !std::io::stdin().is_terminal() {
+            return Err(SchedError::validation("Restore needs confirmation; pass --yes").into());
+        }
+
+        let prompt = format!("Overwrite data in {} with {}?", target.display(), archive.display());
+        if !output::confirm(&prompt)? {
+            output::info("Restore cancelled");
+            return Ok(());
+        }
+    }
+
+    let count = backup::restore_backup(&archive, &target, config_path.as_deref())?;
+    output::success(&format!("Restored {} file(s) into {}", count, target.display()));
+    Ok(())
+}
+
+fn repair_command(storage: &dyn Storage, check: bool) -> anyhow::Result<()> {
+    let report = if check { storage.verify()? } else { storage.repair()? };
+
+    if report.is_clean() {
+        output::success("Data directory looks healthy");
+        return Ok(());
+    }
+
+    if report.current_out_of_sync {
+        if check {
+            output::warning("current.json does not match today's history file");
+        } else {
+            output::success("Re-synced current.json with today's history file");
+        }
+    }
+
+    if !report.orphaned_stats.is_empty() {
+        let verb = if check { "Found" } else { "Removed" };
+        output::info(&format!(
+            "{} {} stats file(s) without a schedule",
+            verb,
+            report.orphaned_stats.len()
+        ));
+        for path in &report.orphaned_stats {
+            println!("  {}", path.display());
+        }
+    }
+
+    for path in &report.corrupt_files {
+        output::warning(&format!("Cannot read {} (left untouched)", path.display()));
+    }
+
+    Ok(())
+}
+
+fn goal_command(storage: &dyn Storage, action: GoalAction) -> anyhow::Result<()> {
+    let today = Local::now().date_naive();
+    let week = WeeklyGoals::week_key(today);
+    let mut goals = storage
+        .load_goals(&week)?
+        .unwrap_or_else(|| WeeklyGoals::for_date(today));
+
+    match action {
+        GoalAction::Add {
+            description,
+            target,
+            tag,
+        } => {
+            let target_minutes = target.as_deref().map(parse_goal_target).transpose()?;
+            if target_minutes.is_some() && tag.is_none() {
+                output::info("Without --tag the target can only be met by `sched goal done`");
+            }
+
+            goals.goals.push(WeeklyGoal::new(description.clone(), target_minutes, tag));
+            storage.save_goals(&goals)?;
+            output::success(&format!("Added goal for {}: {}", week, description));
+        }
+        GoalAction::List => {
+            let (monday, _) = WeeklyGoals::week_range(today);
+            let schedules = load_schedules_between(storage, monday, today);
+            print!("{}", goal_report(&goals, &schedules, false)?);
+        }
+        GoalAction::Done { number } => {
+            let goal = number
+                .checked_sub(1)
+                .and_then(|index| goals.goals.get_mut(index))
+                .ok_or_else(|| SchedError::not_found(format!("No goal #{} this week", number)))?;
+            goal.done = true;
+            let description = goal.description.clone();
+            storage.save_goals(&goals)?;
+            output::success(&format!("Goal done: {}", description));
+        }
+        GoalAction::Review { last } => {
+            let date = if last { today - chrono::Duration::weeks(1) } else { today };
+            let goals = if last {
+                storage
+                    .load_goals(&WeeklyGoals::week_key(date))?
+                    .unwrap_or_else(|| WeeklyGoals::for_date(date))
+            } else {
+                goals
+            };
+            let (monday, sunday) = WeeklyGoals::week_range(date);
+            let schedules = load_schedules_between(storage, monday, sunday.min(today));
+            print!("{}", goal_report(&goals, &schedules, true)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// 목표 시간 파싱 ("20h", "90m", "1h30m", 숫자만 있으면 분)
+fn parse_goal_target(value: &str) -> anyhow::Result<i64> {
+    let invalid = || SchedError::validation(format!("Invalid target '{}'. Use e.g. 20h, 90m or 1h30m", value));
+    let value = value.trim().to_lowercase();
+
+    if let Ok(minutes) = value.parse::<i64>() {
+        return Ok(minutes);
+    }
+
+    let (hours, rest) = match value.split_once('h') {
+        Some((hours, rest)) => (hours.parse::<i64>().map_err(|_| invalid())?, rest),
+        None => (0, value.as_str()),
+    };
+    let minutes = match rest.strip_suffix('m') {
+        Some(minutes) => minutes.parse::<i64>().map_err(|_| invalid())?,
+        None if rest.is_empty() => 0,
+        None => return Err(invalid().into()),
+    };
+
+    let total = hours * 60 + minutes;
+    if total <= 0 {
+        return Err(invalid().into());
+    }
+    Ok(total)
+}
+
+/// 주간 목표와 진행 상황 (`review`면 달성 요약 포함)
+fn goal_report(goals: &WeeklyGoals, schedules: &[Schedule], review: bool) -> anyhow::Result<String> {
+    let mut out = String::new();
+    writeln!(out, "{}", format!("Goals for {}", goals.week).bold())?;
+
+    if goals.goals.is_empty() {
+        writeln!(out, "  No goals set. Add one with `sched goal add`.")?;
+        return Ok(out);
+    }
+
+    for (index, goal) in goals.goals.iter().enumerate() {
+        let mark = if goal.is_met(schedules) { "✓".green() } else { "·".normal() };
+        write!(out, "  {} {}. {}", mark, index + 1, goal.description)?;
+
+        match (goal.completed_minutes(schedules), goal.target_minutes) {
+            (Some(done), Some(target)) => write!(
+                out,
+                "  {} / {} ({:.0}%)",
+                format_minutes(done),
+                format_minutes(target),
+                done as f64 / target as f64 * 100.0
+            )?,
+            (Some(done), None) => write!(out, "  {}", format_minutes(done))?,
+            (None, _) => {}
+        }
+        if let Some(tag) = &goal.tag {
+            write!(out, "  #{}", tag)?;
+        }
+        writeln!(out)?;
+    }
+
+    if review {
+        let met = goals.goals.iter().filter(|g| g.is_met(schedules)).count();
+        writeln!(out)?;
+        writeln!(out, "Met {} of {} goal(s)", met, goals.goals.len())?;
+    }
+
+    Ok(out)
+}
+
+fn tag_command(storage: &dyn Storage, action: TagAction) -> anyhow::Result<()> {
+    match action {
+        TagAction::List { days } => {
+            let tags = storage.known_tags(days)?;
+            if tags.is_empty() {
+                output::info(&format!("No tags used in the last {} days", days));
+            }
+            for (tag, count) in tags {
+                println!("  {:<20} {}", tag, count);
+            }
+        }
+        TagAction::Rename { old, new, days } => {
+            let (tasks, days_touched) = rename_tag(storage, &old, &new, days)?;
+            if tasks == 0 {
+                output::info(&format!("No tasks tagged '{}' in the last {} days", old, days));
+            } else {
+                output::success(&format!(
+                    "Renamed '{}' to '{}' on {} task(s) across {} day(s)",
+                    old, new, tasks, days_touched
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 최근 `days`일 작업의 태그 이름을 바꾸고 (작업 수, 날짜 수) 반환
+fn rename_tag(storage: &dyn Storage, old: &str, new: &str, days: i64) -> anyhow::Result<(usize, usize)> {
+    let new = new.trim();
+    if new.is_empty() {
+        return Err(SchedError::validation("New tag name cannot be empty").into());
+    }
+
+    let mut tasks_touched = 0;
+    let mut days_touched = 0;
+
+    for mut schedule in load_recent_schedules(storage, days) {
+        let renamed = schedule
+            .tasks
+            .iter_mut()
+            .map(|task| task.rename_tag(old, new))
+            .filter(|renamed| *renamed)
+            .count();
+
+        if renamed > 0 {
+            storage.save_schedule(&schedule)?;
+            tasks_touched += renamed;
+            days_touched += 1;
+        }
+    }
+
+    Ok((tasks_touched, days_touched))
+}
+
+fn retitle_command(
+    storage: &dyn Storage,
+    pattern: &str,
+    replacement: &str,
+    regex: bool,
+    days: i64,
+) -> anyhow::Result<()> {
+    let changed = retitle_tasks(storage, pattern, replacement, regex, days)?;
+    if changed.is_empty() {
+        output::info(&format!("No task titles match '{}' in the last {} days", pattern, days));
+        return Ok(());
+    }
+
+    for (date, count) in &changed {
+        println!("  {}  {} task(s)", date.format("%Y-%m-%d"), count);
+    }
+    let total: usize = changed.iter().map(|(_, count)| count).sum();
+    output::success(&format!("Retitled {} task(s) across {} day(s)", total, changed.len()));
+    Ok(())
+}
+
+/// 최근 `days`일 작업 제목의 `pattern`을 `replacement`로 바꾸고 날짜별 바뀐 작업 수 반환
+///
+/// 제목이 비게 되는 작업이 하나라도 있으면 아무것도 저장하지 않습니다.
+fn retitle_tasks(
+    storage: &dyn Storage,
+    pattern: &str,
+    replacement: &str,
+    regex: bool,
+    days: i64,
+) -> anyhow::Result<Vec<(NaiveDate, usize)>> {
+    if pattern.is_empty() {
+        return Err(SchedError::validation("Pattern cannot be empty").into());
+    }
+    let matcher = if regex {
+        Regex::new(pattern).map_err(|e| SchedError::validation(format!("Invalid regex: {}", e)))?
+    } else {
+        Regex::new(&regex::escape(pattern))?
+    };
+    // 리터럴 모드에서는 치환 문자열의 `$`도 그대로 넣음
+    let replace = |title: &str| {
+        if regex {
+            matcher.replace_all(title, replacement).into_owned()
+        } else {
+            matcher.replace_all(title, regex::NoExpand(replacement)).into_owned()
+        }
+    };
+
+    let mut updated = Vec::new();
+    for mut schedule in load_recent_schedules(storage, days) {
+        let mut count = 0;
+        for task in schedule.tasks.iter_mut().filter(|t| matcher.is_match(&t.title)) {
+            let title = replace(&task.title);
+            if title.trim().is_empty() {
+                return Err(SchedError::validation(format!(
+                    "Replacing would leave '{}' without a title",
+                    task.title
+                ))
+                .into());
+            }
+            if title != task.title {
+                task.title = title;
+                count += 1;
+            }
+        }
+        if count > 0 {
+            updated.push((schedule, count));
+        }
+    }
+
+    let mut changed = Vec::new();
+    for (schedule, count) in updated {
+        storage.save_schedule(&schedule)?;
+        changed.push((schedule.date.date_naive(), count));
+    }
+    Ok(changed)
+}
+
+fn template_command(storage: &dyn Storage, action: TemplateAction) -> anyhow::Result<()> {
+    match action {
+        TemplateAction::Save { name } => {
+            let schedule = storage
+                .load_today()?
+                .ok_or_else(|| SchedError::not_found("No schedule for today"))?;
+
+            let template = ScheduleTemplate::from_schedule(name, &schedule);
+            storage.save_template(&template)?;
+            output::success(&format!(
+                "Saved template '{}' ({} tasks)",
+                template.name,
+                template.tasks.len()
+            ));
+        }
+        TemplateAction::Apply { name } => {
+            let template = storage
+                .load_template(&name)?
+                .ok_or_else(|| SchedError::not_found(format!("Template '{}' not found", name)))?;
+
+            let mut schedule = storage.load_today()?.unwrap_or_else(Schedule::today);
+            let existing_ids: Vec<String> = schedule.tasks.iter().map(|t| t.id.clone()).collect();
+            let (added, skipped) = template.apply_to(&mut schedule);
+            storage.save_schedule(&schedule)?;
+
+            for task in schedule.tasks.iter().filter(|t| !existing_ids.contains(&t.id)) {
+                storage.append_event(ScheduleEvent::new(EventType::TaskAdded, &task.id, &task.title))?;
+            }
+
+            output::success(&format!("Added {} task(s) from '{}'", added, name));
+            if skipped > 0 {
+                output::info(&format!("Skipped {} task(s) due to time conflicts", skipped));
+            }
+        }
+        TemplateAction::List => {
+            let names = storage.list_templates()?;
+            if names.is_empty() {
+                output::info("No templates saved");
+            }
+            for name in names {
+                println!("  {}", name);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 새 날에 오늘을 바꾸는 첫 대화형 명령에서 어제 미완료 작업을 옮길지 묻기
+fn offer_carryover(storage: &dyn Storage) -> anyhow::Result<()> {
+    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+        return Ok(());
+    }
+
+    let yesterday = Local::now() - chrono::Duration::days(1);
+    let unfinished = match storage.load_schedule(yesterday)? {
+        Some(previous) => previous
+            .tasks
+            .iter()
+            .filter(|t| {
+                matches!(
+                    t.status,
+                    TaskStatus::Pending
+                        | TaskStatus::InProgress
+                        | TaskStatus::Paused
+                        | TaskStatus::Blocked
+                )
+            })
+            .count(),
+        None => 0,
+    };
+
+    if unfinished == 0 {
+        return Ok(());
+    }
+
+    let prompt = format!(
+        "{} unfinished task(s) from yesterday. Carry them over?",
+        unfinished
+    );
+    // 거절하면 아무것도 저장하지 않음 (오늘 스케줄이 생기기 전까지는 다시 물어봄)
+    if output::confirm(&prompt)? {
+        carryover_command(storage)
+    } else {
+        Ok(())
+    }
+}
+
+fn widget_command(storage: Box<dyn Storage>) -> anyhow::Result<()> {
+    crate::tui::run_widget(storage)
+}
+
+fn profile_command(storage: &dyn Storage, active: Option<&str>, action: ProfileAction, config: &Config) -> anyhow::Result<()> {
+    match action {
+        ProfileAction::List => {
+            if config.profiles.is_empty() {
+                output::info("No profiles configured. Add them under [profiles] in config.toml");
+                return Ok(());
+            }
+
+            for name in config.profile_names() {
+                let marker = if Some(name) == active { "*" } else { " " };
+                println!("{} {:<12} {}", marker, name, config.profiles[name].display());
+            }
+        }
+        ProfileAction::Current => {
+            println!("Profile: {}", active.unwrap_or("default"));
+            println!("Data directory: {}", storage.data_dir().display());
+        }
+    }
+
+    Ok(())
+}
+
+fn open_command(storage: &dyn Storage, config: bool) -> anyhow::Result<()> {
+    let config_path = Config::config_path()?;
+    let dir = open_target(storage.data_dir(), &config_path, config);
+
+    if std::io::stdout().is_terminal() && has_file_manager() {
+        match std::process::Command::new(FILE_MANAGER).arg(&dir).spawn() {
+            Ok(_) => {
+                output::success(&format!("Opened {}", dir.display()));
+                return Ok(());
+            }
+            Err(e) => log::warn!("Could not run {}: {}", FILE_MANAGER, e),
+        }
+    }
+
+    println!("{}", dir.display());
+    Ok(())
+}
+
+fn version_command(storage: &dyn Storage, verbose: bool) -> anyhow::Result<()> {
+    // 전역 -v/--verbose를 줬을 때만 환경 요약 출력
+    if !verbose {
+        println!("sched {}", env!("CARGO_PKG_VERSION"));
+        return Ok(());
+    }
+
+    let providers: Vec<_> = AiProvider::ALL
+        .into_iter()
+        .map(|provider| {
+            let version = AiConfig::cli_version(&provider);
+            (provider, version)
+        })
+        .collect();
+    print!("{}", version_summary(storage.data_dir(), &Config::config_path()?, &providers));
+    Ok(())
+}
+
+/// 버그 리포트용 환경 요약 (버전, 데이터 디렉토리, 설정 파일, AI CLI)
+fn version_summary(
+    data_dir: &std::path::Path,
+    config_path: &std::path::Path,
+    providers: &[(AiProvider, CliVersion)],
+) -> String {
+    let mut out = format!("sched {}\n", env!("CARGO_PKG_VERSION"));
+    out.push_str(&format!("Data dir: {}\n", data_dir.display()));
+    let missing = if config_path.exists() { "" } else { " (not created)" };
+    out.push_str(&format!("Config:   {}{}\n", config_path.display(), missing));
+    out.push_str("AI CLIs:\n");
+    for (provider, version) in providers {
+        match version {
+            Ok((path, version)) => {
+                out.push_str(&format!("  {:<8} {} ({})\n", provider.name(), version, path.display()))
+            }
+            Err(e) => out.push_str(&format!("  {:<8} unavailable: {}\n", provider.name(), e)),
+        }
+    }
+    out
+}
+
+/// 열 디렉토리 (`config`면 config.toml이 있는 디렉토리)
+fn open_target(data_dir: &std::path::Path, config_path: &std::path::Path, config: bool) -> PathBuf {
+    if config {
+        config_path.parent().unwrap_or(config_path).to_path_buf()
+    } else {
+        data_dir.to_path_buf()
+    }
+}
+
+/// 디렉토리를 여는 플랫폼별 명령
+#[cfg(target_os = "macos")]
+const FILE_MANAGER: &str = "open";
+#[cfg(target_os = "windows")]
+const FILE_MANAGER: &str = "explorer";
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const FILE_MANAGER: &str = "xdg-open";
+
+/// 파일 관리자를 띄울 수 있는 환경인지 (리눅스 등은 디스플레이가 있어야 함)
+fn has_file_manager() -> bool {
+    if cfg!(any(target_os = "macos", target_os = "windows")) {
+        return true;
+    }
+    ["DISPLAY", "WAYLAND_DISPLAY"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()))
+}
+
+fn ui_command(storage: Box<dyn Storage>) -> anyhow::Result<()> {
+    use crossterm::{
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    };
+    use ratatui::{backend::CrosstermBackend, Terminal};
+    use std::io;
+
+    // Setup terminal
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    // Create app and run
+    let mut app = crate::tui::App::new(storage)?;
+    let res = app.run(&mut terminal);
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    res
+}
+
+fn stats_command(storage: &dyn Storage, week: bool, heatmap_days: Option<i64>, config: &Config) -> anyhow::Result<()> {
+    if let Some(days) = heatmap_days {
+        let days = days.max(1);
+        print!("{}", heatmap_report(&load_recent_schedules(storage, days), days)?);
+        Ok(())
+    } else if week {
+        show_weekly_stats(storage)
+    } else {
+        show_daily_stats(storage, &config.report_precision)
+    }
+}
+
+fn show_daily_stats(storage: &dyn Storage, fmt: &ReportPrecision) -> anyhow::Result<()> {
+    let schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+    println!("\n{}", "📊 Daily Statistics".bold());
+    println!("{}\n", Local::now().format("%Y-%m-%d (%A)").to_string().cyan());
+
+    let completion = schedule.completion_rate();
+    let completed = schedule.tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+    let total = schedule.tasks.len();
+
+    println!("{}: {}/{} ({})", 
+        "Tasks Completed".bold(), 
+        completed, 
+        total, 
+        output::format_percent(completion, fmt.percent_decimals)
+    );
 
     let progress_bar = create_progress_bar(completion);
     println!("{}\n", progress_bar.green());
 
-    if let Some(accuracy) = schedule.time_accuracy() {
-        println!("{}: {:.1}%", "Time Accuracy".bold(), accuracy);
-        let accuracy_bar = create_progress_bar(accuracy);
-        println!("{}\n", accuracy_bar.blue());
+    if let Some(accuracy) = schedule.time_accuracy() {
+        println!("{}: {}", "Time Accuracy".bold(), output::format_percent(accuracy, fmt.percent_decimals));
+        let accuracy_bar = create_progress_bar(accuracy);
+        println!("{}\n", accuracy_bar.blue());
+    }
+
+    let focus_time: i64 = schedule
+        .tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Completed)
+        .filter_map(|t| t.actual_duration_minutes)
+        .sum();
+
+    println!("{}: {}", 
+        "Focus Time".bold(), 
+        output::format_duration(focus_time, fmt.duration_style)
+    );
+
+    let pending = schedule.tasks.iter().filter(|t| t.status == TaskStatus::Pending).count();
+    let paused = schedule.tasks.iter().filter(|t| t.status == TaskStatus::Paused).count();
+    let blocked = schedule.tasks.iter().filter(|t| t.status == TaskStatus::Blocked).count();
+
+    println!("{}: {}", "Pending".yellow(), pending);
+    println!("{}: {}", "Paused".yellow(), paused);
+    if blocked > 0 {
+        println!("{}: {}", "Blocked".magenta(), blocked);
+    }
+
+    Ok(())
+}
+
+fn show_weekly_stats(storage: &dyn Storage) -> anyhow::Result<()> {
+    use chrono::Duration;
+
+    println!("\n{}", "📊 Weekly Statistics".bold());
+    println!("{}\n", "Last 7 days".cyan());
+
+    let today = Local::now();
+    
+    for i in (0..7).rev() {
+        let date = today - Duration::days(i);
+        let schedule = storage.load_schedule(date)?;
+
+        let day_str = date.format("%a").to_string();
+        
+        if let Some(schedule) = schedule {
+            let completion = schedule.completion_rate();
+            let bar = create_progress_bar(completion);
+            
+            let indicator = if date.date_naive() == today.date_naive() {
+                "◄── Today"
+            } else {
+                ""
+            };
+
+            println!("  {} {} {:.0}% {}", 
+                day_str.cyan(), 
+                bar.green(), 
+                completion,
+                indicator.yellow()
+            );
+        } else {
+            println!("  {} {}", day_str.dimmed(), "No data".dimmed());
+        }
+    }
+
+    Ok(())
+}
+
+fn streak_command(storage: &dyn Storage, format: &str, oneline: bool) -> anyhow::Result<()> {
+    let streak = storage.load_streak()?;
+
+    if oneline {
+        println!("🔥 {}", streak.current_streak);
+        return Ok(());
+    }
+
+    match format.to_lowercase().as_str() {
+        "text" => {}
+        "json" => {
+            println!("{}", streak_json(&streak)?);
+            return Ok(());
+        }
+        _ => {
+            return Err(SchedError::validation(format!(
+                "Unknown format: {} (supported: text, json)",
+                format
+            ))
+            .into())
+        }
+    }
+
+    println!("\n{}", "🔥 Streak Information".bold());
+    println!();
+
+    println!("{}: {} days", 
+        "Current Streak".bold().green(), 
+        streak.current_streak
+    );
+
+    println!("{}: {} days", 
+        "Best Streak".bold().yellow(), 
+        streak.best_streak
+    );
+
+    println!("{}: {}", 
+        "Last Updated".dimmed(), 
+        streak.last_update.format("%Y-%m-%d %H:%M")
+    );
+
+    let fire_count = (streak.current_streak / 7).min(5) as usize;
+    if fire_count > 0 {
+        println!("\n{}", "🔥".repeat(fire_count));
+    }
+
+    Ok(())
+}
+
+/// Streak JSON (상태 표시줄 연동용)
+fn streak_json(streak: &StreakInfo) -> anyhow::Result<String> {
+    let value = serde_json::json!({
+        "current": streak.current_streak,
+        "best": streak.best_streak,
+        "last_update": streak.last_update.to_rfc3339(),
+    });
+
+    Ok(serde_json::to_string(&value)?)
+}
+
+fn create_progress_bar(percentage: f64) -> String {
+    // 요일/퍼센트 표시 등 막대 외 내용이 차지하는 폭을 제외
+    let width = output::bar_width(30, 20);
+    let filled = output::bar_length(percentage, width);
+    let empty = width - filled;
+    
+    format!("{}{}  {:.0}%",
+        "█".repeat(filled),
+        "░".repeat(empty),
+        percentage
+    )
+}
+
+fn pomodoro_command(storage: &dyn Storage, action: super::PomodoroAction) -> anyhow::Result<()> {
+    use super::PomodoroAction;
+    use crate::models::PomodoroSession;
+
+    match action {
+        PomodoroAction::Start => {
+            let mut schedule = storage
+                .load_today()?
+                .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+            let current = schedule
+                .get_current_task()
+                .ok_or_else(|| SchedError::not_found("No task is currently in progress"))?;
+
+            let current_id = current.id.clone();
+            let task = schedule.find_task_mut(&current_id).unwrap();
+
+            if task.pomodoro.is_none() {
+                let mut session = PomodoroSession::new(task.estimated_duration_minutes);
+                if let Some(every) = task.custom_long_break_every {
+                    session.long_break_every = every;
+                }
+                task.pomodoro = Some(session);
+            }
+
+            let pomodoro = task.pomodoro.as_mut().unwrap();
+            pomodoro.start_pomodoro();
+
+            // Extract info before save
+            let task_title = task.title.clone();
+            let current_pomodoro = pomodoro.completed_pomodoros + 1;
+            let total_pomodoros = pomodoro.total_pomodoros;
+            let duration = pomodoro.pomodoro_duration;
+
+            storage.save_schedule(&schedule)?;
+
+            output::success(&format!(
+                "Started Pomodoro {}/{} for '{}'",
+                current_pomodoro,
+                total_pomodoros,
+                task_title
+            ));
+
+            output::info(&format!("Focus for {} minutes", duration));
+        }
+
+        PomodoroAction::Complete => {
+            let mut schedule = storage
+                .load_today()?
+                .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+            let current = schedule
+                .get_current_task()
+                .ok_or_else(|| SchedError::not_found("No task is currently in progress"))?;
+
+            let current_id = current.id.clone();
+            let task = schedule.find_task_mut(&current_id).unwrap();
+
+            let pomodoro = task
+                .pomodoro
+                .as_mut()
+                .ok_or_else(|| SchedError::not_found("No Pomodoro session active"))?;
+
+            pomodoro.complete_pomodoro();
+
+            // Extract info before save
+            let completed = pomodoro.completed_pomodoros;
+            let total = pomodoro.total_pomodoros;
+            let is_complete = pomodoro.is_complete();
+            let break_duration = pomodoro.next_break_duration();
+
+            storage.save_schedule(&schedule)?;
+
+            output::success(&format!(
+                "Completed Pomodoro {}/{}",
+                completed,
+                total
+            ));
+
+            if is_complete {
+                output::info("All Pomodoros completed! 🎉");
+            } else {
+                output::info(&format!("Take a {} minute break", break_duration));
+            }
+        }
+
+        PomodoroAction::Status => {
+            let schedule = storage
+                .load_today()?
+                .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+            let current = schedule
+                .get_current_task()
+                .ok_or_else(|| SchedError::not_found("No task is currently in progress"))?;
+
+            if let Some(pomodoro) = &current.pomodoro {
+                println!("\n{}", "🍅 Pomodoro Status".bold());
+                println!("Task: {}", current.title.cyan());
+                println!(
+                    "Progress: {}/{}",
+                    pomodoro.completed_pomodoros, pomodoro.total_pomodoros
+                );
+
+                if let Some(elapsed) = pomodoro.elapsed_minutes() {
+                    let remaining = pomodoro.remaining_minutes().unwrap();
+                    println!(
+                        "Current: {}m elapsed, {}m remaining",
+                        elapsed, remaining
+                    );
+                }
+
+                let bar = create_progress_bar(
+                    (pomodoro.completed_pomodoros as f64 / pomodoro.total_pomodoros as f64) * 100.0,
+                );
+                println!("{}", bar.green());
+            } else {
+                output::info("No Pomodoro session. Use 'sched pomodoro start'");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn claude_command(storage: &dyn Storage, action: ClaudeAction, no_cache: bool, config: &Config) -> anyhow::Result<()> {
+    use super::ClaudeAction;
+
+    let schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found for today"))?;
+
+    let cache = ContextCache::new(storage.data_dir().join("cache").join("claude_context.json"));
+    let context = if storage.is_dry_run() {
+        ScheduleContext::collect(&schedule, config)
+    } else if storage.is_encrypted() {
+        // 캐시는 평문이므로 암호화된 저장소에서는 쓰지 않고 남은 것도 지움
+        cache.clear()?;
+        ScheduleContext::collect(&schedule, config)
+    } else {
+        if no_cache {
+            cache.clear()?;
+        }
+        let (context, hit) = cache.collect(&schedule, config);
+        log::debug!("Claude context cache {}", if hit { "hit" } else { "miss" });
+        context
+    };
+    log::debug!("Collected Claude context (git info: {})", context.git_info.is_some());
+
+    match action {
+        ClaudeAction::Ask { question } => {
+            let template = PromptTemplate::task_assistant();
+            let mut vars = HashMap::new();
+            vars.insert("context".to_string(), context.to_markdown());
+            vars.insert("question".to_string(), question);
+
+            let prompt = template.render(&vars);
+
+            println!("\n{}\n", "=".repeat(80).bright_blue());
+            println!("{}", "Claude Prompt".bright_cyan().bold());
+            println!("{}\n", "=".repeat(80).bright_blue());
+            println!("{}", prompt);
+            println!("\n{}\n", "=".repeat(80).bright_blue());
+
+            output::info("Copy the prompt above and paste it to Claude Code");
+        }
+
+        ClaudeAction::Validate => {
+            let template = PromptTemplate::schedule_validation();
+            let mut vars = HashMap::new();
+            vars.insert("context".to_string(), context.to_markdown());
+
+            let prompt = template.render(&vars);
+
+            println!("\n{}\n", "=".repeat(80).bright_blue());
+            println!("{}", "Schedule Validation Prompt".bright_cyan().bold());
+            println!("{}\n", "=".repeat(80).bright_blue());
+            println!("{}", prompt);
+            println!("\n{}\n", "=".repeat(80).bright_blue());
+
+            output::info("Copy the prompt above and paste it to Claude Code");
+        }
+
+        ClaudeAction::Optimize { situation } => {
+            let template = PromptTemplate::optimization();
+            let mut vars = HashMap::new();
+            vars.insert("context".to_string(), context.to_markdown());
+            vars.insert(
+                "situation".to_string(),
+                situation.unwrap_or_else(|| "Running behind schedule".to_string()),
+            );
+
+            let prompt = template.render(&vars);
+
+            println!("\n{}\n", "=".repeat(80).bright_blue());
+            println!("{}", "Optimization Prompt".bright_cyan().bold());
+            println!("{}\n", "=".repeat(80).bright_blue());
+            println!("{}", prompt);
+            println!("\n{}\n", "=".repeat(80).bright_blue());
+
+            output::info("Copy the prompt above and paste it to Claude Code");
+        }
+
+        ClaudeAction::Context { format } => {
+            match format.to_lowercase().as_str() {
+                "json" => {
+                    println!("{}", context.to_json()?);
+                }
+                "markdown" | "md" => {
+                    println!("{}", context.to_markdown());
+                }
+                _ => {
+                    output::error(&format!("Unknown format: {}", format));
+                    output::info("Supported formats: json, markdown");
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn report_command(storage: &dyn Storage, args: ReportArgs, config: &Config) -> anyhow::Result<()> {
+    let ReportArgs {
+        week,
+        month,
+        json,
+        all,
+        segments,
+        peak,
+        focus,
+    } = args;
+
+    if month {
+        output::info("Monthly report not yet implemented");
+        return Ok(());
+    }
+
+    if focus {
+        let today = Local::now().date_naive();
+        let from = today - chrono::Duration::days(FOCUS_REPORT_DAYS - 1);
+        print!("{}", focus_report(&load_schedules_between(storage, from, today), from, today)?);
+        return Ok(());
+    }
+
+    if peak {
+        print!("{}", peak_report(&load_recent_schedules(storage, PEAK_REPORT_DAYS))?);
+        return Ok(());
+    }
+
+    let fmt = config.report_precision.clone();
+    let idle = config.idle_window();
+
+    if all {
+        println!("\n{}", "▌ Today".bold().cyan());
+        match storage.load_today()? {
+            Some(schedule) => print!("{}", daily_report(&schedule, &fmt, idle)?),
+            None => output::info("No schedule found for today"),
+        }
+
+        println!("\n{}", "▌ This Week".bold().cyan());
+        print!("{}", weekly_report(storage, &fmt, idle)?);
+
+        println!("\n{}", "▌ Trend".bold().cyan());
+        print!("{}", efficiency_trend(storage, 7, &fmt, idle, config.trend_window_days)?);
+    } else if week {
+        print!("{}", weekly_report(storage, &fmt, idle)?);
+    } else {
+        let schedule = storage
+            .load_today()?
+            .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+        if json {
+            println!("{}", daily_report_json(&schedule, idle)?);
+            return Ok(());
+        }
+
+        print!("{}", daily_report(&schedule, &fmt, idle)?);
+        if segments {
+            print!("{}", segments_report(&schedule)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// 피크 시간 분석에 사용하는 기간 (일)
+const PEAK_REPORT_DAYS: i64 = 30;
+
+/// 집중도 추이에 사용하는 기간 (일)
+const FOCUS_REPORT_DAYS: i64 = 7;
+
+/// 날짜별 평균 집중도 (점수가 있는 날만)
+fn focus_averages(schedules: &[Schedule]) -> Vec<(NaiveDate, f64)> {
+    schedules
+        .iter()
+        .filter_map(|s| s.average_focus_score().map(|avg| (s.date.date_naive(), avg)))
+        .collect()
+}
+
+/// `from`~`to` 날짜별 평균 집중도 막대 그래프와 추이
+fn focus_report(schedules: &[Schedule], from: NaiveDate, to: NaiveDate) -> anyhow::Result<String> {
+    let averages = focus_averages(schedules);
+    let mut out = String::new();
+
+    writeln!(out, "\n{}", "Focus Trend".bold().cyan())?;
+    writeln!(out, "{}", output::separator("=", 60))?;
+
+    // "MM/DD | " 와 " 10.0" 을 제외한 폭
+    let bar_width = output::bar_width(14, 40);
+
+    for date in from.iter_days().take_while(|d| *d <= to) {
+        match averages.iter().find(|(d, _)| *d == date) {
+            Some((_, avg)) => {
+                let bar = "█".repeat(output::bar_length(avg * 10.0, bar_width));
+                let colored_bar = if *avg >= 7.0 {
+                    bar.green()
+                } else if *avg >= 4.0 {
+                    bar.yellow()
+                } else {
+                    bar.red()
+                };
+                writeln!(out, "{} | {:<width$} {:.1}", date.format("%m/%d"), colored_bar, avg, width = bar_width)?;
+            }
+            None => writeln!(out, "{} | {} (no scores)", date.format("%m/%d"), "░".repeat(bar_width).bright_black())?,
+        }
+    }
+
+    writeln!(out, "{}", output::separator("=", 60))?;
+
+    if averages.is_empty() {
+        writeln!(out, "No focus scores yet. Rate tasks with `sched complete --focus <1-10>`.")?;
+        return Ok(out);
+    }
+
+    let values: Vec<f64> = averages.iter().map(|(_, avg)| *avg).collect();
+    let overall = values.iter().sum::<f64>() / values.len() as f64;
+    writeln!(out, "Average Focus: {:.1}/10  {}", overall, output::sparkline(&values))?;
+
+    Ok(out)
+}
+
+/// 오늘을 포함한 최근 `days`일 중 저장된 스케줄
+fn load_recent_schedules(storage: &dyn Storage, days: i64) -> Vec<Schedule> {
+    let today = Local::now().date_naive();
+    load_schedules_between(storage, today - chrono::Duration::days(days - 1), today)
+}
+
+/// `from`부터 `to`까지 (양 끝 포함) 저장된 스케줄
+fn load_schedules_between(storage: &dyn Storage, from: NaiveDate, to: NaiveDate) -> Vec<Schedule> {
+    from.iter_days()
+        .take_while(|date| *date <= to)
+        .filter_map(|date| {
+            storage
+                .load_schedule(resolve_local(date.and_hms_opt(0, 0, 0).unwrap()))
+                .ok()
+                .flatten()
+        })
+        .collect()
+}
+
+/// 시간대별 집중 시간 막대 그래프 (가장 생산적인 시간대 강조)
+fn peak_report(schedules: &[Schedule]) -> anyhow::Result<String> {
+    use crate::models::busiest_hour;
+
+    let mut totals = [0i64; 24];
+    for schedule in schedules {
+        for (hour, minutes) in schedule.focus_minutes_by_hour().iter().enumerate() {
+            totals[hour] += minutes;
+        }
+    }
+
+    let mut out = String::new();
+    writeln!(out, "\n{}", "Peak Focus Hours".bold().green())?;
+    writeln!(out, "{}", output::separator("=", 50))?;
+
+    let Some(peak) = busiest_hour(&totals) else {
+        writeln!(out, "{}", "No completed tasks in range.".dimmed())?;
+        return Ok(out);
+    };
+
+    let max = totals[peak as usize];
+    // "HH:00 " 와 " 999h 59m" 을 제외한 폭
+    let bar_width = output::bar_width(16, 40);
+    for (hour, minutes) in totals.iter().enumerate().filter(|(_, m)| **m > 0) {
+        let bar = "█".repeat(output::bar_length(*minutes as f64 / max as f64 * 100.0, bar_width));
+        let bar = if hour as u32 == peak { bar.green().bold() } else { bar.cyan() };
+        writeln!(out, "{:02}:00 {:<width$} {}", hour, bar, format_minutes(*minutes), width = bar_width)?;
+    }
+
+    writeln!(out, "{}", output::separator("=", 50))?;
+    writeln!(
+        out,
+        "Most productive hour: {} ({} over {} day(s))",
+        format!("{:02}:00–{:02}:00", peak, (peak + 1) % 24).green().bold(),
+        format_minutes(max),
+        schedules.len()
+    )?;
+
+    Ok(out)
+}
+
+/// 히트맵 칸 음영 (없음, 약함 → 강함)
+const HEATMAP_SHADES: [char; 5] = ['·', '░', '▒', '▓', '█'];
+
+/// 최댓값 대비 비율로 음영 고르기
+fn heatmap_shade(minutes: i64, max: i64) -> char {
+    if minutes <= 0 || max <= 0 {
+        return HEATMAP_SHADES[0];
+    }
+    let levels = HEATMAP_SHADES.len() - 1;
+    let level = ((minutes as f64 / max as f64) * levels as f64).ceil() as usize;
+    HEATMAP_SHADES[level.clamp(1, levels)]
+}
+
+fn calendar_command(storage: &dyn Storage, month: Option<&str>, idle: Option<WakingHours>) -> anyhow::Result<()> {
+    let first = match month {
+        Some(month) => parse_month(month)?,
+        None => Local::now().date_naive().with_day(1).unwrap(),
+    };
+    let last = month_grid(first).iter().flatten().flatten().max().copied().unwrap_or(first);
+    let schedules = load_schedules_between(storage, first, last);
+    print!("{}", calendar_report(first, &schedules, idle)?);
+    Ok(())
+}
+
+/// "YYYY-MM"을 그 달 1일로 파싱
+fn parse_month(month: &str) -> anyhow::Result<NaiveDate> {
+    NaiveDate::parse_from_str(&format!("{}-01", month.trim()), "%Y-%m-%d")
+        .map_err(|_| SchedError::validation("Invalid month format. Use YYYY-MM (e.g., 2025-11)").into())
+}
+
+/// 월요일부터 시작하는 주 단위 달력 (그 달이 아닌 칸은 None)
+fn month_grid(first: NaiveDate) -> Vec<[Option<NaiveDate>; 7]> {
+    let mut weeks = Vec::new();
+    let mut week = [None; 7];
+    for date in first.iter_days().take_while(|d| d.month() == first.month()) {
+        let column = date.weekday().num_days_from_monday() as usize;
+        week[column] = Some(date);
+        if column == 6 {
+            weeks.push(std::mem::take(&mut week));
+        }
+    }
+    if week.iter().any(Option::is_some) {
+        weeks.push(week);
+    }
+    weeks
+}
+
+/// 날짜별 효율 등급을 색으로 표시한 달력
+fn calendar_report(first: NaiveDate, schedules: &[Schedule], idle: Option<WakingHours>) -> anyhow::Result<String> {
+    let scores: HashMap<NaiveDate, (f64, &'static str)> = schedules
+        .iter()
+        .filter(|s| !s.tasks.is_empty())
+        .map(|s| {
+            let daily = day_accountability(s, idle);
+            (s.date.date_naive(), (daily.efficiency_score(), daily.grade()))
+        })
+        .collect();
+
+    let mut out = String::new();
+    writeln!(out, "\n{}", first.format("%B %Y").to_string().bold().green())?;
+    writeln!(out, "{}", ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"].map(|d| format!("{:<6}", d)).join(" ").trim_end().dimmed())?;
+
+    for week in month_grid(first) {
+        let cells: Vec<String> = week
+            .iter()
+            .map(|cell| match cell {
+                None => " ".repeat(6),
+                Some(date) => match scores.get(date) {
+                    None => format!("{:>2}    ", date.day()),
+                    Some((score, grade)) => {
+                        let text = format!("{:>2} {:<3}", date.day(), grade);
+                        if *score >= 90.0 {
+                            text.green().to_string()
+                        } else if *score >= 70.0 {
+                            text.yellow().to_string()
+                        } else {
+                            text.red().to_string()
+                        }
+                    }
+                },
+            })
+            .collect();
+        writeln!(out, "{}", cells.join(" ").trim_end())?;
+    }
+
+    if !scores.is_empty() {
+        let average = scores.values().map(|(score, _)| score).sum::<f64>() / scores.len() as f64;
+        writeln!(out, "\n{} days tracked, average efficiency {:.1}%", scores.len(), average)?;
+    }
+    Ok(out)
+}
+
+/// 요일 × 시간대 집중 시간 히트맵
+fn heatmap_report(schedules: &[Schedule], days: i64) -> anyhow::Result<String> {
+    const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+    let mut grid = [[0i64; 24]; 7];
+    for schedule in schedules {
+        for (day, hours) in schedule.focus_minutes_by_weekday_hour().iter().enumerate() {
+            for (hour, minutes) in hours.iter().enumerate() {
+                grid[day][hour] += minutes;
+            }
+        }
+    }
+
+    let mut out = String::new();
+    writeln!(out, "\n{}", format!("Focus Heatmap (last {} days)", days).bold().green())?;
+    writeln!(out, "{}", output::separator("=", 60))?;
+
+    let max = grid.iter().flatten().copied().max().unwrap_or(0);
+    if max == 0 {
+        writeln!(out, "{}", "No completed tasks in range.".dimmed())?;
+        return Ok(out);
     }
 
-    let focus_time: i64 = schedule
+    // 칸당 2글자, 3시간마다 시각 표시
+    let header: String = (0..24).step_by(3).map(|hour| format!("{:<6}", format!("{:02}", hour))).collect();
+    writeln!(out, "    {}", header.trim_end().dimmed())?;
+    for (day, hours) in grid.iter().enumerate() {
+        let cells: String = hours
+            .iter()
+            .map(|minutes| heatmap_shade(*minutes, max).to_string().repeat(2))
+            .collect();
+        let total: i64 = hours.iter().sum();
+        writeln!(out, "{} {} {}", WEEKDAYS[day], cells.green(), format_minutes(total))?;
+    }
+
+    writeln!(out, "{}", output::separator("=", 60))?;
+    writeln!(
+        out,
+        "{} none  {} low  {} {} {} most ({} in one hour slot)",
+        HEATMAP_SHADES[0], HEATMAP_SHADES[1], HEATMAP_SHADES[2], HEATMAP_SHADES[3], HEATMAP_SHADES[4],
+        format_minutes(max)
+    )?;
+
+    Ok(out)
+}
+
+/// 시간대별 획득/낭비 시간 (작은 막대 그래프 포함)
+fn segments_report(schedule: &Schedule) -> anyhow::Result<String> {
+    use crate::models::DailyAccountability;
+
+    const BAR_CELLS: usize = 12;
+
+    let segments = DailyAccountability::by_segment(&schedule.tasks);
+    let max = segments
+        .iter()
+        .map(|(_, perf)| perf.earned_time.max(perf.wasted_time))
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let bar = |minutes: i64| output::bar_length(minutes as f64 / max as f64 * 100.0, BAR_CELLS);
+
+    let mut out = String::new();
+    writeln!(out, "\n{}", "By Time of Day:".bold())?;
+    writeln!(out, "{}", output::separator("-", 50))?;
+
+    for (segment, perf) in &segments {
+        writeln!(
+            out,
+            "  {:<9} {:<width$} +{:>3}m  {:<width$} -{:>3}m",
+            segment.label(),
+            "█".repeat(bar(perf.earned_time)).green(),
+            perf.earned_time,
+            "█".repeat(bar(perf.wasted_time)).red(),
+            perf.wasted_time,
+            width = BAR_CELLS
+        )?;
+    }
+
+    Ok(out)
+}
+
+/// 최근 7일 주간 리포트
+fn weekly_report(storage: &dyn Storage, fmt: &ReportPrecision, idle: Option<WakingHours>) -> anyhow::Result<String> {
+    let mut out = String::new();
+    let today = Local::now().date_naive();
+    let mut total_planned = 0i64;
+    let mut total_earned = 0i64;
+    let mut total_wasted = 0i64;
+    let mut total_bonus = 0i64;
+    let mut total_penalty = 0i64;
+    let mut bucket_counts = [0usize; DURATION_BUCKETS.len()];
+    let mut week_tasks = Vec::new();
+
+    writeln!(out, "\n{}", tr("report.weekly_title").bold().green())?;
+    writeln!(out, "{}", output::separator("=", 50))?;
+
+    for days_ago in (0..7).rev() {
+        let date = today - chrono::Duration::days(days_ago);
+        let date_time = resolve_local(date.and_hms_opt(0, 0, 0).unwrap());
+
+        if let Ok(Some(schedule)) = storage.load_schedule(date_time) {
+            let daily = day_accountability(&schedule, idle);
+
+            total_planned += daily.total_planned;
+            total_earned += daily.total_earned;
+            total_wasted += daily.total_wasted;
+            total_bonus += daily.total_bonus;
+            total_penalty += daily.total_penalty;
+
+            for task in schedule.tasks.iter().filter(|t| t.status == TaskStatus::Completed) {
+                let minutes = task
+                    .actual_duration_minutes
+                    .unwrap_or(task.estimated_duration_minutes);
+                let bucket = bucket_duration(minutes);
+                if let Some(i) = DURATION_BUCKETS.iter().position(|b| *b == bucket) {
+                    bucket_counts[i] += 1;
+                }
+            }
+
+            let score = daily.efficiency_score();
+            let grade = daily.grade();
+            let _score_color = if score >= 90.0 {
+                "green"
+            } else if score >= 70.0 {
+                "yellow"
+            } else {
+                "red"
+            };
+
+            writeln!(out, 
+                "{}: {} {} ({}) | {}: {} | {}: {}",
+                date.format("%Y-%m-%d"),
+                tr("report.efficiency"),
+                output::format_percent(score, fmt.percent_decimals),
+                grade,
+                tr("report.earned"),
+                output::format_duration(daily.net_earned(), fmt.duration_style),
+                tr("report.wasted"),
+                output::format_duration(daily.total_wasted, fmt.duration_style)
+            )?;
+
+            week_tasks.extend(schedule.tasks);
+        }
+    }
+
+    writeln!(out, "{}", output::separator("=", 50))?;
+    let week_score = if total_planned > 0 {
+        ((total_earned + total_bonus - total_penalty) as f64 / total_planned as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    writeln!(out, "\n{}", format!("{}:", tr("report.week_summary")).bold())?;
+    let duration = |minutes| output::format_duration(minutes, fmt.duration_style);
+    writeln!(out, "  {}: {}", tr("report.total_planned"), duration(total_planned))?;
+    writeln!(out, "  {}: {}", tr("report.total_earned"), duration(total_earned))?;
+    writeln!(out, "  {}: {}", tr("report.total_bonus"), duration(total_bonus).green())?;
+    writeln!(out, "  {}: {}", tr("report.total_penalty"), duration(total_penalty).yellow())?;
+    writeln!(out, "  {}: {}", tr("report.total_wasted"), duration(total_wasted).red())?;
+    writeln!(
+        out,
+        "\n  {}: {}",
+        tr("report.overall_efficiency"),
+        output::format_percent(week_score, fmt.percent_decimals)
+    )?;
+    if let Some(delay) = crate::models::average_start_delay(&week_tasks) {
+        writeln!(out, "  {}: {}", tr("report.avg_start_delay"), format_start_delay(delay))?;
+    }
+
+    // Completed task duration histogram
+    writeln!(out, "\n{}", format!("{}:", tr("report.task_durations")).bold())?;
+    let max_count = bucket_counts.iter().copied().max().unwrap_or(0);
+    let bar_width = output::bar_width(20, 30);
+    for (bucket, count) in DURATION_BUCKETS.iter().zip(bucket_counts) {
+        let percentage = if max_count > 0 {
+            count as f64 / max_count as f64 * 100.0
+        } else {
+            0.0
+        };
+        writeln!(out, 
+            "  {:<7} {} {}",
+            bucket,
+            "█".repeat(output::bar_length(percentage, bar_width)).cyan(),
+            count
+        )?;
+    }
+
+    Ok(out)
+}
+
+/// 일일 리포트 (작업별 피드백 포함)
+fn daily_report(schedule: &Schedule, fmt: &ReportPrecision, idle: Option<WakingHours>) -> anyhow::Result<String> {
+    let mut out = String::new();
+    let daily = day_accountability(schedule, idle);
+
+    let label = |id| format!("{}:", tr(id));
+
+    writeln!(out, "\n{}", tr("report.daily_title").bold().green())?;
+    writeln!(out, "{}", output::separator("=", 50))?;
+    writeln!(out, "{} {}", label("report.date"), daily.date.format("%Y-%m-%d"))?;
+    writeln!(out)?;
+    let duration = |minutes| output::format_duration(minutes, fmt.duration_style);
+    writeln!(out, "  {} {}", label("report.total_planned"), duration(daily.total_planned))?;
+    writeln!(out, "  {} {}", label("report.earned").green(), duration(daily.total_earned))?;
+
+    if daily.total_bonus > 0 {
+        writeln!(out, "  {} +{} 🎉", label("report.bonus").bright_green(), duration(daily.total_bonus))?;
+    }
+    if daily.total_penalty > 0 {
+        writeln!(out, "  {} -{} ⚠", label("report.penalty").yellow(), duration(daily.total_penalty))?;
+    }
+    if daily.total_wasted > 0 {
+        writeln!(out, "  {} -{} ✗", label("report.wasted").red(), duration(daily.total_wasted))?;
+    }
+    if daily.total_idle > 0 {
+        let idle = tr_with("report.idle", &[("time", &duration(daily.total_idle))]);
+        writeln!(out, "    {}", idle.dimmed())?;
+    }
+
+    writeln!(out)?;
+    writeln!(out, "  {} {}", label("report.net_earned").bold(), duration(daily.net_earned()))?;
+    writeln!(out, "  {} {} ({})", label("report.efficiency_score").bold(), output::format_percent(daily.efficiency_score(), fmt.percent_decimals), daily.grade())?;
+    if let Some(delay) = schedule.average_start_delay() {
+        writeln!(out, "  {} {}", label("report.avg_start_delay").bold(), format_start_delay(delay))?;
+    }
+
+    // Task breakdown
+    writeln!(out, "\n{}", label("report.task_breakdown").bold())?;
+    writeln!(out, "{}", output::separator("-", 50))?;
+
+    for task in &schedule.tasks {
+        use crate::models::TimeAccountability;
+        let perf = TimeAccountability::from_task(task);
+
+        if let Some(msg) = perf.feedback_message() {
+            let colored_msg = if perf.bonus_time > 0 {
+                msg.green()
+            } else if perf.penalty_time > 0 {
+                msg.yellow()
+            } else if perf.wasted_time > 0 {
+                msg.red()
+            } else {
+                msg.green()
+            };
+            writeln!(out, "  {} - {}", task.title, colored_msg)?;
+        }
+    }
+
+    let interrupted: Vec<&Task> = schedule.tasks.iter().filter(|t| !t.interruptions.is_empty()).collect();
+    if !interrupted.is_empty() {
+        let total: usize = interrupted.iter().map(|t| t.interruptions.len()).sum();
+        writeln!(out, "\n{} ({})", label("report.interruptions").bold(), tr_with("report.interruptions_total", &[("count", &total)]))?;
+        writeln!(out, "{}", output::separator("-", 50))?;
+        for task in interrupted {
+            writeln!(out, "  {} - {}", task.title, task.interruptions.len())?;
+        }
+    }
+
+    Ok(out)
+}
+
+/// 하루 통계 (`idle`이 있으면 그 시간대의 지나간 빈 시간도 낭비로 계산)
+fn day_accountability(schedule: &Schedule, idle: Option<WakingHours>) -> crate::models::DailyAccountability {
+    let mut daily = crate::models::DailyAccountability::from_tasks(schedule.date, &schedule.tasks);
+    if let Some(hours) = idle {
+        daily.add_idle(schedule.idle_minutes(hours.start, hours.end, Local::now()));
+    }
+    daily
+}
+
+/// 주간 다이제스트에 보여줄 태그 수
+const DIGEST_TOP_TAGS: usize = 5;
+
+/// 주간 다이제스트에 보여줄 예상 시간 오차 작업 수
+const DIGEST_TOP_MISSES: usize = 5;
+
+/// 예상보다 이만큼(분) 이상, 그리고 예상의 25% 이상 차이 나면 눈에 띄는 오차로 봄
+const DIGEST_MISS_MINUTES: i64 = 15;
+
+fn digest_command(
+    storage: &dyn Storage,
+    week: Option<&str>,
+    out: Option<PathBuf>,
+    idle: Option<WakingHours>,
+) -> anyhow::Result<()> {
+    let date = match week {
+        Some(date_str) => parse_date(date_str)?,
+        None => Local::now().date_naive(),
+    };
+    let (monday, sunday) = WeeklyGoals::week_range(date);
+    let schedules = storage.load_range(monday, sunday)?;
+    let digest = weekly_digest(monday, sunday, &schedules, &storage.load_streak()?, idle)?;
+
+    match out {
+        Some(path) if storage.is_dry_run() => {
+            output::info(&format!("Dry run: would write the digest for {} to {}", monday.format("%Y-%m-%d"), path.display()));
+        }
+        Some(path) => {
+            std::fs::write(&path, digest)?;
+            output::success(&format!("Wrote the digest for {} to {}", monday.format("%Y-%m-%d"), path.display()));
+        }
+        None => print!("{}", digest),
+    }
+
+    Ok(())
+}
+
+/// 한 주 다이제스트 마크다운 (하루별 효율 표, 총 집중 시간, 태그별 시간, 연속 기록, 예상 오차)
+fn weekly_digest(
+    from: NaiveDate,
+    to: NaiveDate,
+    schedules: &[Schedule],
+    streak: &StreakInfo,
+    idle: Option<WakingHours>,
+) -> anyhow::Result<String> {
+    let worked = |t: &Task| t.plausible_actual_minutes().unwrap_or(t.estimated_duration_minutes);
+    fn completed(schedule: &Schedule) -> impl Iterator<Item = &Task> {
+        schedule.tasks.iter().filter(|t| t.status == TaskStatus::Completed)
+    }
+
+    let mut out = String::new();
+    writeln!(out, "# Weekly Digest: {} – {}\n", from.format("%Y-%m-%d"), to.format("%Y-%m-%d"))?;
+
+    // 하루별 효율
+    writeln!(out, "| Day | Tasks | Focus | Efficiency | Grade |")?;
+    writeln!(out, "|-----|-------|-------|------------|-------|")?;
+    let (mut planned, mut net, mut done, mut total, mut focus) = (0i64, 0i64, 0usize, 0usize, 0i64);
+    for schedule in schedules {
+        let daily = day_accountability(schedule, idle);
+        let day_done: Vec<&Task> = completed(schedule).collect();
+        let day_focus: i64 = day_done.iter().map(|t| worked(t)).sum();
+        writeln!(
+            out,
+            "| {} | {}/{} | {} | {:.1}% | {} |",
+            schedule.date.format("%Y-%m-%d (%a)"),
+            day_done.len(),
+            schedule.tasks.len(),
+            format_minutes(day_focus),
+            daily.efficiency_score(),
+            daily.grade()
+        )?;
+        planned += daily.total_planned;
+        net += daily.net_earned();
+        done += day_done.len();
+        total += schedule.tasks.len();
+        focus += day_focus;
+    }
+    let week_score = if planned > 0 { net as f64 / planned as f64 * 100.0 } else { 0.0 };
+    writeln!(
+        out,
+        "| **Week** | **{}/{}** | **{}** | **{:.1}%** | |",
+        done,
+        total,
+        format_minutes(focus),
+        week_score
+    )?;
+
+    writeln!(out, "\n## Focus Time\n")?;
+    writeln!(out, "{} of completed work across {} day(s).", format_minutes(focus), schedules.len())?;
+
+    // 태그별 시간
+    let mut tag_minutes: HashMap<&str, i64> = HashMap::new();
+    for task in schedules.iter().flat_map(completed) {
+        for tag in &task.tags {
+            *tag_minutes.entry(tag.as_str()).or_insert(0) += worked(task);
+        }
+    }
+    let mut tags: Vec<(&str, i64)> = tag_minutes.into_iter().collect();
+    tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    writeln!(out, "\n## Top Tags\n")?;
+    if tags.is_empty() {
+        writeln!(out, "No tagged work this week.")?;
+    }
+    for (tag, minutes) in tags.into_iter().take(DIGEST_TOP_TAGS) {
+        writeln!(out, "- #{}: {}", tag, format_minutes(minutes))?;
+    }
+
+    writeln!(out, "\n## Streak\n")?;
+    writeln!(out, "Current streak: {} day(s) (best {}).", streak.current_streak, streak.best_streak)?;
+
+    // 예상보다 크게 길거나 짧았던 작업
+    let mut misses: Vec<(&Task, i64)> = schedules
+        .iter()
+        .flat_map(completed)
+        .filter_map(|t| t.plausible_actual_minutes().map(|actual| (t, actual - t.estimated_duration_minutes)))
+        .filter(|(t, diff)| diff.abs() >= DIGEST_MISS_MINUTES && diff.abs() * 4 >= t.estimated_duration_minutes)
+        .collect();
+    misses.sort_by_key(|(_, diff)| std::cmp::Reverse(diff.abs()));
+    writeln!(out, "\n## Estimates\n")?;
+    if misses.is_empty() {
+        writeln!(out, "No task was far off its estimate.")?;
+    }
+    for (task, diff) in misses.into_iter().take(DIGEST_TOP_MISSES) {
+        writeln!(
+            out,
+            "- {} \"{}\" ({}): {}m actual vs {}m estimated ({:+}m)",
+            if diff > 0 { "Over:" } else { "Under:" },
+            task.title,
+            task.start_time.format("%a"),
+            task.estimated_duration_minutes + diff,
+            task.estimated_duration_minutes,
+            diff
+        )?;
+    }
+
+    Ok(out)
+}
+
+/// 평균 시작 지연 표시 ("+10m late", "5m early", "on time")
+fn format_start_delay(minutes: f64) -> String {
+    let rounded = minutes.round() as i64;
+    match rounded {
+        0 => "on time".to_string(),
+        m if m > 0 => format!("+{}m late", m),
+        m => format!("{}m early", -m),
+    }
+}
+
+/// 일일 리포트 JSON (대시보드 연동용)
+fn daily_report_json(schedule: &Schedule, idle: Option<WakingHours>) -> anyhow::Result<String> {
+    use crate::models::TimeAccountability;
+
+    let daily = day_accountability(schedule, idle);
+    let tasks: Vec<_> = schedule
+        .tasks
+        .iter()
+        .map(|task| {
+            serde_json::json!({
+                "title": task.title,
+                "accountability": TimeAccountability::from_task(task),
+            })
+        })
+        .collect();
+
+    let report = serde_json::json!({
+        "accountability": daily,
+        "efficiency_score": daily.efficiency_score(),
+        "grade": daily.grade(),
+        "average_start_delay_minutes": schedule.average_start_delay(),
+        "tasks": tasks,
+    });
+
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+fn summary_command(storage: &dyn Storage, ai: bool) -> anyhow::Result<()> {
+    let schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+    let streak = storage.load_streak()?;
+
+    let summary = compose_summary(&schedule, &streak);
+
+    if ai {
+        let template = PromptTemplate::day_recap();
+        let mut vars = HashMap::new();
+        vars.insert("summary".to_string(), summary);
+
+        let prompt = template.render(&vars);
+
+        println!("\n{}\n", "=".repeat(80).bright_blue());
+        println!("{}", "Day Recap Prompt".bright_cyan().bold());
+        println!("{}\n", "=".repeat(80).bright_blue());
+        println!("{}", prompt);
+        println!("\n{}\n", "=".repeat(80).bright_blue());
+
+        output::info("Copy the prompt above and paste it to Claude Code");
+    } else {
+        println!("{}", summary);
+    }
+
+    Ok(())
+}
+
+fn format_minutes(minutes: i64) -> String {
+    output::format_duration(minutes, DurationStyle::HoursMinutes)
+}
+
+/// 로컬 데이터만으로 하루 요약 문단 생성
+fn compose_summary(schedule: &Schedule, streak: &StreakInfo) -> String {
+    use crate::models::{DailyAccountability, STREAK_THRESHOLD};
+
+    let completed: Vec<&Task> = schedule
         .tasks
         .iter()
         .filter(|t| t.status == TaskStatus::Completed)
-        .filter_map(|t| t.actual_duration_minutes)
-        .sum();
+        .collect();
+    let worked = |t: &Task| t.actual_duration_minutes.unwrap_or(t.estimated_duration_minutes);
 
-    println!("{}: {}h {}m", 
-        "Focus Time".bold(), 
-        focus_time / 60, 
-        focus_time % 60
+    let completion = schedule.completion_rate();
+    let focus_time: i64 = completed.iter().map(|t| worked(t)).sum();
+    let daily = DailyAccountability::from_tasks(schedule.date, &schedule.tasks);
+
+    let mut text = format!(
+        "On {} I completed {} of {} tasks ({:.0}%) with {} of focused work. Efficiency grade: {} ({:.1}%).",
+        schedule.date.format("%Y-%m-%d"),
+        completed.len(),
+        schedule.tasks.len(),
+        completion,
+        format_minutes(focus_time),
+        daily.grade(),
+        daily.efficiency_score()
     );
 
-    let pending = schedule.tasks.iter().filter(|t| t.status == TaskStatus::Pending).count();
-    let paused = schedule.tasks.iter().filter(|t| t.status == TaskStatus::Paused).count();
+    if let Some(longest) = completed.iter().max_by_key(|t| worked(t)) {
+        text.push_str(&format!(
+            " My longest focus block was '{}' at {}.",
+            longest.title,
+            format_minutes(worked(longest))
+        ));
+    }
 
-    println!("{}: {}", "Pending".yellow(), pending);
-    println!("{}: {}", "Paused".yellow(), paused);
+    let mut tag_minutes: HashMap<&str, i64> = HashMap::new();
+    for task in &completed {
+        for tag in &task.tags {
+            *tag_minutes.entry(tag.as_str()).or_insert(0) += worked(task);
+        }
+    }
+    if let Some((tag, minutes)) = tag_minutes
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+    {
+        text.push_str(&format!(
+            " Most of my time went to #{} ({}).",
+            tag,
+            format_minutes(minutes)
+        ));
+    }
+
+    if completion >= STREAK_THRESHOLD {
+        let already_counted = streak.last_update.date_naive() == schedule.date.date_naive();
+        let days = if already_counted {
+            streak.current_streak
+        } else {
+            streak.current_streak + 1
+        };
+        text.push_str(&format!(" The streak advanced to {} days.", days));
+    } else {
+        text.push_str(&format!(
+            " The streak did not advance (needs {:.0}% completion).",
+            STREAK_THRESHOLD
+        ));
+    }
+
+    text
+}
+
+/// 작업 소요 시간 구간 (주간 리포트 히스토그램용)
+const DURATION_BUCKETS: [&str; 4] = ["<30m", "30-60m", "1-2h", ">2h"];
+
+/// 소요 시간(분)을 히스토그램 구간으로 분류
+fn bucket_duration(minutes: i64) -> &'static str {
+    if minutes < 30 {
+        DURATION_BUCKETS[0]
+    } else if minutes < 60 {
+        DURATION_BUCKETS[1]
+    } else if minutes <= 120 {
+        DURATION_BUCKETS[2]
+    } else {
+        DURATION_BUCKETS[3]
+    }
+}
 
+fn efficiency_command(storage: &dyn Storage, days: Option<usize>, sparkline: bool, config: &Config) -> anyhow::Result<()> {
+    let days = days.unwrap_or(7);
+    let fmt = config.report_precision.clone();
+    if sparkline {
+        println!("{}", efficiency_sparkline(&load_recent_schedules(storage, days as i64), &fmt));
+    } else {
+        print!("{}", efficiency_trend(storage, days, &fmt, config.idle_window(), config.trend_window_days)?);
+    }
     Ok(())
 }
 
-fn show_weekly_stats(storage: &JsonStorage) -> anyhow::Result<()> {
-    use chrono::Duration;
+/// `done:<id>=<분>`, `est:<id>=<분>`, `skip:<id>` 형식의 가상 변경 파싱
+fn parse_sim_change(expr: &str) -> anyhow::Result<SimChange> {
+    let invalid = || SchedError::validation(format!(
+        "Invalid change '{}' (expected done:<id>=<minutes>, est:<id>=<minutes> or skip:<id>)",
+        expr
+    ));
+
+    let (kind, rest) = expr.split_once(':').ok_or_else(invalid)?;
+    let minutes_for = |rest: &str| -> anyhow::Result<(String, i64)> {
+        let (id, minutes) = rest.split_once('=').ok_or_else(invalid)?;
+        let minutes: i64 = minutes.trim().parse().map_err(|_| invalid())?;
+        if minutes <= 0 {
+            return Err(invalid().into());
+        }
+        Ok((id.trim().to_string(), minutes))
+    };
 
-    println!("\n{}", "📊 Weekly Statistics".bold());
-    println!("{}\n", "Last 7 days".cyan());
+    Ok(match kind.trim().to_lowercase().as_str() {
+        "done" => {
+            let (task_id, actual_minutes) = minutes_for(rest)?;
+            SimChange::Complete { task_id, actual_minutes }
+        }
+        "est" => {
+            let (task_id, minutes) = minutes_for(rest)?;
+            SimChange::Estimate { task_id, minutes }
+        }
+        "skip" => SimChange::Skip { task_id: rest.trim().to_string() },
+        _ => return Err(invalid().into()),
+    })
+}
 
-    let today = Local::now();
-    
-    for i in (0..7).rev() {
-        let date = today - Duration::days(i);
-        let schedule = storage.load_schedule(date)?;
+fn simulate_command(storage: &dyn Storage, exprs: &[String]) -> anyhow::Result<()> {
+    use crate::models::DailyAccountability;
 
-        let day_str = date.format("%a").to_string();
-        
-        if let Some(schedule) = schedule {
-            let completion = schedule.completion_rate();
-            let bar = create_progress_bar(completion);
-            
-            let indicator = if date.date_naive() == today.date_naive() {
-                "◄── Today"
+    let schedule = storage
+        .load_today()?
+        .ok_or_else(|| SchedError::not_found("No schedule found"))?;
+
+    let changes = exprs
+        .iter()
+        .map(|expr| parse_sim_change(expr))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    if let Some(missing) = changes.iter().find(|c| schedule.find_task(c.task_id()).is_none()) {
+        return Err(SchedError::not_found(format!("Task not found: {}", missing.task_id())).into());
+    }
+
+    let current = DailyAccountability::from_tasks(schedule.date, &schedule.tasks);
+    let projected = schedule.simulate(&changes);
+    let delta = projected.efficiency_score() - current.efficiency_score();
+
+    println!("Current:   {:.1}% ({})", current.efficiency_score(), current.grade());
+    println!(
+        "Projected: {:.1}% ({}) {}",
+        projected.efficiency_score(),
+        projected.grade(),
+        if delta >= 0.0 {
+            format!("+{:.1}", delta).green()
+        } else {
+            format!("{:.1}", delta).red()
+        }
+    );
+    output::info("Simulation only; nothing was saved.");
+    Ok(())
+}
+
+/// 효율 점수 스파크라인 한 줄 (데이터가 있는 날만)
+fn efficiency_sparkline(schedules: &[Schedule], fmt: &ReportPrecision) -> String {
+    use crate::models::DailyAccountability;
+
+    let scores: Vec<f64> = schedules
+        .iter()
+        .map(|s| DailyAccountability::from_tasks(s.date, &s.tasks).efficiency_score())
+        .collect();
+
+    match (schedules.first(), schedules.last()) {
+        (Some(first), Some(last)) => format!(
+            "{}–{} {} avg {}",
+            first.date.format("%m/%d"),
+            last.date.format("%m/%d"),
+            output::sparkline(&scores),
+            output::format_percent(scores.iter().sum::<f64>() / scores.len() as f64, fmt.percent_decimals)
+        ),
+        _ => "No efficiency data".to_string(),
+    }
+}
+
+/// 이 이하의 기울기 (%/일)는 안정적인 추세로 봄
+const TREND_STABLE_SLOPE: f64 = 0.5;
+
+/// 최근 `num_days`일 효율 점수 추이 차트
+fn efficiency_trend(
+    storage: &dyn Storage,
+    num_days: usize,
+    fmt: &ReportPrecision,
+    idle: Option<WakingHours>,
+    window: usize,
+) -> anyhow::Result<String> {
+    use crate::models::{linear_trend, moving_average};
+
+    let mut out = String::new();
+    let today = Local::now().date_naive();
+
+    writeln!(out, "\n{}", format!("{}-Day Efficiency Trend", num_days).bold().cyan())?;
+    writeln!(out, "{}", output::separator("=", 60))?;
+
+    // (며칠째인지, 날짜, 하루 결과) - 데이터가 없는 날은 None
+    let days: Vec<_> = (0..num_days)
+        .rev()
+        .enumerate()
+        .map(|(index, days_ago)| {
+            let date = today - chrono::Duration::days(days_ago as i64);
+            let date_time = resolve_local(date.and_hms_opt(0, 0, 0).unwrap());
+            let daily = storage
+                .load_schedule(date_time)
+                .ok()
+                .flatten()
+                .map(|schedule| day_accountability(&schedule, idle));
+            (index, date, daily)
+        })
+        .collect();
+
+    let points: Vec<(f64, f64)> = days
+        .iter()
+        .filter_map(|(index, _, daily)| daily.as_ref().map(|d| (*index as f64, d.efficiency_score())))
+        .collect();
+    let scores: Vec<f64> = points.iter().map(|(_, score)| *score).collect();
+    let mut averages = moving_average(&scores, window).into_iter();
+
+    // "MM/DD | " 와 " 100.0% (A+)" 를 제외한 폭
+    let bar_width = output::bar_width(20, 40);
+
+    for (_, date, daily) in &days {
+        if let Some(daily) = daily {
+            let score = daily.efficiency_score();
+            let average = averages.next().unwrap_or(score);
+
+            // ASCII bar chart, 이동 평균 위치에 세로선
+            let bar_length = output::bar_length(score, bar_width);
+            let marker = output::bar_length(average, bar_width).min(bar_width - 1);
+            let bar: String = (0..bar_width)
+                .map(|i| match i {
+                    _ if i == marker => '┃',
+                    _ if i < bar_length => '█',
+                    _ => ' ',
+                })
+                .collect();
+
+            let colored_bar = if score >= 90.0 {
+                bar.green()
+            } else if score >= 70.0 {
+                bar.yellow()
             } else {
-                ""
+                bar.red()
             };
 
-            println!("  {} {} {:.0}% {}", 
-                day_str.cyan(), 
-                bar.green(), 
-                completion,
-                indicator.yellow()
-            );
+            writeln!(out, "{} | {} {} ({})",
+                date.format("%m/%d"),
+                colored_bar,
+                output::format_percent(score, fmt.percent_decimals),
+                daily.grade(),
+            )?;
         } else {
-            println!("  {} {}", day_str.dimmed(), "No data".dimmed());
+            writeln!(out, "{} | {} (no data)", date.format("%m/%d"), "░".repeat(bar_width).bright_black())?;
         }
     }
 
-    Ok(())
+    writeln!(out, "{}", output::separator("=", 60))?;
+
+    // Calculate average
+    if !scores.is_empty() {
+        let avg = scores.iter().sum::<f64>() / scores.len() as f64;
+        writeln!(out, "Average Efficiency: {}", output::format_percent(avg, fmt.percent_decimals))?;
+        writeln!(out, "{} = {}-day moving average", "┃".dimmed(), window.max(1))?;
+
+        // Trend: 하루 점수에 맞춘 회귀 직선의 기울기
+        if scores.len() >= 2 {
+            let slope = linear_trend(&points);
+            let rate = format!("{:+.1}%/day", slope);
+
+            if slope > TREND_STABLE_SLOPE {
+                writeln!(out, "{} Improving trend ({}) 📈", "✓".green(), rate)?;
+            } else if slope < -TREND_STABLE_SLOPE {
+                writeln!(out, "{} Declining trend ({}) 📉", "✗".red(), rate)?;
+            } else {
+                writeln!(out, "{} Stable performance ({})", "ℹ".blue(), rate)?;
+            }
+        }
+    }
+
+    Ok(out)
 }
 
-fn streak_command(storage: &JsonStorage) -> anyhow::Result<()> {
-    let streak = storage.load_streak()?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{at, day_schedule};
+    use crate::models::ChangeType;
+
+    fn add_args(title: &str, start: &str, end: &str) -> AddArgs {
+        AddArgs {
+            title: title.to_string(),
+            start: Some(start.to_string()),
+            end: Some(end.to_string()),
+            ..AddArgs::default()
+        }
+    }
 
-    println!("\n{}", "🔥 Streak Information".bold());
-    println!();
+    #[test]
+    fn test_add_task_records_creation_change() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
 
-    println!("{}: {} days", 
-        "Current Streak".bold().green(), 
-        streak.current_streak
-    );
+        add_task(&storage, add_args("Write report", "09:00", "10:00"), &Config::default()).unwrap();
 
-    println!("{}: {} days", 
-        "Best Streak".bold().yellow(), 
-        streak.best_streak
-    );
+        let schedule = storage.load_today().unwrap().unwrap();
+        assert_eq!(schedule.changes.len(), 1);
+        assert!(matches!(schedule.changes[0].change_type, ChangeType::TaskCreated));
+        assert_eq!(schedule.changes[0].task_title.as_deref(), Some("Write report"));
+        assert_eq!(schedule.changes[0].new_time.as_deref(), Some("09:00-10:00"));
+    }
 
-    println!("{}: {}", 
-        "Last Updated".dimmed(), 
-        streak.last_update.format("%Y-%m-%d %H:%M")
-    );
+    #[test]
+    fn test_ai_add_keeps_explicit_flags() {
+        let at = |h| Local::now().date_naive().and_hms_opt(h, 0, 0).unwrap();
+        let mut task = Task::new("Jog".to_string(), resolve_local(at(7)), resolve_local(at(8)));
+        task.tags = vec!["health".to_string()];
+        task.notes = Some("from the AI".to_string());
+        let args = AddArgs {
+            title: "30분 조깅 아침에".to_string(),
+            tags: Some("fitness,morning".to_string()),
+            priority: Some("high".to_string()),
+            ai: true,
+            ..AddArgs::default()
+        };
 
-    let fire_count = (streak.current_streak / 7).min(5) as usize;
-    if fire_count > 0 {
-        println!("\n{}", "🔥".repeat(fire_count));
+        apply_explicit_add_flags(&mut task, &args).unwrap();
+        assert_eq!(task.tags, vec!["fitness", "morning"]);
+        assert_eq!(task.notes.as_deref(), Some("from the AI"));
+        assert_eq!(task.priority, Priority::High);
+    }
+
+    #[test]
+    fn test_add_task_with_priority() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let args = |priority: &str| AddArgs {
+            priority: Some(priority.to_string()),
+            ..add_args("Release", "09:00", "10:00")
+        };
+
+        assert!(add_task(&storage, args("someday"), &Config::default()).is_err());
+        add_task(&storage, args("high"), &Config::default()).unwrap();
+
+        let schedule = storage.load_today().unwrap().unwrap();
+        assert_eq!(schedule.tasks[0].priority, Priority::High);
+    }
+
+    #[test]
+    fn test_month_grid_starts_on_the_right_weekday() {
+        let first = parse_month("2025-11").unwrap();
+        let weeks = month_grid(first);
+
+        // 2025-11-01은 토요일 → 첫 주 여섯 번째 칸
+        assert_eq!(weeks[0].iter().position(Option::is_some), Some(5));
+        assert_eq!(weeks[0][5], Some(first));
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[4][6], NaiveDate::from_ymd_opt(2025, 11, 30));
+        assert_eq!(weeks.iter().flatten().flatten().count(), 30);
+
+        // 2025-09-01은 월요일 → 첫 칸
+        assert_eq!(month_grid(parse_month("2025-09").unwrap())[0][0], NaiveDate::from_ymd_opt(2025, 9, 1));
+        assert!(parse_month("2025-13").is_err());
     }
 
-    Ok(())
-}
+    #[test]
+    fn test_merge_command_records_change() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        add_task(&storage, add_args("Draft", "09:00", "09:30"), &Config::default()).unwrap();
+        add_task(&storage, add_args("Edit", "09:30", "10:15"), &Config::default()).unwrap();
+        let ids: Vec<String> = storage.load_today().unwrap().unwrap().tasks.iter().map(|t| t.id.clone()).collect();
+
+        merge_command(&storage, &ids[0], &ids[1]).unwrap();
+
+        let schedule = storage.load_today().unwrap().unwrap();
+        assert_eq!(schedule.tasks.len(), 1);
+        assert_eq!(schedule.tasks[0].estimated_duration_minutes, 75);
+        let change = schedule.changes.last().unwrap();
+        assert_eq!(change.old_time.as_deref(), Some("09:00-09:30, 09:30-10:15"));
+        assert_eq!(change.new_time.as_deref(), Some("09:00-10:15"));
+
+        let err = merge_command(&storage, &ids[0], &ids[1]).unwrap_err();
+        assert!(matches!(err.downcast_ref::<SchedError>(), Some(SchedError::NotFound(_))));
+    }
 
-fn create_progress_bar(percentage: f64) -> String {
-    let filled = (percentage / 100.0 * 20.0) as usize;
-    let empty = 20 - filled;
-    
-    format!("{}{}  {:.0}%",
-        "█".repeat(filled),
-        "░".repeat(empty),
-        percentage
-    )
-}
+    #[test]
+    fn test_add_task_applies_tag_policy() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
 
-fn pomodoro_command(storage: &JsonStorage, action: super::PomodoroAction) -> anyhow::Result<()> {
-    use super::PomodoroAction;
-    use crate::models::PomodoroSession;
+        let required = Config { required_tags: true, ..Config::default() };
+        let err = add_task(&storage, add_args("Untagged", "09:00", "10:00"), &required).unwrap_err();
+        assert!(matches!(err.downcast_ref::<SchedError>(), Some(SchedError::Validation(_))));
+        assert!(storage.load_today().unwrap().is_none());
 
-    match action {
-        PomodoroAction::Start => {
-            let mut schedule = storage
-                .load_today()?
-                .ok_or_else(|| anyhow::anyhow!("No schedule found"))?;
+        let defaults = Config {
+            default_tags: vec!["work".to_string()],
+            ..required
+        };
+        add_task(&storage, add_args("Tagged", "09:00", "10:00"), &defaults).unwrap();
+        let schedule = storage.load_today().unwrap().unwrap();
+        assert_eq!(schedule.tasks[0].tags, vec!["work"]);
+    }
 
-            let current = schedule
-                .get_current_task()
-                .ok_or_else(|| anyhow::anyhow!("No task is currently in progress"))?;
+    #[test]
+    fn test_dry_run_add_leaves_storage_unchanged() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf())
+            .unwrap()
+            .with_dry_run(true);
 
-            let current_id = current.id.clone();
-            let task = schedule.find_task_mut(&current_id).unwrap();
+        add_task(&storage, add_args("Write report", "09:00", "10:00"), &Config::default()).unwrap();
 
-            if task.pomodoro.is_none() {
-                task.pomodoro = Some(PomodoroSession::new(task.estimated_duration_minutes));
+        assert!(storage.load_today().unwrap().is_none());
+        let history = temp_dir.path().join("history");
+        let today_file = history.join(format!("{}.json", Local::now().format("%Y-%m-%d")));
+        assert!(storage.skipped_writes().contains(&today_file));
+        assert_eq!(std::fs::read_dir(history).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_focus_averages_per_day() {
+        let day = |d: u32, scores: &[Option<u8>]| {
+            let mut schedule = Schedule::new(at(d, 0, 0));
+            for (i, score) in scores.iter().enumerate() {
+                let mut task = Task::new(format!("Task {}", i), at(d, 9 + i as u32, 0), at(d, 10 + i as u32, 0));
+                task.status = TaskStatus::Completed;
+                task.focus_score = *score;
+                schedule.add_task(task).unwrap();
             }
+            schedule
+        };
 
-            let pomodoro = task.pomodoro.as_mut().unwrap();
-            pomodoro.start_pomodoro();
+        let schedules = vec![
+            day(3, &[Some(8), Some(6), None]),
+            day(4, &[None]),
+            day(5, &[Some(3)]),
+        ];
 
-            // Extract info before save
-            let task_title = task.title.clone();
-            let current_pomodoro = pomodoro.completed_pomodoros + 1;
-            let total_pomodoros = pomodoro.total_pomodoros;
-            let duration = pomodoro.pomodoro_duration;
+        let averages = focus_averages(&schedules);
+        let date = |d| NaiveDate::from_ymd_opt(2025, 11, d).unwrap();
+        assert_eq!(averages, vec![(date(3), 7.0), (date(5), 3.0)]);
 
-            storage.save_schedule(&schedule)?;
+        let report = focus_report(&schedules, date(3), date(5)).unwrap();
+        assert!(report.contains("(no scores)"));
+        assert!(report.contains("Average Focus: 5.0/10"));
+    }
 
-            output::success(&format!(
-                "Started Pomodoro {}/{} for '{}'",
-                current_pomodoro,
-                total_pomodoros,
-                task_title
-            ));
+    #[test]
+    fn test_now_status_states() {
+        let mut schedule = day_schedule();
+        let mut writing = Task::new("Write".to_string(), at(4, 9, 0), at(4, 10, 0));
+        writing.status = TaskStatus::InProgress;
+        writing.actual_start_time = Some(at(4, 9, 0));
+        schedule.add_task(writing).unwrap();
+        schedule.add_task(Task::new("Standup".to_string(), at(4, 11, 0), at(4, 11, 15))).unwrap();
+
+        let status = now_status(Some(&schedule), at(4, 9, 35));
+        assert_eq!(status.state, "current");
+        assert_eq!(status.line, "▶ Write · 25m left");
+        assert_eq!(now_status(Some(&schedule), at(4, 10, 10)).line, "▶ Write · 10m over");
+
+        schedule.tasks[0].status = TaskStatus::Completed;
+        let status = now_status(Some(&schedule), at(4, 10, 15));
+        assert_eq!(status.state, "next");
+        assert_eq!(status.line, "⏱ Standup in 45m");
+        assert_eq!(status.minutes, Some(45));
+
+        schedule.tasks[1].status = TaskStatus::Skipped;
+        assert_eq!(now_status(Some(&schedule), at(4, 12, 0)).line, "idle");
+        assert_eq!(now_status(None, at(4, 12, 0)).state, "idle");
+    }
 
-            output::info(&format!("Focus for {} minutes", duration));
-        }
+    #[test]
+    fn test_parse_goal_target() {
+        assert_eq!(parse_goal_target("20h").unwrap(), 1200);
+        assert_eq!(parse_goal_target("90m").unwrap(), 90);
+        assert_eq!(parse_goal_target("1h30m").unwrap(), 90);
+        assert_eq!(parse_goal_target("45").unwrap(), 45);
+        assert!(parse_goal_target("soon").is_err());
+        assert!(parse_goal_target("0h").is_err());
+    }
 
-        PomodoroAction::Complete => {
-            let mut schedule = storage
-                .load_today()?
-                .ok_or_else(|| anyhow::anyhow!("No schedule found"))?;
+    #[test]
+    fn test_passphrase_encrypts_schedules_written_by_commands() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let inner = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let store = EncryptedStorage::from_passphrase(inner, Some("correct horse")).unwrap();
+        let args = AddArgs {
+            notes: Some("private notes".to_string()),
+            ..add_args("Therapy", "09:00", "10:00")
+        };
+        add_task(store.as_ref(), args, &Config::default()).unwrap();
 
-            let current = schedule
-                .get_current_task()
-                .ok_or_else(|| anyhow::anyhow!("No task is currently in progress"))?;
+        let file = format!("{}.json", Local::now().format("%Y-%m-%d"));
+        let on_disk = std::fs::read(temp_dir.path().join("history").join(file)).unwrap();
+        let text = String::from_utf8_lossy(&on_disk);
+        assert!(!text.contains("Therapy") && !text.contains("private notes"));
+        assert!(temp_dir.path().join("encryption.salt").exists());
+    }
 
-            let current_id = current.id.clone();
-            let task = schedule.find_task_mut(&current_id).unwrap();
+    #[test]
+    fn test_claude_context_is_not_cached_in_plaintext_when_encrypted() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let inner = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let store = EncryptedStorage::from_passphrase(inner, Some("correct horse")).unwrap();
+        add_task(store.as_ref(), add_args("Therapy", "09:00", "10:00"), &Config::default()).unwrap();
 
-            let pomodoro = task
-                .pomodoro
-                .as_mut()
-                .ok_or_else(|| anyhow::anyhow!("No Pomodoro session active"))?;
+        claude_command(store.as_ref(), ClaudeAction::Validate, false, &Config::default()).unwrap();
+        assert!(!temp_dir.path().join("cache").join("claude_context.json").exists());
+    }
 
-            pomodoro.complete_pomodoro();
+    #[test]
+    fn test_task_left_running_yesterday_is_paused_in_yesterdays_schedule() {
+        use crate::models::TaskStatus;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let yesterday = Local::now() - chrono::Duration::days(1);
+        let mut schedule = Schedule::new(yesterday);
+        let mut task = Task::new("Overnight".to_string(), yesterday, yesterday + chrono::Duration::minutes(1));
+        task.start();
+        task.actual_start_time = Some(yesterday);
+        schedule.add_task(task).unwrap();
+        let history = storage.save_schedule(&schedule).unwrap();
+        // 어제 저장한 current.json
+        std::fs::copy(&history, temp_dir.path().join("current.json")).unwrap();
+
+        pause_left_running(&storage).unwrap();
+        let saved = storage.load_schedule(yesterday).unwrap().unwrap();
+        assert_eq!(saved.tasks[0].status, TaskStatus::Paused);
+        assert!(saved.tasks[0].paused_at.is_some());
+        assert_eq!(storage.load_events().unwrap().len(), 1);
+
+        // 이미 일시정지했으므로 다시 기록하지 않음
+        pause_left_running(&storage).unwrap();
+        assert_eq!(storage.load_events().unwrap().len(), 1);
+    }
 
-            // Extract info before save
-            let completed = pomodoro.completed_pomodoros;
-            let total = pomodoro.total_pomodoros;
-            let is_complete = pomodoro.is_complete();
-            let break_duration = pomodoro.next_break_duration();
+    #[test]
+    fn test_recurring_task_survives_a_long_absence() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let today = resolve_local(Local::now().date_naive().and_hms_opt(0, 0, 0).unwrap());
+        let long_ago = today - chrono::Duration::days(RECURRENCE_LOOKBACK_DAYS + 10);
+
+        let mut schedule = Schedule::new(long_ago);
+        let start = long_ago + chrono::Duration::hours(9);
+        let mut standup = Task::new("Standup".to_string(), start, start + chrono::Duration::minutes(15));
+        make_recurring(&mut standup, Recurrence::Daily);
+        schedule.add_task(standup.clone()).unwrap();
+        storage.save_schedule(&schedule).unwrap();
+        remember_recurring(&storage, &standup).unwrap();
+
+        assert_eq!(materialize_recurring(&storage, today).unwrap(), 1);
+        let added = storage.load_schedule(today).unwrap().unwrap();
+        assert_eq!(added.tasks[0].title, "Standup");
+        assert_eq!(added.tasks[0].recurrence_id, standup.recurrence_id);
+    }
 
-            storage.save_schedule(&schedule)?;
+    #[test]
+    fn test_out_files_are_not_written_on_dry_run() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        add_task(&storage, add_args("Write report", "09:00", "10:00"), &Config::default()).unwrap();
+
+        let dry = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap().with_dry_run(true);
+        let ics = temp_dir.path().join("today.ics");
+        let digest = temp_dir.path().join("digest.md");
+        export_command(&dry, "ics".to_string(), None, Some(ics.clone())).unwrap();
+        digest_command(&dry, None, Some(digest.clone()), None).unwrap();
+        assert!(!ics.exists());
+        assert!(!digest.exists());
+
+        export_command(&storage, "ics".to_string(), None, Some(ics.clone())).unwrap();
+        assert!(ics.exists());
+    }
 
-            output::success(&format!(
-                "Completed Pomodoro {}/{}",
-                completed,
-                total
-            ));
+    #[test]
+    fn test_edit_task_keeps_id_and_rejects_conflicts() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let config = Config::default();
+
+        add_task(&storage, add_args("Write report", "09:00", "10:00"), &config).unwrap();
+        add_task(&storage, add_args("Standup", "11:00", "11:30"), &config).unwrap();
+        let id = storage.load_today().unwrap().unwrap().tasks[0].id.clone();
+        let edit = |start: &str, end: &str| EditArgs {
+            id: id.clone(),
+            start: Some(start.to_string()),
+            end: Some(end.to_string()),
+            ..EditArgs::default()
+        };
 
-            if is_complete {
-                output::info("All Pomodoros completed! 🎉");
-            } else {
-                output::info(&format!("Take a {} minute break", break_duration));
-            }
-        }
+        // 다른 작업과 겹치면 아무것도 저장하지 않음
+        assert!(edit_task(&storage, edit("10:30", "11:15"), &config).is_err());
+        let schedule = storage.load_today().unwrap().unwrap();
+        assert_eq!(schedule.find_task(&id).unwrap().start_time.format("%H:%M").to_string(), "09:00");
+        assert_eq!(schedule.changes.len(), 2);
 
-        PomodoroAction::Status => {
-            let schedule = storage
-                .load_today()?
-                .ok_or_else(|| anyhow::anyhow!("No schedule found"))?;
+        let args = EditArgs {
+            title: Some("Write summary".to_string()),
+            tags: Some("work, writing".to_string()),
+            ..edit("12:00", "13:30")
+        };
+        edit_task(&storage, args, &config).unwrap();
+
+        let schedule = storage.load_today().unwrap().unwrap();
+        let task = schedule.find_task(&id).unwrap();
+        assert_eq!(task.title, "Write summary");
+        assert_eq!(task.tags, ["work", "writing"]);
+        assert_eq!(task.estimated_duration_minutes, 90);
+        assert_eq!(schedule.tasks.last().unwrap().id, id);
+        let change = schedule.changes.last().unwrap();
+        assert!(matches!(change.change_type, ChangeType::TaskUpdated));
+        assert_eq!(change.old_time.as_deref(), Some("09:00-10:00"));
+        assert_eq!(change.new_time.as_deref(), Some("12:00-13:30"));
+    }
 
-            let current = schedule
-                .get_current_task()
-                .ok_or_else(|| anyhow::anyhow!("No task is currently in progress"))?;
+    #[test]
+    fn test_interruptions_are_logged_and_reported() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        add_task(&storage, add_args("Deep work", "09:00", "10:00"), &Config::default()).unwrap();
+        let id = storage.load_today().unwrap().unwrap().tasks[0].id.clone();
+        start_task(&storage, None, true).unwrap();
+
+        let before = Local::now();
+        interrupt_task(&storage, &id, "Slack ping".to_string(), false).unwrap();
+        interrupt_task(&storage, &id, "Phone call".to_string(), true).unwrap();
+        assert!(interrupt_task(&storage, &id, "  ".to_string(), false).is_err());
+
+        let schedule = storage.load_today().unwrap().unwrap();
+        let task = &schedule.tasks[0];
+        let reasons: Vec<&str> = task.interruptions.iter().map(|i| i.reason.as_str()).collect();
+        assert_eq!(reasons, ["Slack ping", "Phone call"]);
+        assert!(task.interruptions.iter().all(|i| i.at >= before && i.at <= Local::now()));
+        assert_eq!(task.status, TaskStatus::Paused);
+
+        let report = daily_report(&schedule, &ReportPrecision::default(), None).unwrap();
+        assert!(report.contains("Interruptions:"));
+        assert!(report.contains("2 total"));
+        assert!(report.contains("  Deep work - 2\n"));
+    }
 
-            if let Some(pomodoro) = &current.pomodoro {
-                println!("\n{}", "🍅 Pomodoro Status".bold());
-                println!("Task: {}", current.title.cyan());
-                println!(
-                    "Progress: {}/{}",
-                    pomodoro.completed_pomodoros, pomodoro.total_pomodoros
-                );
+    #[test]
+    fn test_skip_task_defaults_to_current() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
 
-                if let Some(elapsed) = pomodoro.elapsed_minutes() {
-                    let remaining = pomodoro.remaining_minutes().unwrap();
-                    println!(
-                        "Current: {}m elapsed, {}m remaining",
-                        elapsed, remaining
-                    );
-                }
+        add_task(&storage, add_args("First", "09:00", "10:00"), &Config::default()).unwrap();
+        add_task(&storage, add_args("Second", "10:00", "11:00"), &Config::default()).unwrap();
+        assert!(skip_task(&storage, None).is_err());
 
-                let bar = create_progress_bar(
-                    (pomodoro.completed_pomodoros as f64 / pomodoro.total_pomodoros as f64) * 100.0,
-                );
-                println!("{}", bar.green());
-            } else {
-                output::info("No Pomodoro session. Use 'sched pomodoro start'");
-            }
-        }
+        start_task(&storage, None, true).unwrap();
+        skip_task(&storage, None).unwrap();
+
+        let schedule = storage.load_today().unwrap().unwrap();
+        let first = schedule.tasks.iter().find(|t| t.title == "First").unwrap();
+        assert_eq!(first.status, TaskStatus::Skipped);
+        assert!(first.sessions.iter().all(|s| s.end.is_some()));
+
+        let second_id = schedule.tasks.iter().find(|t| t.title == "Second").unwrap().id.clone();
+        skip_task(&storage, Some(second_id.clone())).unwrap();
+        assert!(skip_task(&storage, Some(second_id)).is_err());
     }
 
-    Ok(())
-}
+    #[test]
+    fn test_complete_auto_starts_next_task() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
 
-fn claude_command(storage: &JsonStorage, action: ClaudeAction) -> anyhow::Result<()> {
-    use super::ClaudeAction;
+        add_task(&storage, add_args("First", "09:00", "10:00"), &Config::default()).unwrap();
+        add_task(&storage, add_args("Second", "10:00", "11:00"), &Config::default()).unwrap();
+        start_task(&storage, None, true).unwrap();
 
-    let schedule = storage
-        .load_today()?
-        .ok_or_else(|| anyhow::anyhow!("No schedule found for today"))?;
+        complete_task(&storage, None, true).unwrap();
 
-    let context = ScheduleContext::collect(&schedule);
+        let schedule = storage.load_today().unwrap().unwrap();
+        let status = |title: &str| schedule.tasks.iter().find(|t| t.title == title).unwrap().status;
+        assert_eq!(status("First"), TaskStatus::Completed);
+        assert_eq!(status("Second"), TaskStatus::InProgress);
 
-    match action {
-        ClaudeAction::Ask { question } => {
-            let template = PromptTemplate::task_assistant();
-            let mut vars = HashMap::new();
-            vars.insert("context".to_string(), context.to_markdown());
-            vars.insert("question".to_string(), question);
+        // 마지막 작업이면 더 시작할 것이 없음
+        complete_task(&storage, None, true).unwrap();
+        let schedule = storage.load_today().unwrap().unwrap();
+        assert!(schedule.tasks.iter().all(|t| t.status == TaskStatus::Completed));
+    }
 
-            let prompt = template.render(&vars);
+    #[test]
+    fn test_apply_default_template_by_weekday() {
+        use crate::models::TemplateTask;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        let template = |name: &str, title: &str| ScheduleTemplate {
+            name: name.to_string(),
+            tasks: vec![TemplateTask {
+                title: title.to_string(),
+                start_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                end_time: NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+                tags: vec![],
+                notes: None,
+            }],
+        };
+        storage.save_template(&template("work", "Standup")).unwrap();
+        storage.save_template(&template("rest", "Long run")).unwrap();
 
-            println!("\n{}\n", "=".repeat(80).bright_blue());
-            println!("{}", "Claude Prompt".bright_cyan().bold());
-            println!("{}\n", "=".repeat(80).bright_blue());
-            println!("{}", prompt);
-            println!("\n{}\n", "=".repeat(80).bright_blue());
+        let config = Config {
+            default_weekday_template: Some("work".to_string()),
+            default_weekend_template: Some("rest".to_string()),
+            ..Config::default()
+        };
 
-            output::info("Copy the prompt above and paste it to Claude Code");
+        let saturday = at(1, 8, 0);
+        let tuesday = at(4, 8, 0);
+
+        for (date, expected) in [(saturday, "Long run"), (tuesday, "Standup")] {
+            apply_default_template(&storage, &config, date).unwrap();
+            let schedule = storage.load_schedule(date).unwrap().unwrap();
+            assert_eq!(schedule.tasks.len(), 1);
+            assert_eq!(schedule.tasks[0].title, expected);
         }
 
-        ClaudeAction::Validate => {
-            let template = PromptTemplate::schedule_validation();
-            let mut vars = HashMap::new();
-            vars.insert("context".to_string(), context.to_markdown());
+        // 이미 스케줄이 있으면 다시 적용하지 않음
+        assert_eq!(apply_default_template(&storage, &config, tuesday).unwrap(), None);
+    }
 
-            let prompt = template.render(&vars);
+    #[test]
+    fn test_resolve_selection() {
+        let start = Local::now();
+        let first = Task::new("First".to_string(), start, start + chrono::Duration::hours(1));
+        let second = Task::new("Second".to_string(), start, start + chrono::Duration::hours(1));
+        let candidates = vec![&first, &second];
 
-            println!("\n{}\n", "=".repeat(80).bright_blue());
-            println!("{}", "Schedule Validation Prompt".bright_cyan().bold());
-            println!("{}\n", "=".repeat(80).bright_blue());
-            println!("{}", prompt);
-            println!("\n{}\n", "=".repeat(80).bright_blue());
+        assert_eq!(resolve_selection(&candidates, Some(1)), Some(second.id.clone()));
+        assert_eq!(resolve_selection(&candidates, Some(2)), None);
+        assert_eq!(resolve_selection(&candidates, None), None);
+    }
 
-            output::info("Copy the prompt above and paste it to Claude Code");
+    #[test]
+    fn test_report_helpers_render_independently() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        for days_ago in 0..3 {
+            let day = Local::now() - chrono::Duration::days(days_ago);
+            let start = resolve_local(day.date_naive().and_hms_opt(9, 0, 0).unwrap());
+            let mut schedule = Schedule::new(day);
+            let mut task = Task::new("Focus".to_string(), start, start + chrono::Duration::hours(1));
+            task.complete();
+            task.actual_duration_minutes = Some(50);
+            schedule.add_task(task).unwrap();
+            storage.save_schedule(&schedule).unwrap();
         }
 
-        ClaudeAction::Optimize { situation } => {
-            let template = PromptTemplate::optimization();
-            let mut vars = HashMap::new();
-            vars.insert("context".to_string(), context.to_markdown());
-            vars.insert(
-                "situation".to_string(),
-                situation.unwrap_or_else(|| "Running behind schedule".to_string()),
-            );
+        let today = storage.load_today().unwrap().unwrap();
+        let fmt = ReportPrecision::default();
+        let daily = daily_report(&today, &fmt, None).unwrap();
+        assert!(daily.contains("Daily Time Accountability Report"));
+        assert!(daily.contains("Focus"));
 
-            let prompt = template.render(&vars);
+        let weekly = weekly_report(&storage, &fmt, None).unwrap();
+        assert!(weekly.contains("Week Summary"));
+        assert!(weekly.contains("Total Planned: 3h"));
 
-            println!("\n{}\n", "=".repeat(80).bright_blue());
-            println!("{}", "Optimization Prompt".bright_cyan().bold());
-            println!("{}\n", "=".repeat(80).bright_blue());
-            println!("{}", prompt);
-            println!("\n{}\n", "=".repeat(80).bright_blue());
+        let trend = efficiency_trend(&storage, 5, &fmt, None, 3).unwrap();
+        assert!(trend.contains("5-Day Efficiency Trend"));
+        assert_eq!(trend.matches("(no data)").count(), 2);
+    }
 
-            output::info("Copy the prompt above and paste it to Claude Code");
-        }
+    #[test]
+    fn test_command_error_variants() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
 
-        ClaudeAction::Context { format } => {
-            match format.to_lowercase().as_str() {
-                "json" => {
-                    println!("{}", context.to_json()?);
-                }
-                "markdown" | "md" => {
-                    println!("{}", context.to_markdown());
-                }
-                _ => {
-                    output::error(&format!("Unknown format: {}", format));
-                    output::info("Supported formats: json, markdown");
-                }
-            }
-        }
+        let err = start_task(&storage, None, true).unwrap_err();
+        assert!(matches!(err.downcast_ref::<SchedError>(), Some(SchedError::NotFound(_))));
+
+        let add = |start: &str, end: &str| {
+            add_task(&storage, add_args("Task", start, end), &Config::default())
+        };
+        let err = add("9am", "10:00").unwrap_err();
+        assert!(matches!(err.downcast_ref::<SchedError>(), Some(SchedError::Validation(_))));
+        let err = add("10:00", "09:00").unwrap_err();
+        assert_eq!(crate::error::exit_code(&err), 3);
+
+        add("09:00", "10:00").unwrap();
+        let err = delete_task(&storage, Some("missing".to_string()), true).unwrap_err();
+        assert_eq!(crate::error::exit_code(&err), 2);
     }
 
-    Ok(())
-}
+    #[test]
+    fn test_add_task_to_past_date_needs_force() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
 
-fn report_command(storage: &JsonStorage, week: bool, month: bool) -> anyhow::Result<()> {
-    use crate::models::DailyAccountability;
-    use chrono::Datelike;
+        let yesterday = (Local::now() - chrono::Duration::days(1)).date_naive();
+        let args = || AddArgs {
+            date: Some(yesterday.format("%Y-%m-%d").to_string()),
+            ..add_args("Late entry", "09:00", "10:00")
+        };
 
-    if month {
-        output::info("Monthly report not yet implemented");
-        return Ok(());
+        let err = add_task(&storage, args(), &Config::default()).unwrap_err();
+        assert!(matches!(err.downcast_ref::<SchedError>(), Some(SchedError::Validation(_))));
+        assert!(err.to_string().contains(&yesterday.format("%Y-%m-%d").to_string()));
+
+        add_task(&storage, AddArgs { force: true, ..args() }, &Config::default()).unwrap();
+        let day = resolve_local(yesterday.and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(storage.load_schedule(day).unwrap().unwrap().tasks.len(), 1);
     }
 
-    if week {
-        // Weekly report
-        let today = Local::now().date_naive();
-        let mut total_planned = 0i64;
-        let mut total_earned = 0i64;
-        let mut total_wasted = 0i64;
-        let mut total_bonus = 0i64;
-        let mut total_penalty = 0i64;
-
-        println!("\n{}", "Weekly Time Accountability Report".bold().green());
-        println!("{}", "=".repeat(50));
-
-        for days_ago in (0..7).rev() {
-            let date = today - chrono::Duration::days(days_ago);
-            let date_time = Local
-                .with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0)
-                .unwrap();
-
-            if let Ok(Some(schedule)) = storage.load_schedule(date_time) {
-                let daily = DailyAccountability::from_tasks(date_time, &schedule.tasks);
-
-                total_planned += daily.total_planned;
-                total_earned += daily.total_earned;
-                total_wasted += daily.total_wasted;
-                total_bonus += daily.total_bonus;
-                total_penalty += daily.total_penalty;
-
-                let score = daily.efficiency_score();
-                let grade = daily.grade();
-                let _score_color = if score >= 90.0 {
-                    "green"
-                } else if score >= 70.0 {
-                    "yellow"
-                } else {
-                    "red"
-                };
+    #[test]
+    fn test_bucket_duration() {
+        assert_eq!(bucket_duration(20), "<30m");
+        assert_eq!(bucket_duration(45), "30-60m");
+        assert_eq!(bucket_duration(90), "1-2h");
+        assert_eq!(bucket_duration(150), ">2h");
+    }
 
-                println!(
-                    "{}: Efficiency {:.1}% ({}) | Earned: {}m | Wasted: {}m",
-                    date.format("%Y-%m-%d"),
-                    score,
-                    grade,
-                    daily.net_earned(),
-                    daily.total_wasted
-                );
-            }
+    #[test]
+    fn test_open_target() {
+        let data_dir = std::path::Path::new("/data/scheduler");
+        let config_path = std::path::Path::new("/home/me/.config/scheduler/config.toml");
+
+        assert_eq!(open_target(data_dir, config_path, false), data_dir);
+        assert_eq!(
+            open_target(data_dir, config_path, true),
+            std::path::Path::new("/home/me/.config/scheduler")
+        );
+    }
+
+    #[test]
+    fn test_version_summary_lists_paths() {
+        let data_dir = std::path::Path::new("/data/scheduler");
+        let config_path = std::path::Path::new("/nonexistent/scheduler/config.toml");
+        let providers = vec![
+            (AiProvider::Claude, Ok((PathBuf::from("/npm/claude/cli.js"), "1.0.0".to_string()))),
+            (AiProvider::Copilot, Err("not found".to_string())),
+        ];
+
+        let summary = version_summary(data_dir, config_path, &providers);
+        assert!(summary.starts_with(&format!("sched {}", env!("CARGO_PKG_VERSION"))));
+        assert!(summary.contains("Data dir: /data/scheduler"));
+        assert!(summary.contains("config.toml (not created)"));
+        assert!(summary.contains("claude   1.0.0 (/npm/claude/cli.js)"));
+        assert!(summary.contains("copilot  unavailable: not found"));
+    }
+
+    #[test]
+    fn test_idle_gap_counts_as_wasted() {
+        let mut schedule = day_schedule();
+        for (title, start) in [("Plan", 9), ("Build", 11)] {
+            let mut task = Task::new(title.to_string(), at(4, start, 0), at(4, start + 1, 0));
+            task.status = TaskStatus::Completed;
+            task.actual_duration_minutes = Some(60);
+            schedule.add_task(task).unwrap();
         }
 
-        println!("{}", "=".repeat(50));
-        let week_score = if total_planned > 0 {
-            ((total_earned + total_bonus - total_penalty) as f64 / total_planned as f64) * 100.0
-        } else {
-            0.0
+        let hours = WakingHours {
+            start: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
         };
+        let without = day_accountability(&schedule, None);
+        let with = day_accountability(&schedule, Some(hours));
 
-        println!("\n{}", "Week Summary:".bold());
-        println!("  Total Planned: {}m", total_planned);
-        println!("  Total Earned: {}m", total_earned);
-        println!("  Total Bonus: {}m", total_bonus.to_string().green());
-        println!("  Total Penalty: {}m", total_penalty.to_string().yellow());
-        println!("  Total Wasted: {}m", total_wasted.to_string().red());
-        println!("\n  Overall Efficiency: {:.1}%", week_score);
+        assert_eq!(without.total_wasted, 0);
+        assert_eq!(with.total_wasted, 60);
+        assert_eq!(with.total_idle, 60);
+        assert_eq!(with.total_planned, without.total_planned + 60);
+    }
 
-    } else {
-        // Daily report
-        let schedule = storage
-            .load_today()?
-            .ok_or_else(|| anyhow::anyhow!("No schedule found"))?;
+    #[test]
+    fn test_daily_report_json() {
+        use chrono::Duration;
+
+        let mut schedule = Schedule::today();
+        let start = Local::now();
+
+        let mut done = Task::new("Done".to_string(), start, start + Duration::hours(1));
+        done.status = TaskStatus::Completed;
+        done.actual_duration_minutes = Some(60);
+        let pending = Task::new(
+            "Pending".to_string(),
+            start + Duration::hours(1),
+            start + Duration::hours(2),
+        );
+        schedule.add_task(done).unwrap();
+        schedule.add_task(pending).unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&daily_report_json(&schedule, None).unwrap()).unwrap();
+
+        assert_eq!(json["efficiency_score"].as_f64(), Some(50.0));
+        let tasks = json["tasks"].as_array().unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0]["title"], "Done");
+        assert_eq!(tasks[0]["accountability"]["earned_time"], 60);
+    }
 
-        let daily = DailyAccountability::from_tasks(Local::now(), &schedule.tasks);
+    #[test]
+    fn test_parse_sim_change() {
+        assert_eq!(
+            parse_sim_change("done:abc-1=45").unwrap(),
+            SimChange::Complete { task_id: "abc-1".to_string(), actual_minutes: 45 }
+        );
+        assert_eq!(
+            parse_sim_change("skip:abc-1").unwrap(),
+            SimChange::Skip { task_id: "abc-1".to_string() }
+        );
+        assert!(parse_sim_change("est:abc-1=soon").is_err());
+        assert!(parse_sim_change("move:abc-1").is_err());
+    }
 
-        println!("\n{}", "Daily Time Accountability Report".bold().green());
-        println!("{}", "=".repeat(50));
-        println!("Date: {}", daily.date.format("%Y-%m-%d"));
-        println!();
-        println!("  Total Planned: {}m ({:.1}h)", daily.total_planned, daily.total_planned as f64 / 60.0);
-        println!("  {} {}m ({:.1}h)", "Earned:".green(), daily.total_earned, daily.total_earned as f64 / 60.0);
+    #[test]
+    fn test_retitle_across_days() {
+        use chrono::Duration;
 
-        if daily.total_bonus > 0 {
-            println!("  {} +{}m 🎉", "Bonus:".bright_green(), daily.total_bonus);
-        }
-        if daily.total_penalty > 0 {
-            println!("  {} -{}m ⚠", "Penalty:".yellow(), daily.total_penalty);
-        }
-        if daily.total_wasted > 0 {
-            println!("  {} -{}m ✗", "Wasted:".red(), daily.total_wasted);
-        }
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let today = Local::now().date_naive();
 
-        println!();
-        println!("  {} {}m ({:.1}h)", "Net Earned:".bold(), daily.net_earned(), daily.net_earned() as f64 / 60.0);
-        println!("  {} {:.1}% ({})", "Efficiency Score:".bold(), daily.efficiency_score(), daily.grade());
-
-        // Task breakdown
-        println!("\n{}", "Task Breakdown:".bold());
-        println!("{}", "-".repeat(50));
-
-        for task in &schedule.tasks {
-            use crate::models::TimeAccountability;
-            let perf = TimeAccountability::from_task(task);
-
-            if let Some(msg) = perf.feedback_message() {
-                let colored_msg = if perf.bonus_time > 0 {
-                    msg.green()
-                } else if perf.penalty_time > 0 {
-                    msg.yellow()
-                } else if perf.wasted_time > 0 {
-                    msg.red()
-                } else {
-                    msg.green()
-                };
-                println!("  {} - {}", task.title, colored_msg);
+        let seeds = [(0, vec!["Reveiw PR 12", "Lunch"]), (1, vec!["Reveiw PR 7", "Reveiw docs"])];
+        for (days_ago, titles) in seeds {
+            let date = today - Duration::days(days_ago);
+            let mut schedule = Schedule::new(resolve_local(date.and_hms_opt(0, 0, 0).unwrap()));
+            for (i, title) in titles.into_iter().enumerate() {
+                let start = resolve_local(date.and_hms_opt(9 + i as u32, 0, 0).unwrap());
+                schedule.add_task(Task::new(title.to_string(), start, start + Duration::hours(1))).unwrap();
             }
+            storage.save_schedule(&schedule).unwrap();
         }
-    }
+        let titles_on = |days_ago| {
+            let date = today - Duration::days(days_ago);
+            storage
+                .load_schedule(resolve_local(date.and_hms_opt(0, 0, 0).unwrap()))
+                .unwrap()
+                .unwrap()
+                .tasks
+                .into_iter()
+                .map(|t| t.title)
+                .collect::<Vec<_>>()
+        };
 
-    Ok(())
-}
+        let changed = retitle_tasks(&storage, "Reveiw", "Review", false, 7).unwrap();
+        assert_eq!(changed.iter().map(|(_, n)| n).collect::<Vec<_>>(), vec![&2, &1]);
+        assert_eq!(titles_on(1), vec!["Review PR 7", "Review docs"]);
 
-fn efficiency_command(storage: &JsonStorage, days: Option<usize>) -> anyhow::Result<()> {
-    use crate::models::DailyAccountability;
-    use chrono::Datelike;
+        let changed = retitle_tasks(&storage, r"PR (\d+)", "PR #$1", true, 7).unwrap();
+        assert_eq!(changed.len(), 2);
+        assert_eq!(titles_on(0), vec!["Review PR #12", "Lunch"]);
+        assert_eq!(titles_on(1), vec!["Review PR #7", "Review docs"]);
 
-    let num_days = days.unwrap_or(7);
-    let today = Local::now().date_naive();
+        assert!(retitle_tasks(&storage, "", "x", false, 7).is_err());
+        assert!(retitle_tasks(&storage, "Lunch", " ", false, 7).is_err());
+        assert_eq!(titles_on(0)[1], "Lunch");
+    }
 
-    println!("\n{}", format!("{}-Day Efficiency Trend", num_days).bold().cyan());
-    println!("{}", "=".repeat(60));
+    #[test]
+    fn test_rename_tag_across_days() {
+        use chrono::Duration;
 
-    let mut scores: Vec<(String, f64)> = Vec::new();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let today = Local::now().date_naive();
 
-    for days_ago in (0..num_days).rev() {
-        let date = today - chrono::Duration::days(days_ago as i64);
-        let date_time = Local
-            .with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0)
-            .unwrap();
+        let seeds = [
+            (0, vec![vec!["proj-x"], vec!["proj-x", "acme"]]),
+            (1, vec![vec!["proj-x", "code"], vec!["home"]]),
+        ];
+        for (days_ago, tag_sets) in seeds {
+            let date = today - Duration::days(days_ago);
+            let mut schedule = Schedule::new(resolve_local(date.and_hms_opt(0, 0, 0).unwrap()));
+            for (i, tags) in tag_sets.into_iter().enumerate() {
+                let start = resolve_local(date.and_hms_opt(9 + i as u32, 0, 0).unwrap());
+                let mut task = Task::new(format!("Task {}", i), start, start + Duration::hours(1));
+                task.tags = tags.into_iter().map(String::from).collect();
+                schedule.add_task(task).unwrap();
+            }
+            storage.save_schedule(&schedule).unwrap();
+        }
 
-        if let Ok(Some(schedule)) = storage.load_schedule(date_time) {
-            let daily = DailyAccountability::from_tasks(date_time, &schedule.tasks);
-            let score = daily.efficiency_score();
-            scores.push((date.format("%m/%d").to_string(), score));
+        assert_eq!(rename_tag(&storage, "proj-x", "acme", 7).unwrap(), (3, 2));
+
+        let tags_on = |days_ago| {
+            let date = today - Duration::days(days_ago);
+            storage
+                .load_schedule(resolve_local(date.and_hms_opt(0, 0, 0).unwrap()))
+                .unwrap()
+                .unwrap()
+                .tasks
+                .into_iter()
+                .map(|t| t.tags)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(tags_on(0), vec![vec!["acme"], vec!["acme"]]);
+        assert_eq!(tags_on(1), vec![vec!["acme", "code"], vec!["home"]]);
 
-            // ASCII bar chart
-            let bar_length = (score / 100.0 * 40.0) as usize;
-            let bar = "█".repeat(bar_length);
+        assert_eq!(rename_tag(&storage, "proj-x", "acme", 7).unwrap(), (0, 0));
+    }
 
-            let colored_bar = if score >= 90.0 {
-                bar.green()
-            } else if score >= 70.0 {
-                bar.yellow()
-            } else {
-                bar.red()
-            };
+    #[test]
+    fn test_streak_json() {
+        let mut streak = StreakInfo::new();
+        streak.current_streak = 12;
+        streak.best_streak = 20;
 
-            println!("{} | {:<40} {:.1}% ({})",
-                date.format("%m/%d"),
-                colored_bar,
-                score,
-                daily.grade()
-            );
-        } else {
-            println!("{} | {} (no data)", date.format("%m/%d"), "░".repeat(40).bright_black());
-        }
+        let json: serde_json::Value = serde_json::from_str(&streak_json(&streak).unwrap()).unwrap();
+        assert_eq!(json["current"], 12);
+        assert_eq!(json["best"], 20);
+        assert!(json["last_update"].is_string());
     }
 
-    println!("{}", "=".repeat(60));
+    #[test]
+    fn test_compose_summary() {
+        use chrono::Duration;
+
+        let mut schedule = Schedule::new(at(1, 0, 0));
+        for (i, actual) in [(9, 60), (10, 90), (12, 30)] {
+            let mut task = Task::new(format!("Task {}", i), at(1, i, 0), at(1, i, 0) + Duration::hours(1));
+            task.status = TaskStatus::Completed;
+            task.actual_duration_minutes = Some(actual);
+            task.tags = vec!["work".to_string()];
+            schedule.add_task(task).unwrap();
+        }
+        schedule
+            .add_task(Task::new("Later".to_string(), at(1, 15, 0), at(1, 16, 0)))
+            .unwrap();
 
-    // Calculate average
-    if !scores.is_empty() {
-        let avg = scores.iter().map(|(_, s)| s).sum::<f64>() / scores.len() as f64;
-        println!("Average Efficiency: {:.1}%", avg);
+        let text = compose_summary(&schedule, &StreakInfo::new());
 
-        // Trend
-        if scores.len() >= 2 {
-            let recent_avg = scores[scores.len().saturating_sub(3)..].iter().map(|(_, s)| s).sum::<f64>()
-                / scores.len().saturating_sub(3).max(1) as f64;
-            let early_avg = scores[..scores.len().saturating_sub(3).max(1)].iter().map(|(_, s)| s).sum::<f64>()
-                / scores.len().saturating_sub(3).max(1) as f64;
-
-            if recent_avg > early_avg + 5.0 {
-                output::success("Improving trend! 📈");
-            } else if recent_avg < early_avg - 5.0 {
-                output::error("Declining trend 📉");
-            } else {
-                output::info("Stable performance");
-            }
-        }
+        assert!(text.contains("completed 3 of 4 tasks"));
+        assert!(text.contains("Efficiency grade: D"));
+        assert!(text.contains("'Task 10' at 1h 30m"));
+        assert!(text.contains("#work"));
+        assert!(text.contains("streak advanced to 1 days"));
     }
 
-    Ok(())
+    #[test]
+    fn test_weekly_digest_rows_and_total() {
+        let mut schedules = Vec::new();
+        for (d, actual) in [(3, 60), (5, 120)] {
+            let mut schedule = Schedule::new(at(d, 0, 0));
+            let mut task = Task::new(format!("Work {}", d), at(d, 9, 0), at(d, 10, 0));
+            task.status = TaskStatus::Completed;
+            task.actual_duration_minutes = Some(actual);
+            task.tags = vec!["deep".to_string()];
+            schedule.add_task(task).unwrap();
+            schedules.push(schedule);
+        }
+        let (from, to) = WeeklyGoals::week_range(at(3, 0, 0).date_naive());
+
+        let digest = weekly_digest(from, to, &schedules, &StreakInfo::new(), None).unwrap();
+
+        assert!(digest.starts_with("# Weekly Digest: 2025-11-03 – 2025-11-09"));
+        assert!(digest.contains("| 2025-11-03 (Mon) | 1/1 | 1h |"));
+        assert!(digest.contains("| 2025-11-05 (Wed) | 1/1 | 2h |"));
+        assert!(!digest.contains("2025-11-04"));
+        assert!(digest.contains("| **Week** | **2/2** | **3h** |"));
+        assert!(digest.contains("- #deep: 3h"));
+        assert!(digest.contains("Over: \"Work 5\" (Wed): 120m actual vs 60m estimated (+60m)"));
+        assert!(!digest.contains("\"Work 3\""));
+    }
 }
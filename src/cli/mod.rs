@@ -1,40 +1,226 @@
 pub mod commands;
 pub mod output;
+pub mod spinner;
 
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use clap::{ArgAction, Args, Parser, Subcommand};
+use log::LevelFilter;
 
 #[derive(Parser)]
 #[command(name = "sched")]
 #[command(about = "Daily task scheduler with AI integration", long_about = None)]
 #[command(version)]
 pub struct Cli {
+    /// Increase log output (-v info, -vv debug, -vvv trace)
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Only log errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Use the data directory of this profile from config.toml
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+
+    /// Show what would change without writing any files
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+impl Cli {
+    /// `-v`/`--quiet` 플래그에 해당하는 로그 레벨
+    pub fn log_level(&self) -> LevelFilter {
+        log_level(self.verbose, self.quiet)
+    }
+}
+
+/// 플래그를 로그 레벨로 변환 (기본 Warn)
+pub fn log_level(verbose: u8, quiet: bool) -> LevelFilter {
+    if quiet {
+        return LevelFilter::Error;
+    }
+
+    match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
-    Add {
-        title: String,
+    Add(AddArgs),
+    /// Change a task's title, time, tags or notes in place
+    Edit(EditArgs),
+    List {
+        /// Print one line per task
         #[arg(short, long)]
-        start: String,
-        #[arg(short, long)]
-        end: String,
-        #[arg(short, long)]
-        tags: Option<String>,
-        #[arg(short, long)]
-        notes: Option<String>,
+        compact: bool,
     },
-    List,
     Start {
         id: Option<String>,
+        /// Never prompt; pick the next task automatically
+        #[arg(long)]
+        no_input: bool,
     },
     Pause,
-    Complete,
+    Complete {
+        /// How well you focused, 1-10 (shown by `sched report --focus`)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=10))]
+        focus: Option<u8>,
+    },
     Status,
+    /// Print one line: the current task, the next one, or "idle"
+    Now {
+        /// Output format: text or json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
     Delete {
+        id: Option<String>,
+        /// Never prompt for a task
+        #[arg(long)]
+        no_input: bool,
+    },
+    /// Record how far along a task is (0-100%)
+    Progress {
+        id: String,
+        percent: f64,
+    },
+    /// Log something that interrupted a task
+    Interrupt {
+        id: String,
+        /// What interrupted you (e.g. "Slack ping")
+        reason: String,
+        /// Also pause the task if it is in progress
+        #[arg(long)]
+        pause: bool,
+    },
+    /// Skip a task (counts its whole estimate as wasted)
+    Skip {
+        /// Task to skip (defaults to the current task)
+        id: Option<String>,
+    },
+    /// Mark a task as blocked on something external
+    Block {
+        id: String,
+        /// What the task is waiting on
+        reason: Option<String>,
+    },
+    /// Clear a task's blocked state
+    Unblock {
         id: String,
     },
+    /// Pin a task as the current one when several are in progress
+    Focus {
+        id: String,
+    },
+    /// Combine two adjacent or overlapping tasks into one
+    Merge {
+        first: String,
+        second: String,
+    },
+    /// Spread today's unstarted tasks evenly between two times
+    Rebalance {
+        /// Window start (HH:MM); never earlier than now
+        #[arg(short, long)]
+        start: String,
+        /// Window end (HH:MM)
+        #[arg(short, long)]
+        end: String,
+    },
+    /// Copy yesterday's unfinished tasks into today
+    Carryover,
+    /// Export a day's tasks (currently only iCalendar)
+    Export {
+        #[arg(short, long, default_value = "ics")]
+        format: String,
+        /// Day to export (YYYY-MM-DD, defaults to today)
+        #[arg(short, long)]
+        date: Option<String>,
+        /// Write to this file instead of stdout
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+    /// Import events from an iCalendar (.ics) file as tasks
+    Import {
+        file: PathBuf,
+    },
+    /// Archive the whole data directory and config into a zip file
+    Backup {
+        /// Archive path (defaults to a timestamped file in the current directory)
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+    /// Restore a backup archive created by `sched backup`
+    Restore {
+        archive: PathBuf,
+        /// Restore into this data directory instead of the default one
+        #[arg(long)]
+        data_dir: Option<PathBuf>,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Check the data directory and fix what can be fixed safely
+    Repair {
+        /// Only report problems, change nothing
+        #[arg(long)]
+        check: bool,
+    },
+    /// Manage tags across saved days
+    Tag {
+        #[command(subcommand)]
+        action: TagAction,
+    },
+    /// Find and replace text in task titles across recent days
+    Retitle {
+        pattern: String,
+        replacement: String,
+        /// Treat the pattern as a regular expression ($1 etc. in the replacement)
+        #[arg(long)]
+        regex: bool,
+        /// How many days back to update, including today
+        #[arg(short, long, default_value_t = 365)]
+        days: i64,
+    },
+    /// Set and review this week's goals
+    Goal {
+        #[command(subcommand)]
+        action: GoalAction,
+    },
+    /// Open the data directory (or config directory) in the file manager
+    Open {
+        /// Open the directory holding config.toml instead
+        #[arg(long)]
+        config: bool,
+    },
+    /// Serve today's schedule, stats and streak as read-only JSON over HTTP
+    Serve {
+        #[arg(short, long, default_value_t = crate::server::DEFAULT_PORT)]
+        port: u16,
+        /// Address to listen on; use 0.0.0.0 to allow other devices on the network
+        #[arg(long, default_value = crate::server::DEFAULT_BIND)]
+        bind: String,
+    },
+    /// Print the version; with --verbose also the data dir, config path and AI CLIs
+    Version,
+    /// Show the data directory profiles from config.toml
+    Profile {
+        #[command(subcommand)]
+        action: ProfileAction,
+    },
+    /// Save and apply reusable day templates
+    Template {
+        #[command(subcommand)]
+        action: TemplateAction,
+    },
     Daemon {
         #[command(subcommand)]
         action: DaemonAction,
@@ -45,30 +231,198 @@ pub enum Commands {
     Stats {
         #[arg(short, long)]
         week: bool,
+        /// Show completed focus time as a weekday × hour heatmap
+        #[arg(long, conflicts_with = "week")]
+        heatmap: bool,
+        /// How many days back the heatmap covers, including today
+        #[arg(short, long, default_value_t = 28, requires = "heatmap")]
+        days: i64,
+    },
+    /// Find tasks added at the same time several days in a row and offer to make them recurring
+    SuggestHabits {
+        /// How many days back to look, including today
+        #[arg(short, long, default_value_t = 14)]
+        days: i64,
+    },
+    /// Show a month calendar with each day's efficiency grade
+    Calendar {
+        /// Month to show (YYYY-MM, default: this month)
+        #[arg(short, long)]
+        month: Option<String>,
+    },
+    Streak {
+        /// Output format: text or json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+        /// Print only "🔥 <days>" (for prompts and status bars)
+        #[arg(long)]
+        oneline: bool,
     },
-    Streak,
     Pomodoro {
         #[command(subcommand)]
         action: PomodoroAction,
     },
     Claude {
+        /// Collect fresh context instead of reusing the last one
+        #[arg(long)]
+        no_cache: bool,
         #[command(subcommand)]
         action: ClaudeAction,
     },
     /// Show time accountability report
-    Report {
+    Report(ReportArgs),
+    /// Add the recurring tasks due on a day (done automatically for today)
+    Expand {
+        /// Day to fill in (YYYY-MM-DD, defaults to today)
         #[arg(short, long)]
-        week: bool,
+        date: Option<String>,
+    },
+    /// Write a markdown digest of a week: per-day efficiency, focus time, top tags, streak, estimate misses
+    Digest {
+        /// Any day in the week to summarize (YYYY-MM-DD, defaults to this week)
+        #[arg(short, long)]
+        week: Option<String>,
+        /// Write to this file instead of stdout
         #[arg(short, long)]
-        month: bool,
+        out: Option<PathBuf>,
+    },
+    /// Print a one-paragraph recap of today
+    Summary {
+        /// Build a Claude prompt for a richer narrative
+        #[arg(long)]
+        ai: bool,
     },
     /// Show time efficiency score trend
     Efficiency {
         #[arg(short, long)]
         days: Option<usize>,
+        /// Print a one-line sparkline with the average instead of the chart
+        #[arg(long)]
+        sparkline: bool,
+    },
+    /// Preview today's efficiency after hypothetical changes (nothing is saved)
+    Simulate {
+        /// Changes: done:<id>=<minutes>, est:<id>=<minutes>, skip:<id>
+        #[arg(required = true)]
+        changes: Vec<String>,
     },
 }
 
+impl Commands {
+    /// 오늘 스케줄을 바꾸는 명령인지 (이때만 기본 템플릿, 반복 작업, 이월 확인으로 하루를 시작)
+    ///
+    /// 조회나 `--json` 출력 명령은 파일을 쓰거나 stdout에 안내를 섞지 않도록 제외합니다.
+    pub fn mutates_today(&self) -> bool {
+        matches!(
+            self,
+            Commands::Add(_)
+                | Commands::Edit(_)
+                | Commands::Start { .. }
+                | Commands::Pause
+                | Commands::Complete { .. }
+                | Commands::Delete { .. }
+                | Commands::Progress { .. }
+                | Commands::Interrupt { .. }
+                | Commands::Skip { .. }
+                | Commands::Block { .. }
+                | Commands::Unblock { .. }
+                | Commands::Focus { .. }
+                | Commands::Merge { .. }
+                | Commands::Rebalance { .. }
+                | Commands::Pomodoro {
+                    action: PomodoroAction::Start | PomodoroAction::Complete
+                }
+                | Commands::Widget
+                | Commands::Ui
+        )
+    }
+}
+
+#[derive(Args, Default)]
+pub struct AddArgs {
+    /// Task title, or a natural-language request with --ai
+    pub title: String,
+    #[arg(short, long, required_unless_present = "ai")]
+    pub start: Option<String>,
+    #[arg(short, long, required_unless_present = "ai")]
+    pub end: Option<String>,
+    #[arg(short, long)]
+    pub tags: Option<String>,
+    #[arg(short, long)]
+    pub notes: Option<String>,
+    /// Link to a related doc or issue
+    #[arg(short, long)]
+    pub link: Option<String>,
+    /// Day to add the task to (YYYY-MM-DD, defaults to today)
+    #[arg(short, long)]
+    pub date: Option<String>,
+    /// Hard deadline (HH:MM on the task's day, or "YYYY-MM-DD HH:MM")
+    #[arg(long)]
+    pub deadline: Option<String>,
+    /// Allow adding to a date that has already passed
+    #[arg(long)]
+    pub force: bool,
+    /// Shift later tasks so every gap is at least `min_break_minutes`
+    #[arg(long)]
+    pub enforce_break: bool,
+    /// Immovable block (lunch, commute): rebalancing and shifting plan around it
+    #[arg(long)]
+    pub fixed: bool,
+    /// Priority: low, medium (default), high or critical
+    #[arg(short, long)]
+    pub priority: Option<String>,
+    /// Repeat the task: daily, weekdays, or days like mon,wed,fri
+    #[arg(short, long)]
+    pub repeat: Option<String>,
+    /// Last day to repeat on (YYYY-MM-DD)
+    #[arg(long, requires = "repeat")]
+    pub repeat_until: Option<String>,
+    /// Ask the AI provider to turn the title into a task, e.g. "30분 조깅 아침에"
+    #[arg(long, conflicts_with_all = ["start", "end", "date", "repeat", "enforce_break"])]
+    pub ai: bool,
+}
+
+#[derive(Args, Default)]
+pub struct EditArgs {
+    /// Task to edit (today's schedule)
+    pub id: String,
+    #[arg(long)]
+    pub title: Option<String>,
+    #[arg(short, long)]
+    pub start: Option<String>,
+    #[arg(short, long)]
+    pub end: Option<String>,
+    /// Comma-separated tags, replacing the current ones
+    #[arg(short, long)]
+    pub tags: Option<String>,
+    /// New notes (an empty string clears them)
+    #[arg(short, long)]
+    pub notes: Option<String>,
+}
+
+#[derive(Args, Default)]
+pub struct ReportArgs {
+    #[arg(short, long)]
+    pub week: bool,
+    #[arg(short, long)]
+    pub month: bool,
+    /// Print the daily report as JSON
+    #[arg(long)]
+    pub json: bool,
+    /// Print the daily report, weekly summary, and efficiency trend together
+    #[arg(short, long)]
+    pub all: bool,
+    /// Split today's earned/wasted time by morning, afternoon, evening, night
+    #[arg(short, long)]
+    pub segments: bool,
+    /// Show completed focus time per hour of day over the last 30 days
+    #[arg(short, long)]
+    pub peak: bool,
+    /// Show the average self-rated focus score per day for the last 7 days
+    #[arg(long)]
+    pub focus: bool,
+}
+
 #[derive(Subcommand)]
 pub enum DaemonAction {
     Start,
@@ -78,6 +432,66 @@ pub enum DaemonAction {
 
 pub use commands::execute_command;
 
+#[derive(Subcommand)]
+pub enum TagAction {
+    /// List tags used recently, most frequent first
+    List {
+        /// How many days back to look, including today
+        #[arg(short, long, default_value_t = 30)]
+        days: i64,
+    },
+    /// Rename a tag on every task in the last N days
+    Rename {
+        old: String,
+        new: String,
+        /// How many days back to update, including today
+        #[arg(short, long, default_value_t = 365)]
+        days: i64,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum GoalAction {
+    /// Add a goal for this week
+    Add {
+        description: String,
+        /// Target time, e.g. 20h, 90m or 1h30m
+        #[arg(short, long)]
+        target: Option<String>,
+        /// Count completed tasks with this tag toward the target
+        #[arg(long)]
+        tag: Option<String>,
+    },
+    /// List this week's goals with progress
+    List,
+    /// Mark a goal as done (number from `sched goal list`)
+    Done { number: usize },
+    /// Compare each goal against the week's completed time
+    Review {
+        /// Review last week instead of this week
+        #[arg(long)]
+        last: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ProfileAction {
+    /// List configured profiles and their data directories
+    List,
+    /// Print the active profile and data directory
+    Current,
+}
+
+#[derive(Subcommand)]
+pub enum TemplateAction {
+    /// Save today's tasks as a template
+    Save { name: String },
+    /// Add a template's tasks to today
+    Apply { name: String },
+    /// List saved templates
+    List,
+}
+
 #[derive(Subcommand)]
 pub enum PomodoroAction {
     Start,
@@ -104,3 +518,51 @@ pub enum ClaudeAction {
         format: String,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_level_mapping() {
+        let cli = Cli::parse_from(["sched", "-vv", "list"]);
+        assert_eq!(cli.log_level(), LevelFilter::Debug);
+
+        assert_eq!(log_level(0, false), LevelFilter::Warn);
+        assert_eq!(log_level(1, false), LevelFilter::Info);
+        assert_eq!(log_level(5, false), LevelFilter::Trace);
+        assert_eq!(log_level(0, true), LevelFilter::Error);
+    }
+
+    #[test]
+    fn test_add_ai_needs_no_times() {
+        let cli = Cli::parse_from(["sched", "add", "--ai", "30분 조깅 아침에"]);
+        assert!(matches!(cli.command, Commands::Add(AddArgs { ai: true, start: None, .. })));
+
+        assert!(Cli::try_parse_from(["sched", "add", "Run"]).is_err());
+    }
+
+    #[test]
+    fn test_dry_run_flag_is_global() {
+        let cli = Cli::parse_from(["sched", "carryover", "--dry-run"]);
+        assert!(cli.dry_run);
+    }
+
+    #[test]
+    fn test_only_mutating_commands_start_the_day() {
+        let mutates = |args: &[&str]| Cli::parse_from(args).command.mutates_today();
+        assert!(mutates(&["sched", "add", "Run", "--start", "07:00", "--end", "08:00"]));
+        assert!(mutates(&["sched", "complete"]));
+        assert!(mutates(&["sched", "pomodoro", "start"]));
+        assert!(!mutates(&["sched", "pomodoro", "status"]));
+        assert!(!mutates(&["sched", "list"]));
+        assert!(!mutates(&["sched", "report", "--json"]));
+        assert!(!mutates(&["sched", "export", "--format", "ics"]));
+    }
+
+    #[test]
+    fn test_profile_flag_is_global() {
+        let cli = Cli::parse_from(["sched", "list", "--profile", "work"]);
+        assert_eq!(cli.profile.as_deref(), Some("work"));
+    }
+}
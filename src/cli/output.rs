@@ -1,14 +1,179 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
 use colored::*;
-use crate::models::{Task, TaskStatus, Schedule};
+use terminal_size::{terminal_size, Width};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
-pub fn print_task(task: &Task) {
-    let status_icon = match task.status {
+use crate::config::DurationStyle;
+use crate::models::{Priority, Task, TaskStatus, Schedule};
+
+/// 터미널 폭을 알 수 없을 때 사용하는 기본 폭
+const DEFAULT_TERMINAL_WIDTH: usize = 80;
+
+/// 레이아웃 계산에 사용하는 최소 폭
+const MIN_TERMINAL_WIDTH: usize = 40;
+
+/// 막대 그래프의 최소 길이
+const MIN_BAR_LENGTH: usize = 10;
+
+/// `print_task` 한 줄에서 제목 외 내용(아이콘, 시간, 소요 시간)이 차지하는 폭
+const TASK_LINE_RESERVED: usize = 26;
+
+/// 상태를 색 외에 기호와 이름으로도 표시할지 (`accessible_symbols` 설정)
+static ACCESSIBLE_SYMBOLS: AtomicBool = AtomicBool::new(false);
+
+/// 접근성 모드 켜기/끄기 (명령 실행 전에 설정에서 한 번 호출)
+pub fn set_accessible_symbols(enabled: bool) {
+    ACCESSIBLE_SYMBOLS.store(enabled, Ordering::Relaxed);
+}
+
+/// 접근성 모드인지
+pub fn accessible_symbols() -> bool {
+    ACCESSIBLE_SYMBOLS.load(Ordering::Relaxed)
+}
+
+/// 예상 시간을 넘겨도 OVERDUE로 표시하지 않는 여유 (`overdue_grace_minutes` 설정)
+static OVERDUE_GRACE_MINUTES: AtomicU32 = AtomicU32::new(0);
+
+/// 초과 표시 여유 설정 (명령 실행 전에 설정에서 한 번 호출)
+pub fn set_overdue_grace_minutes(minutes: u32) {
+    OVERDUE_GRACE_MINUTES.store(minutes, Ordering::Relaxed);
+}
+
+/// 초과 표시 여유 (분)
+pub fn overdue_grace_minutes() -> u32 {
+    OVERDUE_GRACE_MINUTES.load(Ordering::Relaxed)
+}
+
+/// 현재 터미널 폭 (최소 폭 보장)
+pub fn terminal_width() -> usize {
+    terminal_size()
+        .map(|(Width(w), _)| w as usize)
+        .unwrap_or(DEFAULT_TERMINAL_WIDTH)
+        .max(MIN_TERMINAL_WIDTH)
+}
+
+/// 터미널 폭에 맞춘 구분선 (`max` 이하)
+pub fn separator(ch: &str, max: usize) -> String {
+    ch.repeat(terminal_width().min(max))
+}
+
+/// 다른 내용이 차지하는 `reserved` 열을 제외하고 막대 그래프에 쓸 수 있는 길이
+pub fn bar_width(reserved: usize, max: usize) -> usize {
+    available_bar_width(terminal_width(), reserved, max)
+}
+
+/// 주어진 폭에서 막대 그래프 길이 계산 (`MIN_BAR_LENGTH`..=`max`)
+pub fn available_bar_width(width: usize, reserved: usize, max: usize) -> usize {
+    width.saturating_sub(reserved).clamp(MIN_BAR_LENGTH, max.max(MIN_BAR_LENGTH))
+}
+
+/// 백분율을 막대 길이로 변환
+pub fn bar_length(percentage: f64, bar_width: usize) -> usize {
+    ((percentage / 100.0).clamp(0.0, 1.0) * bar_width as f64) as usize
+}
+
+/// 분 단위 시간을 리포트 형식으로 표시 ("3h 20m", "45m" 또는 "3.3h")
+pub fn format_duration(minutes: i64, style: DurationStyle) -> String {
+    let sign = if minutes < 0 { "-" } else { "" };
+    let abs = minutes.abs();
+
+    match style {
+        DurationStyle::HoursMinutes => match (abs / 60, abs % 60) {
+            (0, m) => format!("{}{}m", sign, m),
+            (h, 0) => format!("{}{}h", sign, h),
+            (h, m) => format!("{}{}h {}m", sign, h, m),
+        },
+        DurationStyle::DecimalHours => format!("{}{:.1}h", sign, abs as f64 / 60.0),
+    }
+}
+
+/// 백분율을 소수점 `precision`자리로 표시 ("91.7%")
+pub fn format_percent(value: f64, precision: usize) -> String {
+    format!("{:.*}%", precision, value)
+}
+
+/// 값들을 8단계 블록 문자 한 줄로 표시 (최솟값 ▁ ~ 최댓값 █)
+pub fn sparkline(values: &[f64]) -> String {
+    const GLYPHS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    values
+        .iter()
+        .map(|value| {
+            // 모든 값이 같으면 가운데 높이로
+            let level = if range > 0.0 { (value - min) / range } else { 0.5 };
+            GLYPHS[(level * (GLYPHS.len() - 1) as f64).round() as usize]
+        })
+        .collect()
+}
+
+/// 제목을 화면 폭 `max` 열 이하로 줄이고, 줄였으면 말줄임표(…)를 붙임
+///
+/// 한글 같은 전각 문자는 2열로 계산하고, 그래핌 중간에서는 자르지 않습니다.
+pub fn truncate_title(title: &str, max: usize) -> String {
+    if title.width() <= max {
+        return title.to_string();
+    }
+    if max == 0 {
+        return String::new();
+    }
+
+    let mut truncated = String::new();
+    let mut width = 0;
+    for grapheme in title.graphemes(true) {
+        width += grapheme.width();
+        if width > max - 1 {
+            break;
+        }
+        truncated.push_str(grapheme);
+    }
+    truncated.push('…');
+    truncated
+}
+
+/// 제목 앞 우선순위 표시 (보통이면 빈 문자열, 아니면 뒤에 공백 포함)
+fn priority_marker(priority: Priority) -> String {
+    let marker = priority.marker();
+    let colored = match priority {
+        Priority::Medium => return String::new(),
+        Priority::Critical => marker.red().bold(),
+        Priority::High => marker.yellow().bold(),
+        Priority::Low => marker.dimmed(),
+    };
+    format!("{} ", colored)
+}
+
+fn status_icon(status: TaskStatus) -> ColoredString {
+    if accessible_symbols() {
+        let marker = format!("{} {:<11}", status.symbol(), status.label());
+        return match status {
+            TaskStatus::Completed => marker.green(),
+            TaskStatus::InProgress => marker.bright_green(),
+            TaskStatus::Pending => marker.white(),
+            TaskStatus::Paused => marker.yellow(),
+            TaskStatus::Skipped => marker.red(),
+            TaskStatus::Blocked => marker.magenta(),
+        };
+    }
+
+    match status {
         TaskStatus::Completed => "✓".green(),
         TaskStatus::InProgress => "▶".bright_green(),
         TaskStatus::Pending => " ".white(),
         TaskStatus::Paused => "⏸".yellow(),
         TaskStatus::Skipped => "⊘".red(),
-    };
+        TaskStatus::Blocked => "⊗".magenta(),
+    }
+}
+
+pub fn print_task(task: &Task) {
+    let status_icon = status_icon(task.status);
 
     let time_range = format!(
         "{} - {}",
@@ -16,13 +181,31 @@ pub fn print_task(task: &Task) {
         task.end_time.format("%H:%M")
     );
 
-    println!(
-        "{} {} {} ({})",
-        status_icon,
-        time_range.cyan(),
-        task.title.bold(),
-        format!("{}min", task.estimated_duration_minutes).dimmed()
-    );
+    let marker = priority_marker(task.priority);
+    let marker_width = task.priority.marker().chars().count() + usize::from(!marker.is_empty());
+
+    // 파이프로 넘길 때는 전체 제목 유지
+    let title = if io::stdout().is_terminal() {
+        truncate_title(&task.title, terminal_width().saturating_sub(TASK_LINE_RESERVED + marker_width))
+    } else {
+        task.title.clone()
+    };
+
+    let length = if task.fixed {
+        format!("{}min, fixed", task.estimated_duration_minutes)
+    } else {
+        format!("{}min", task.estimated_duration_minutes)
+    };
+    println!("{} {} {}{} ({})", status_icon, time_range.cyan(), marker, title.bold(), length.dimmed());
+
+    if let Some(deadline) = task.deadline {
+        let text = format!("Deadline: {}", deadline.format("%m-%d %H:%M"));
+        if task.is_deadline_urgent() {
+            println!("    {} {}", "!".red().bold(), text.red());
+        } else {
+            println!("    {}", text.dimmed());
+        }
+    }
 
     if let Some(notes) = &task.notes {
         println!("    {}", notes.dimmed());
@@ -32,12 +215,26 @@ pub fn print_task(task: &Task) {
         println!("    Tags: {}", task.tags.join(", ").blue());
     }
 
+    if let Some(link) = &task.link {
+        println!("    Link: {}", hyperlink(link, link).underline());
+    }
+
+    if task.status == TaskStatus::Blocked {
+        let reason = task.blocked_reason.as_deref().unwrap_or("no reason given");
+        println!("    Blocked: {}", reason.magenta());
+    }
+
     if task.status == TaskStatus::InProgress {
         if let Some(elapsed) = task.elapsed_minutes() {
-            let progress = if elapsed > task.estimated_duration_minutes {
+            let percent = task.progress_ratio() * 100.0;
+            let progress = if task.manual_progress.is_none() && task.is_overdue_with_grace(overdue_grace_minutes()) {
                 format!("{}min (OVERDUE)", elapsed).red()
             } else {
-                format!("{}min / {}min", elapsed, task.estimated_duration_minutes).green()
+                format!(
+                    "{:.0}% ({}min / {}min)",
+                    percent, elapsed, task.estimated_duration_minutes
+                )
+                .green()
             };
             println!("    Progress: {}", progress);
         }
@@ -65,6 +262,94 @@ pub fn print_schedule(schedule: &Schedule) {
     );
 }
 
+/// 지원하는 터미널에서는 OSC 8 하이퍼링크로, 아니면 텍스트 그대로 출력
+pub fn hyperlink(url: &str, text: &str) -> String {
+    if supports_hyperlinks() {
+        format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// OSC 8 하이퍼링크 지원 여부 (TTY이고 dumb 터미널이 아닌 경우)
+fn supports_hyperlinks() -> bool {
+    io::stdout().is_terminal()
+        && std::env::var("TERM").map(|t| t != "dumb").unwrap_or(true)
+}
+
+/// 한 줄 요약 형식으로 스케줄 출력 (`sched list --compact`)
+pub fn print_schedule_compact(schedule: &Schedule) {
+    if schedule.tasks.is_empty() {
+        println!("{}", "No tasks scheduled.".dimmed());
+        return;
+    }
+
+    println!("{}", render_compact(schedule));
+}
+
+/// 작업당 정확히 한 줄씩 렌더링 (메모/태그 제외)
+pub fn render_compact(schedule: &Schedule) -> String {
+    schedule
+        .tasks
+        .iter()
+        .map(compact_line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn compact_line(task: &Task) -> String {
+    format!(
+        "{} {} {} {}",
+        status_icon(task.status),
+        format!(
+            "{}-{}",
+            task.start_time.format("%H:%M"),
+            task.end_time.format("%H:%M")
+        )
+        .cyan(),
+        mini_progress(task),
+        task.title.replace('\n', " ")
+    )
+}
+
+/// 5칸짜리 작은 진행 표시 (예: ▰▰▱▱▱)
+fn mini_progress(task: &Task) -> String {
+    const CELLS: usize = 5;
+
+    let percentage = match task.status {
+        TaskStatus::Completed => 100.0,
+        TaskStatus::InProgress | TaskStatus::Paused | TaskStatus::Blocked => {
+            task.progress_ratio() * 100.0
+        }
+        TaskStatus::Pending | TaskStatus::Skipped => 0.0,
+    };
+
+    let filled = bar_length(percentage, CELLS);
+    format!("{}{}", "▰".repeat(filled), "▱".repeat(CELLS - filled))
+}
+
+/// 작업 목록에서 하나를 고르는 퍼지 검색 선택기 (취소 시 None)
+pub fn pick_task(prompt: &str, tasks: &[&Task]) -> io::Result<Option<usize>> {
+    let items: Vec<String> = tasks.iter().map(|t| compact_line(t)).collect();
+
+    dialoguer::FuzzySelect::new()
+        .with_prompt(prompt)
+        .items(&items)
+        .default(0)
+        .interact_opt()
+        .map_err(|dialoguer::Error::IO(e)| e)
+}
+
+/// y/n 확인 프롬프트 (y/yes면 true)
+pub fn confirm(prompt: &str) -> io::Result<bool> {
+    print!("{} {} ", "?".yellow(), prompt);
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
 pub fn success(msg: &str) {
     println!("{} {}", "✓".green(), msg);
 }
@@ -76,3 +361,94 @@ pub fn error(msg: &str) {
 pub fn info(msg: &str) {
     println!("{} {}", "ℹ".blue(), msg);
 }
+
+/// 명령 결과가 아닌 부수 안내 (stdout 출력을 스크립트가 읽을 수 있도록 stderr로)
+pub fn notice(msg: &str) {
+    eprintln!("{} {}", "ℹ".blue(), msg);
+}
+
+pub fn warning(msg: &str) {
+    println!("{} {}", "⚠".yellow(), msg);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Local};
+
+    #[test]
+    fn test_format_duration_styles() {
+        assert_eq!(format_duration(200, DurationStyle::HoursMinutes), "3h 20m");
+        assert_eq!(format_duration(45, DurationStyle::HoursMinutes), "45m");
+        assert_eq!(format_duration(120, DurationStyle::HoursMinutes), "2h");
+        assert_eq!(format_duration(-75, DurationStyle::HoursMinutes), "-1h 15m");
+        assert_eq!(format_duration(200, DurationStyle::DecimalHours), "3.3h");
+        assert_eq!(format_duration(45, DurationStyle::DecimalHours), "0.8h");
+        assert_eq!(format_duration(-90, DurationStyle::DecimalHours), "-1.5h");
+    }
+
+    #[test]
+    fn test_format_percent_precision() {
+        assert_eq!(format_percent(91.6666, 0), "92%");
+        assert_eq!(format_percent(91.6666, 1), "91.7%");
+        assert_eq!(format_percent(91.6666, 2), "91.67%");
+    }
+
+    #[test]
+    fn test_truncate_title_korean() {
+        let title = "주간 회의 자료 준비하고 팀원들에게 공유하기";
+        // 한글은 한 글자가 2열
+        assert_eq!(truncate_title(title, 10), "주간 회의…");
+        assert_eq!(truncate_title(title, 100), title);
+        assert_eq!(truncate_title("가나다", 6), "가나다");
+        assert_eq!(truncate_title("가나다라", 6), "가나…");
+        assert_eq!(truncate_title("가나다라", 4), "가…");
+        assert!(truncate_title(title, 17).width() <= 17);
+        // 결합 문자(é = e + U+0301)는 한 글자로 취급
+        assert_eq!(truncate_title("cafe\u{301} au lait", 5), "cafe\u{301}…");
+    }
+
+    #[test]
+    fn test_sparkline_glyphs() {
+        let values: Vec<f64> = (0..8).map(|i| 60.0 + i as f64 * 5.0).collect();
+        assert_eq!(sparkline(&values), "▁▂▃▄▅▆▇█");
+        assert_eq!(sparkline(&[40.0, 100.0, 70.0]), "▁█▅");
+        assert_eq!(sparkline(&[80.0, 80.0]), "▅▅");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn test_bar_length_scales_with_width() {
+        assert_eq!(bar_length(50.0, 40), 20);
+        assert_eq!(bar_length(50.0, 20), 10);
+        assert_eq!(bar_length(100.0, 30), 30);
+        assert_eq!(bar_length(150.0, 30), 30); // 100% 초과는 잘라냄
+    }
+
+    #[test]
+    fn test_available_bar_width() {
+        assert_eq!(available_bar_width(120, 20, 40), 40); // 넓은 터미널: 최대값
+        assert_eq!(available_bar_width(50, 20, 40), 30); // 좁은 터미널: 남은 폭
+        assert_eq!(available_bar_width(25, 20, 40), MIN_BAR_LENGTH); // 최소값 보장
+    }
+
+    #[test]
+    fn test_render_compact_one_line_per_task() {
+        let mut schedule = Schedule::today();
+        let start = Local::now();
+        for i in 0..3 {
+            let mut task = Task::new(
+                format!("Task {}", i),
+                start + Duration::hours(i),
+                start + Duration::hours(i + 1),
+            );
+            task.notes = Some("notes\nspanning lines".to_string());
+            task.tags = vec!["work".to_string()];
+            schedule.add_task(task).unwrap();
+        }
+
+        let rendered = render_compact(&schedule);
+        assert_eq!(rendered.lines().count(), 3);
+        assert!(!rendered.contains("notes"));
+    }
+}
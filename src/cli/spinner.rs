@@ -0,0 +1,108 @@
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+const FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const FRAME_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 오래 걸리는 작업(AI 호출 등) 동안 stderr에 스피너와 경과 초를 표시
+///
+/// stderr가 TTY가 아니면 아무것도 출력하지 않습니다.
+pub struct Spinner {
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+    started: Instant,
+}
+
+impl Spinner {
+    /// 스피너 시작 (stderr가 TTY일 때만 표시)
+    pub fn start(message: &str) -> Self {
+        Self::start_with(message, std::io::stderr().is_terminal())
+    }
+
+    fn start_with(message: &str, enabled: bool) -> Self {
+        let running = Arc::new(AtomicBool::new(enabled));
+        let started = Instant::now();
+
+        let handle = enabled.then(|| {
+            let running = Arc::clone(&running);
+            let message = message.to_string();
+
+            thread::spawn(move || {
+                let mut frame = 0;
+                while running.load(Ordering::Relaxed) {
+                    eprint!(
+                        "\r{} {} ({}s)",
+                        FRAMES[frame % FRAMES.len()],
+                        message,
+                        started.elapsed().as_secs()
+                    );
+                    frame += 1;
+                    thread::sleep(FRAME_INTERVAL);
+                }
+                // 스피너 줄 지우기
+                eprint!("\r\x1b[2K");
+            })
+        });
+
+        Self {
+            running,
+            handle,
+            started,
+        }
+    }
+
+    /// 애니메이션이 돌고 있는지
+    pub fn is_running(&self) -> bool {
+        self.handle.is_some() && self.running.load(Ordering::Relaxed)
+    }
+
+    /// 스피너를 멈추고 줄을 지움. 경과 시간을 반환합니다.
+    pub fn stop(mut self) -> Duration {
+        self.finish();
+        self.started.elapsed()
+    }
+
+    fn finish(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for Spinner {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// `f`가 끝날 때까지 스피너를 표시
+pub fn with_spinner<T>(message: &str, f: impl FnOnce() -> T) -> T {
+    let spinner = Spinner::start(message);
+    let result = f();
+    spinner.stop();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spinner_starts_and_stops() {
+        let spinner = Spinner::start_with("Waiting", true);
+        assert!(spinner.is_running());
+
+        thread::sleep(Duration::from_millis(150));
+        let elapsed = spinner.stop();
+        assert!(elapsed >= Duration::from_millis(150));
+
+        // TTY가 아니면 스레드 없이 바로 끝남
+        let inert = Spinner::start_with("Waiting", false);
+        assert!(!inert.is_running());
+        inert.stop();
+    }
+}
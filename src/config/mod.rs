@@ -1,7 +1,21 @@
 use anyhow::Result;
+use chrono::{NaiveTime, Weekday};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::i18n::Locale;
+use crate::storage::StorageFormat;
+
+/// Whether `Config::load` may create a missing config file (off for `--dry-run`)
+static CREATE_MISSING: AtomicBool = AtomicBool::new(true);
+
+/// Stop `Config::load` from writing a default config file (called once for `--dry-run`)
+pub fn set_dry_run(dry_run: bool) {
+    CREATE_MISSING.store(!dry_run, Ordering::Relaxed);
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -13,6 +27,78 @@ pub struct Config {
     #[serde(default = "default_theme")]
     pub theme: Theme,
 
+    /// Template applied to a new weekday (Mon-Fri) with no schedule
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_weekday_template: Option<String>,
+
+    /// Template applied to a new weekend day (Sat-Sun) with no schedule
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_weekend_template: Option<String>,
+
+    /// Number of recent commits included in the Claude context
+    #[serde(default = "default_git_context_commits")]
+    pub git_context_commits: usize,
+
+    /// Include `git status --short` in the Claude context
+    #[serde(default = "default_true")]
+    pub git_context_include_status: bool,
+
+    /// Warn about task lengths that don't split into whole pomodoros
+    #[serde(default = "default_true")]
+    pub warn_uneven_estimates: bool,
+
+    /// Minimum gap between consecutive tasks in minutes (0 disables the check)
+    #[serde(default)]
+    pub min_break_minutes: u32,
+
+    /// Minutes a task may run past its estimate before it counts as overdue
+    #[serde(default)]
+    pub overdue_grace_minutes: u32,
+
+    /// Start the next pending task as soon as one is completed
+    #[serde(default)]
+    pub auto_start_next: bool,
+
+    /// AI provider used by the desktop app when none is given ("claude" or "copilot")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_provider: Option<String>,
+
+    /// Count past uncovered time within waking hours as wasted in reports
+    #[serde(default)]
+    pub count_idle_as_wasted: bool,
+
+    /// Days in the moving average drawn on the efficiency trend chart
+    #[serde(default = "default_trend_window_days")]
+    pub trend_window_days: usize,
+
+    /// Language of reports and change history ("en" or "ko")
+    #[serde(default)]
+    pub locale: Locale,
+
+    /// Show task status as symbols and words, not only colors
+    #[serde(default)]
+    pub accessible_symbols: bool,
+
+    /// Tags applied to `sched add` when none are given
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_tags: Vec<String>,
+
+    /// Refuse to add a task that ends up with no tags
+    #[serde(default)]
+    pub required_tags: bool,
+
+    /// File format for saved schedules ("json" or "messagepack")
+    #[serde(default)]
+    pub storage_format: StorageFormat,
+
+    /// Named data directories selectable with `--profile` (e.g. work, personal)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub profiles: HashMap<String, PathBuf>,
+
+    /// Part of the day you are normally awake
+    #[serde(default)]
+    pub waking_hours: WakingHours,
+
     /// Notification settings
     #[serde(default)]
     pub notifications: NotificationSettings,
@@ -20,6 +106,10 @@ pub struct Config {
     /// Daemon settings
     #[serde(default)]
     pub daemon: DaemonSettings,
+
+    /// Number formatting in reports
+    #[serde(default)]
+    pub report_precision: ReportPrecision,
 }
 
 fn default_time_block() -> u32 {
@@ -30,6 +120,14 @@ fn default_theme() -> Theme {
     Theme::Green
 }
 
+fn default_git_context_commits() -> usize {
+    5
+}
+
+fn default_trend_window_days() -> usize {
+    3
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Theme {
@@ -91,13 +189,92 @@ impl Default for DaemonSettings {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WakingHours {
+    #[serde(default = "default_wake")]
+    pub start: NaiveTime,
+
+    #[serde(default = "default_sleep")]
+    pub end: NaiveTime,
+}
+
+fn default_wake() -> NaiveTime {
+    NaiveTime::from_hms_opt(7, 0, 0).unwrap()
+}
+
+fn default_sleep() -> NaiveTime {
+    NaiveTime::from_hms_opt(23, 0, 0).unwrap()
+}
+
+impl Default for WakingHours {
+    fn default() -> Self {
+        Self {
+            start: default_wake(),
+            end: default_sleep(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportPrecision {
+    /// Decimal places for percentages
+    #[serde(default = "default_percent_decimals")]
+    pub percent_decimals: usize,
+
+    /// How durations are written
+    #[serde(default)]
+    pub duration_style: DurationStyle,
+}
+
+fn default_percent_decimals() -> usize {
+    1
+}
+
+impl Default for ReportPrecision {
+    fn default() -> Self {
+        Self {
+            percent_decimals: 1,
+            duration_style: DurationStyle::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DurationStyle {
+    /// "3h 20m"
+    #[default]
+    HoursMinutes,
+    /// "3.3h"
+    DecimalHours,
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
             default_time_block: 30,
             theme: Theme::Green,
+            default_weekday_template: None,
+            default_weekend_template: None,
+            git_context_commits: 5,
+            git_context_include_status: true,
+            warn_uneven_estimates: true,
+            min_break_minutes: 0,
+            overdue_grace_minutes: 0,
+            auto_start_next: false,
+            ai_provider: None,
+            count_idle_as_wasted: false,
+            trend_window_days: 3,
+            locale: Locale::default(),
+            accessible_symbols: false,
+            default_tags: Vec::new(),
+            required_tags: false,
+            storage_format: StorageFormat::default(),
+            profiles: HashMap::new(),
+            waking_hours: WakingHours::default(),
             notifications: NotificationSettings::default(),
             daemon: DaemonSettings::default(),
+            report_precision: ReportPrecision::default(),
         }
     }
 }
@@ -122,27 +299,90 @@ impl Config {
 
     /// Load config from file, or create default if not exists
     pub fn load() -> Result<Self> {
-        let path = Self::config_path()?;
+        Self::load_from(&Self::config_path()?)
+    }
+
+    /// Load config from the given file, or create default if not exists
+    pub fn load_from(path: &Path) -> Result<Self> {
+        Self::load_or_create(path, CREATE_MISSING.load(Ordering::Relaxed))
+    }
+
+    /// Load config from the given file; a missing file gives the default, saved only if `create`
+    fn load_or_create(path: &Path, create: bool) -> Result<Self> {
+        log::debug!("Loading config from {}", path.display());
 
         if path.exists() {
-            let contents = fs::read_to_string(&path)?;
+            let contents = fs::read_to_string(path)?;
             let config: Config = toml::from_str(&contents)?;
             Ok(config)
         } else {
             let config = Config::default();
-            config.save()?;
+            if create {
+                config.save_to(path)?;
+            } else {
+                eprintln!("[dry-run] Would write {}", path.display());
+            }
             Ok(config)
         }
     }
 
     /// Save config to file
     pub fn save(&self) -> Result<()> {
-        let path = Self::config_path()?;
+        self.save_to(&Self::config_path()?)
+    }
+
+    /// Save config to the given file
+    pub fn save_to(&self, path: &Path) -> Result<()> {
         let contents = toml::to_string_pretty(self)?;
-        fs::write(&path, contents)?;
+        fs::write(path, contents)?;
         Ok(())
     }
 
+    /// Default template name for the given weekday
+    pub fn template_for(&self, weekday: Weekday) -> Option<&str> {
+        match weekday {
+            Weekday::Sat | Weekday::Sun => self.default_weekend_template.as_deref(),
+            _ => self.default_weekday_template.as_deref(),
+        }
+    }
+
+    /// Data directory of a named profile
+    pub fn profile_dir(&self, name: &str) -> Result<&Path> {
+        self.profiles.get(name).map(PathBuf::as_path).ok_or_else(|| {
+            crate::error::SchedError::not_found(format!(
+                "Unknown profile '{}'. Add it under [profiles] in config.toml",
+                name
+            ))
+            .into()
+        })
+    }
+
+    /// Waking hours to count idle time in, if `count_idle_as_wasted` is on
+    pub fn idle_window(&self) -> Option<WakingHours> {
+        self.count_idle_as_wasted.then_some(self.waking_hours)
+    }
+
+    /// Fill in `default_tags` when `tags` is empty, then enforce `required_tags`
+    pub fn apply_tag_policy(&self, tags: &mut Vec<String>) -> Result<()> {
+        if tags.is_empty() {
+            tags.clone_from(&self.default_tags);
+        }
+        if self.required_tags && tags.is_empty() {
+            return Err(crate::error::SchedError::validation(
+                "Tags are required (required_tags = true). Add some with --tags",
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Profile names in alphabetical order
+    pub fn profile_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
     /// Get theme color
     pub fn theme_color(&self) -> ratatui::style::Color {
         use ratatui::style::Color;
@@ -166,6 +406,18 @@ mod tests {
         assert!(config.notifications.task_start_reminder);
     }
 
+    #[test]
+    fn test_template_for_weekday() {
+        let config = Config {
+            default_weekday_template: Some("work".to_string()),
+            default_weekend_template: Some("rest".to_string()),
+            ..Config::default()
+        };
+        assert_eq!(config.template_for(Weekday::Tue), Some("work"));
+        assert_eq!(config.template_for(Weekday::Sat), Some("rest"));
+        assert_eq!(Config::default().template_for(Weekday::Mon), None);
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = Config::default();
@@ -173,4 +425,76 @@ mod tests {
         let deserialized: Config = toml::from_str(&toml).unwrap();
         assert_eq!(deserialized.default_time_block, config.default_time_block);
     }
+
+    #[test]
+    fn test_report_precision_from_toml() {
+        let config: Config =
+            toml::from_str("[report_precision]\nduration_style = \"decimal_hours\"\n").unwrap();
+        assert_eq!(config.report_precision.duration_style, DurationStyle::DecimalHours);
+        assert_eq!(config.report_precision.percent_decimals, 1);
+    }
+
+    #[test]
+    fn test_idle_window_from_toml() {
+        assert_eq!(Config::default().idle_window(), None);
+
+        let config: Config =
+            toml::from_str("count_idle_as_wasted = true\n[waking_hours]\nstart = \"06:30\"\n").unwrap();
+        let window = config.idle_window().unwrap();
+        assert_eq!(window.start, NaiveTime::from_hms_opt(6, 30, 0).unwrap());
+        assert_eq!(window.end, NaiveTime::from_hms_opt(23, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_tag_policy() {
+        let mut tags = Vec::new();
+        Config::default().apply_tag_policy(&mut tags).unwrap();
+        assert!(tags.is_empty());
+
+        let required = Config { required_tags: true, ..Config::default() };
+        assert!(required.apply_tag_policy(&mut tags).is_err());
+
+        let config: Config = toml::from_str("required_tags = true\ndefault_tags = [\"work\"]\n").unwrap();
+        config.apply_tag_policy(&mut tags).unwrap();
+        assert_eq!(tags, vec!["work"]);
+
+        let mut given = vec!["home".to_string()];
+        config.apply_tag_policy(&mut given).unwrap();
+        assert_eq!(given, vec!["home"]);
+    }
+
+    #[test]
+    fn test_profiles_from_toml() {
+        let config: Config =
+            toml::from_str("[profiles]\nwork = \"/data/work\"\npersonal = \"/data/home\"\n").unwrap();
+        assert_eq!(config.profile_names(), vec!["personal", "work"]);
+        assert_eq!(config.profile_dir("work").unwrap(), Path::new("/data/work"));
+        assert!(config.profile_dir("gym").is_err());
+    }
+
+    #[test]
+    fn test_ai_provider_round_trip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("config.toml");
+
+        let config = Config {
+            ai_provider: Some("copilot".to_string()),
+            ..Config::default()
+        };
+        config.save_to(&path).unwrap();
+
+        let loaded = Config::load_from(&path).unwrap();
+        assert_eq!(loaded.ai_provider.as_deref(), Some("copilot"));
+    }
+
+    #[test]
+    fn test_missing_config_is_not_created_on_dry_run() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("config.toml");
+
+        Config::load_or_create(&path, false).unwrap();
+        assert!(!path.exists());
+        Config::load_or_create(&path, true).unwrap();
+        assert!(path.exists());
+    }
 }
@@ -4,6 +4,7 @@ use directories::ProjectDirs;
 
 pub struct DaemonProcess {
     pid_file: PathBuf,
+    dry_run: bool,
 }
 
 impl DaemonProcess {
@@ -16,7 +17,13 @@ impl DaemonProcess {
 
         let pid_file = data_dir.join("daemon.pid");
 
-        Ok(Self { pid_file })
+        Ok(Self { pid_file, dry_run: false })
+    }
+
+    /// dry-run이면 PID 파일을 쓰거나 지우지 않고 알리기만 함
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
     }
 
     pub fn is_running(&self) -> bool {
@@ -36,6 +43,10 @@ impl DaemonProcess {
     }
 
     pub fn write_pid(&self) -> anyhow::Result<()> {
+        if self.dry_run {
+            eprintln!("[dry-run] Would write {}", self.pid_file.display());
+            return Ok(());
+        }
         let pid = std::process::id();
         fs::write(&self.pid_file, pid.to_string())?;
         Ok(())
@@ -43,6 +54,10 @@ impl DaemonProcess {
 
     pub fn remove_pid(&self) -> anyhow::Result<()> {
         if self.pid_file.exists() {
+            if self.dry_run {
+                eprintln!("[dry-run] Would remove {}", self.pid_file.display());
+                return Ok(());
+            }
             fs::remove_file(&self.pid_file)?;
         }
         Ok(())
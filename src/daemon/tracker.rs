@@ -1,28 +1,51 @@
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
 use std::time::Duration;
-use chrono::Local;
-
-use crate::models::{TaskStatus, DailyStats};
-use crate::storage::{JsonStorage, Storage};
+use chrono::{DateTime, Local};
+
+use crate::config::Config;
+use crate::models::{Schedule, Task, TaskStatus, DailyStats};
+use crate::storage::Storage;
+
+/// 마지막 확인 후 이보다 오래 지났으면 데몬이 멈춰 있었던 것으로 간주 (초)
+const CATCH_UP_THRESHOLD_SECONDS: i64 = 120;
+
+/// `since` 이후 `now`까지 알림 시각(시작 `reminder_minutes`분 전)이 지난 대기 작업
+pub fn due_reminders(
+    schedule: &Schedule,
+    since: DateTime<Local>,
+    now: DateTime<Local>,
+    reminder_minutes: i64,
+) -> Vec<&Task> {
+    schedule
+        .tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Pending)
+        .filter(|t| {
+            let remind_at = t.start_time - chrono::Duration::minutes(reminder_minutes);
+            remind_at > since && remind_at <= now
+        })
+        .collect()
+}
 
 pub struct TimeTracker {
-    storage: JsonStorage,
-    running: bool,
+    storage: Box<dyn Storage>,
+    running: AtomicBool,
 }
 
 impl TimeTracker {
-    pub fn new(storage: JsonStorage) -> Self {
+    pub fn new(storage: Box<dyn Storage>) -> Self {
         Self {
             storage,
-            running: false,
+            running: AtomicBool::new(false),
         }
     }
 
     pub fn start(&mut self) {
-        self.running = true;
+        self.running.store(true, Ordering::SeqCst);
         log::info!("Time tracker started");
 
-        while self.running {
+        while self.running.load(Ordering::SeqCst) {
             if let Err(e) = self.update() {
                 log::error!("Tracker update error: {}", e);
             }
@@ -32,16 +55,22 @@ impl TimeTracker {
     }
 
     pub fn stop(&mut self) {
-        self.running = false;
+        self.running.store(false, Ordering::SeqCst);
         log::info!("Time tracker stopped");
     }
 
     fn update(&self) -> anyhow::Result<()> {
+        let now = Local::now();
+        let last_tick = self.storage.load_last_tick()?.unwrap_or(now);
+        self.storage.save_last_tick(now)?;
+
         let schedule = match self.storage.load_today()? {
             Some(s) => s,
             None => return Ok(()), // 스케줄 없으면 스킵
         };
 
+        self.notify_reminders(&schedule, last_tick, now);
+
         // 현재 진행 중인 작업이 있는지 확인
         if let Some(current) = schedule.get_current_task() {
             log::debug!("Current task: {} - elapsed: {:?}min", 
@@ -50,42 +79,81 @@ impl TimeTracker {
             );
 
             // 시간 초과 경고
-            if current.is_overdue() {
+            let grace = Config::load().unwrap_or_default().overdue_grace_minutes;
+            if current.is_overdue_with_grace(grace) {
                 log::warn!("Task '{}' is overdue!", current.title);
                 // TODO: 알림 보내기
             }
         }
 
+        for warning in schedule.deadline_warnings(Local::now()) {
+            log::warn!("{}", warning.message());
+        }
+
         // 통계 업데이트
         self.update_stats(&schedule)?;
 
         Ok(())
     }
 
+    /// 작업 시작 알림 (데몬이 멈춰 있던 동안 놓친 알림은 한 번에 모아서)
+    fn notify_reminders(&self, schedule: &Schedule, last_tick: DateTime<Local>, now: DateTime<Local>) {
+        let settings = Config::load().unwrap_or_default().notifications;
+        if !settings.task_start_reminder {
+            return;
+        }
+
+        let due = due_reminders(schedule, last_tick, now, settings.reminder_minutes as i64);
+        if due.is_empty() {
+            return;
+        }
+
+        let titles: Vec<&str> = due.iter().map(|t| t.title.as_str()).collect();
+        if (now - last_tick).num_seconds() > CATCH_UP_THRESHOLD_SECONDS {
+            log::warn!(
+                "Missed {} reminder(s) while the daemon was not running: {}",
+                due.len(),
+                titles.join(", ")
+            );
+        } else {
+            for task in due {
+                log::info!("'{}' starts at {}", task.title, task.start_time.format("%H:%M"));
+            }
+        }
+    }
+
     fn update_stats(&self, schedule: &crate::models::Schedule) -> anyhow::Result<()> {
         let mut stats = self.storage
             .load_stats(Local::now())?
             .unwrap_or_else(|| DailyStats::new(Local::now()));
 
-        stats.completion_rate = schedule.completion_rate();
-        stats.time_accuracy = schedule.time_accuracy();
-        stats.total_tasks = schedule.tasks.len();
-        stats.completed_tasks = schedule
-            .tasks
-            .iter()
-            .filter(|t| t.status == TaskStatus::Completed)
-            .count();
-
-        // 집중 시간 계산 (완료된 작업의 실제 시간 합)
-        stats.focus_time_minutes = schedule
-            .tasks
-            .iter()
-            .filter(|t| t.status == TaskStatus::Completed)
-            .filter_map(|t| t.actual_duration_minutes)
-            .sum();
-
+        stats.update_from(schedule);
         self.storage.save_stats(&stats)?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{at, day_schedule};
+
+    #[test]
+    fn test_due_reminders_catch_up_after_downtime() {
+        let mut schedule = day_schedule();
+        schedule.add_task(Task::new("Standup".to_string(), at(4, 9, 0), at(4, 9, 15))).unwrap();
+        schedule.add_task(Task::new("Lunch".to_string(), at(4, 12, 0), at(4, 13, 0))).unwrap();
+        let mut done = Task::new("Email".to_string(), at(4, 9, 30), at(4, 10, 0));
+        done.status = TaskStatus::Completed;
+        schedule.add_task(done).unwrap();
+
+        // 08:00에 멈췄다가 10:00에 다시 실행됨
+        let due = due_reminders(&schedule, at(4, 8, 0), at(4, 10, 0), 5);
+        let titles: Vec<&str> = due.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Standup"]);
+
+        // 이미 확인한 구간은 다시 알리지 않음
+        assert!(due_reminders(&schedule, at(4, 10, 0), at(4, 10, 1), 5).is_empty());
+    }
+}
@@ -0,0 +1,83 @@
+use thiserror::Error;
+
+use crate::ai::AiError;
+
+/// CLI 에러 분류 (종료 코드로 매핑)
+///
+/// | 코드 | 의미 |
+/// |------|------|
+/// | 1 | 기타 에러 |
+/// | 2 | 스케줄/작업/템플릿을 찾을 수 없음 |
+/// | 3 | 잘못된 입력 (시간 형식, 충돌, 범위 등) |
+/// | 4 | 저장소 읽기/쓰기 실패 |
+/// | 5 | AI 연동 실패 |
+#[derive(Debug, Error)]
+pub enum SchedError {
+    #[error("{0}")]
+    NotFound(String),
+
+    #[error("{0}")]
+    Validation(String),
+
+    #[error("{0}")]
+    Storage(String),
+
+    #[error("{0}")]
+    Ai(String),
+}
+
+impl SchedError {
+    pub fn not_found(msg: impl Into<String>) -> Self {
+        Self::NotFound(msg.into())
+    }
+
+    pub fn validation(msg: impl Into<String>) -> Self {
+        Self::Validation(msg.into())
+    }
+
+    /// 프로세스 종료 코드
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::NotFound(_) => 2,
+            Self::Validation(_) => 3,
+            Self::Storage(_) => 4,
+            Self::Ai(_) => 5,
+        }
+    }
+}
+
+/// 에러에 해당하는 종료 코드 (분류되지 않은 I/O·직렬화 에러는 저장소 에러로 취급)
+pub fn exit_code(err: &anyhow::Error) -> i32 {
+    if let Some(sched) = err.downcast_ref::<SchedError>() {
+        return sched.exit_code();
+    }
+    if err.is::<AiError>() {
+        return 5;
+    }
+
+    let is_storage = err.chain().any(|cause| {
+        cause.is::<std::io::Error>() || cause.is::<serde_json::Error>() || cause.is::<toml::de::Error>()
+    });
+
+    if is_storage {
+        4
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        assert_eq!(exit_code(&SchedError::not_found("No schedule found").into()), 2);
+        assert_eq!(exit_code(&SchedError::validation("bad time").into()), 3);
+        assert_eq!(exit_code(&AiError::Timeout("slow".to_string()).into()), 5);
+
+        let io = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
+        assert_eq!(exit_code(&anyhow::Error::new(io)), 4);
+        assert_eq!(exit_code(&anyhow::anyhow!("something else")), 1);
+    }
+}
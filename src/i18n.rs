@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+/// 출력 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    /// 영어 (기본)
+    #[default]
+    En,
+    /// 한국어
+    Ko,
+}
+
+/// (메시지 ID, 영어, 한국어) - `{name}` 자리에 인자가 들어감
+const MESSAGES: &[(&str, &str, &str)] = &[
+    ("change.task_created", "Added \"{title}\" ({time})", "\"{title}\" 추가 ({time})"),
+    ("change.task_moved", "Moved \"{title}\" from {old} to {new}", "\"{title}\"를 {old}에서 {new}로 이동"),
+    ("change.task_updated", "Changed \"{title}\" time: {old} → {new}", "\"{title}\" 시간 변경: {old} → {new}"),
+    ("change.tasks_merged", "Merged into \"{title}\": {old} → {new}", "\"{title}\"로 합치기: {old} → {new}"),
+    (
+        "change.schedule_rebalanced",
+        "Spread {count} tasks evenly within {window}",
+        "{window} 안에 {count}개 작업 고르게 배치",
+    ),
+    (
+        "change.schedule_delayed",
+        "Pushed {count} tasks after \"{title}\" back by {minutes} min",
+        "\"{title}\" 이후 {count}개 작업 {minutes}분 미루기 (영향: {count}개 작업)",
+    ),
+    (
+        "change.schedule_advanced",
+        "Pulled {count} tasks after \"{title}\" forward by {minutes} min",
+        "\"{title}\" 이후 {count}개 작업 {minutes}분 당기기 (영향: {count}개 작업)",
+    ),
+    ("report.daily_title", "Daily Time Accountability Report", "일일 시간 책임 리포트"),
+    ("report.weekly_title", "Weekly Time Accountability Report", "주간 시간 책임 리포트"),
+    ("report.date", "Date", "날짜"),
+    ("report.total_planned", "Total Planned", "계획한 시간"),
+    ("report.total_earned", "Total Earned", "얻은 시간"),
+    ("report.total_bonus", "Total Bonus", "보너스"),
+    ("report.total_penalty", "Total Penalty", "페널티"),
+    ("report.total_wasted", "Total Wasted", "낭비한 시간"),
+    ("report.earned", "Earned", "얻은 시간"),
+    ("report.bonus", "Bonus", "보너스"),
+    ("report.penalty", "Penalty", "페널티"),
+    ("report.wasted", "Wasted", "낭비"),
+    ("report.idle", "incl. {time} idle between tasks", "작업 사이 빈 시간 {time} 포함"),
+    ("report.net_earned", "Net Earned", "순수 얻은 시간"),
+    ("report.efficiency", "Efficiency", "효율"),
+    ("report.efficiency_score", "Efficiency Score", "효율 점수"),
+    ("report.overall_efficiency", "Overall Efficiency", "전체 효율"),
+    ("report.avg_start_delay", "Avg Start Delay", "평균 시작 지연"),
+    ("report.task_breakdown", "Task Breakdown", "작업별 결과"),
+    ("report.week_summary", "Week Summary", "주간 요약"),
+    ("report.task_durations", "Task Durations", "작업 길이 분포"),
+    ("report.interruptions", "Interruptions", "방해"),
+    ("report.interruptions_total", "{count} total", "총 {count}회"),
+];
+
+static LOCALE: AtomicU8 = AtomicU8::new(0);
+
+/// 이후 출력에 쓸 언어 설정 (config.toml의 `locale`)
+pub fn set_locale(locale: Locale) {
+    LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+/// 현재 출력 언어
+pub fn locale() -> Locale {
+    match LOCALE.load(Ordering::Relaxed) {
+        1 => Locale::Ko,
+        _ => Locale::En,
+    }
+}
+
+fn catalog() -> &'static HashMap<&'static str, (&'static str, &'static str)> {
+    static CATALOG: OnceLock<HashMap<&'static str, (&'static str, &'static str)>> = OnceLock::new();
+    CATALOG.get_or_init(|| MESSAGES.iter().map(|(id, en, ko)| (*id, (*en, *ko))).collect())
+}
+
+/// `locale`로 된 메시지 (없는 ID면 ID 그대로)
+pub fn message(locale: Locale, id: &'static str) -> &'static str {
+    match (catalog().get(id), locale) {
+        (Some((en, _)), Locale::En) => en,
+        (Some((_, ko)), Locale::Ko) => ko,
+        (None, _) => id,
+    }
+}
+
+/// 현재 언어로 된 메시지
+pub fn tr(id: &'static str) -> &'static str {
+    message(locale(), id)
+}
+
+/// 현재 언어로 된 메시지에 `{name}` 인자 채우기
+pub fn tr_with(id: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    format_message(tr(id), args)
+}
+
+/// 메시지의 `{name}` 자리를 인자로 바꿈
+///
+/// 템플릿을 한 번만 훑으므로 인자 값 안의 `{name}`은 다시 바뀌지 않습니다.
+/// 모르는 자리는 그대로 둡니다.
+pub fn format_message(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let value = after
+            .find('}')
+            .and_then(|close| args.iter().find(|(name, _)| *name == &after[..close]).map(|(_, v)| (close, v)));
+        match value {
+            Some((close, value)) => {
+                out.push_str(&value.to_string());
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_depends_on_locale() {
+        assert_eq!(message(Locale::En, "report.total_planned"), "Total Planned");
+        assert_eq!(message(Locale::Ko, "report.total_planned"), "계획한 시간");
+        assert_eq!(message(Locale::Ko, "no.such.message"), "no.such.message");
+
+        let args: [(&str, &dyn Display); 2] = [("title", &"Gym"), ("time", &"07:00-08:00")];
+        assert_eq!(
+            format_message(message(Locale::En, "change.task_created"), &args),
+            "Added \"Gym\" (07:00-08:00)"
+        );
+        assert_eq!(
+            format_message(message(Locale::Ko, "change.task_created"), &args),
+            "\"Gym\" 추가 (07:00-08:00)"
+        );
+    }
+
+    #[test]
+    fn test_format_message_does_not_expand_placeholders_in_values() {
+        let args: [(&str, &dyn Display); 2] = [("title", &"Plan {time}"), ("time", &"07:00-08:00")];
+        assert_eq!(format_message("{title} ({time}) {unknown}", &args), "Plan {time} (07:00-08:00) {unknown}");
+        assert_eq!(format_message("{ {title", &args), "{ {title");
+    }
+
+    #[test]
+    fn test_every_message_id_is_unique() {
+        assert_eq!(catalog().len(), MESSAGES.len());
+    }
+}
@@ -1,11 +1,24 @@
+pub mod ai;
 pub mod claude;
 pub mod cli;
 pub mod config;
 pub mod daemon;
+pub mod error;
+pub mod i18n;
 pub mod models;
+pub mod server;
 pub mod storage;
+pub mod time;
 pub mod tui;
 
+#[cfg(test)]
+pub(crate) mod test_support;
+
 pub use config::Config;
-pub use models::{ChangeType, DailyStats, Schedule, ScheduleChange, StreakInfo, Task, TaskStatus};
-pub use storage::{JsonStorage, Storage};
+pub use error::SchedError;
+pub use models::{
+    ChangeType, ConflictPolicy, DailyStats, EventType, Schedule, ScheduleChange, ScheduleEvent,
+    StreakInfo, Task, TaskConflict, TaskStatus,
+};
+pub use storage::{EncryptedStorage, JsonStorage, Storage};
+pub use time::resolve_local;
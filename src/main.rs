@@ -1,11 +1,18 @@
 use clap::Parser;
 use scheduler::cli::{execute_command, Cli};
+use scheduler::error::exit_code;
 
 fn main() {
     let cli = Cli::parse();
 
-    if let Err(e) = execute_command(cli.command) {
+    // RUST_LOG가 있으면 플래그보다 우선
+    env_logger::Builder::new()
+        .filter_level(cli.log_level())
+        .parse_default_env()
+        .init();
+
+    if let Err(e) = execute_command(cli.command, cli.profile.as_deref(), cli.dry_run, cli.verbose > 0) {
         eprintln!("Error: {}", e);
-        std::process::exit(1);
+        std::process::exit(exit_code(&e));
     }
 }
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, Timelike};
 use serde::{Deserialize, Serialize};
 
 use super::{Task, TaskStatus};
@@ -23,7 +23,13 @@ impl TimeAccountability {
 
         match task.status {
             TaskStatus::Completed => {
-                if let Some(actual) = task.actual_duration_minutes {
+                // 음수나 터무니없는 실제 시간은 기록이 없는 것으로 처리
+                let actual = task.plausible_actual_minutes();
+                if actual.is_none() && task.recorded_duration_minutes().is_some() {
+                    log::warn!("Ignoring implausible actual duration for '{}'", task.title);
+                }
+
+                if let Some(actual) = actual {
                     if actual <= estimated {
                         // 시간 내 완료 또는 빨리 완료
                         let bonus = estimated - actual;
@@ -37,7 +43,7 @@ impl TimeAccountability {
                         // 예상보다 늦게 완료
                         let penalty = actual - estimated;
                         Self {
-                            earned_time: estimated.saturating_sub(penalty),
+                            earned_time: (estimated - penalty).max(0),
                             wasted_time: 0,
                             bonus_time: 0,
                             penalty_time: penalty,
@@ -62,7 +68,10 @@ impl TimeAccountability {
                     penalty_time: 0,
                 }
             }
-            TaskStatus::Pending | TaskStatus::InProgress | TaskStatus::Paused => {
+            TaskStatus::Pending
+            | TaskStatus::InProgress
+            | TaskStatus::Paused
+            | TaskStatus::Blocked => {
                 // 미완료 - 낭비로 간주하지 않음 (아직 진행 가능)
                 Self {
                     earned_time: 0,
@@ -95,6 +104,67 @@ impl TimeAccountability {
     }
 }
 
+/// 하루 중 시간대 구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DaySegment {
+    /// 오전 (05:00 ~ 12:00)
+    Morning,
+    /// 오후 (12:00 ~ 17:00)
+    Afternoon,
+    /// 저녁 (17:00 ~ 22:00)
+    Evening,
+    /// 밤 (22:00 ~ 05:00)
+    Night,
+}
+
+impl DaySegment {
+    /// 하루 순서대로 나열한 모든 시간대
+    pub const ALL: [DaySegment; 4] = [
+        DaySegment::Morning,
+        DaySegment::Afternoon,
+        DaySegment::Evening,
+        DaySegment::Night,
+    ];
+
+    /// 시각(0~23시)이 속한 시간대
+    pub fn from_hour(hour: u32) -> Self {
+        match hour {
+            5..=11 => Self::Morning,
+            12..=16 => Self::Afternoon,
+            17..=21 => Self::Evening,
+            _ => Self::Night,
+        }
+    }
+
+    /// 표시용 이름
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Morning => "morning",
+            Self::Afternoon => "afternoon",
+            Self::Evening => "evening",
+            Self::Night => "night",
+        }
+    }
+}
+
+impl TimeAccountability {
+    fn zero() -> Self {
+        Self {
+            earned_time: 0,
+            wasted_time: 0,
+            bonus_time: 0,
+            penalty_time: 0,
+        }
+    }
+
+    fn add(&mut self, other: &TimeAccountability) {
+        self.earned_time += other.earned_time;
+        self.wasted_time += other.wasted_time;
+        self.bonus_time += other.bonus_time;
+        self.penalty_time += other.penalty_time;
+    }
+}
+
 /// 일일 시간 책임성 통계
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DailyAccountability {
@@ -109,6 +179,9 @@ pub struct DailyAccountability {
     pub total_bonus: i64,
     /// 총 페널티 시간 (분)
     pub total_penalty: i64,
+    /// 낭비 시간에 포함된, 작업 없이 흘려보낸 시간 (분)
+    #[serde(default)]
+    pub total_idle: i64,
 }
 
 impl DailyAccountability {
@@ -120,9 +193,17 @@ impl DailyAccountability {
             total_wasted: 0,
             total_bonus: 0,
             total_penalty: 0,
+            total_idle: 0,
         }
     }
 
+    /// 빈 시간을 계획했지만 쓰지 못한 시간으로 반영 (계획·낭비 모두 증가)
+    pub fn add_idle(&mut self, minutes: i64) {
+        self.total_idle += minutes;
+        self.total_planned += minutes;
+        self.total_wasted += minutes;
+    }
+
     /// Task 목록으로부터 일일 통계 계산
     pub fn from_tasks(date: DateTime<Local>, tasks: &[Task]) -> Self {
         let mut accountability = Self::new(date);
@@ -140,6 +221,23 @@ impl DailyAccountability {
         accountability
     }
 
+    /// 작업 시작 시각 기준 시간대별 성과 (오전 → 밤 순)
+    pub fn by_segment(tasks: &[Task]) -> Vec<(DaySegment, TimeAccountability)> {
+        let mut segments: Vec<_> = DaySegment::ALL
+            .iter()
+            .map(|segment| (*segment, TimeAccountability::zero()))
+            .collect();
+
+        for task in tasks {
+            let segment = DaySegment::from_hour(task.start_time.hour());
+            if let Some((_, total)) = segments.iter_mut().find(|(s, _)| *s == segment) {
+                total.add(&TimeAccountability::from_task(task));
+            }
+        }
+
+        segments
+    }
+
     /// 시간 효율 점수 (0-100%)
     pub fn efficiency_score(&self) -> f64 {
         if self.total_planned == 0 {
@@ -177,6 +275,7 @@ impl DailyAccountability {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::at;
     use chrono::TimeZone;
 
     #[test]
@@ -185,9 +284,6 @@ mod tests {
             "Test".to_string(),
             Local.with_ymd_and_hms(2025, 11, 1, 9, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2025, 11, 1, 10, 0, 0).unwrap(),
-            60,
-            vec![],
-            None,
         );
         task.status = TaskStatus::Completed;
         task.actual_duration_minutes = Some(60);
@@ -199,15 +295,30 @@ mod tests {
         assert_eq!(perf.wasted_time, 0);
     }
 
+    #[test]
+    fn test_end_before_start_earns_nothing_negative() {
+        let mut task = Task::new("Test".to_string(), at(1, 9, 0), at(1, 10, 0));
+        task.status = TaskStatus::Completed;
+        task.actual_start_time = Some(at(1, 11, 0));
+        task.actual_end_time = Some(at(1, 9, 0));
+        task.actual_duration_minutes = Some(-120);
+
+        let perf = TimeAccountability::from_task(&task);
+        assert_eq!(perf.earned_time, 60);
+        assert_eq!(perf.bonus_time, 0);
+        assert_eq!(perf.penalty_time, 0);
+
+        let daily = DailyAccountability::from_tasks(at(1, 0, 0), &[task]);
+        assert!(daily.net_earned() >= 0);
+        assert_eq!(daily.efficiency_score(), 100.0);
+    }
+
     #[test]
     fn test_early_completion() {
         let mut task = Task::new(
             "Test".to_string(),
             Local.with_ymd_and_hms(2025, 11, 1, 9, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2025, 11, 1, 10, 0, 0).unwrap(),
-            60,
-            vec![],
-            None,
         );
         task.status = TaskStatus::Completed;
         task.actual_duration_minutes = Some(45);
@@ -224,9 +335,6 @@ mod tests {
             "Test".to_string(),
             Local.with_ymd_and_hms(2025, 11, 1, 9, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2025, 11, 1, 10, 0, 0).unwrap(),
-            60,
-            vec![],
-            None,
         );
         task.status = TaskStatus::Completed;
         task.actual_duration_minutes = Some(75);
@@ -242,9 +350,6 @@ mod tests {
             "Test".to_string(),
             Local.with_ymd_and_hms(2025, 11, 1, 9, 0, 0).unwrap(),
             Local.with_ymd_and_hms(2025, 11, 1, 10, 0, 0).unwrap(),
-            60,
-            vec![],
-            None,
         );
         task.status = TaskStatus::Skipped;
 
@@ -253,6 +358,20 @@ mod tests {
         assert_eq!(perf.earned_time, 0);
     }
 
+    #[test]
+    fn test_blocked_task_not_wasted() {
+        let mut task = Task::new(
+            "Test".to_string(),
+            Local.with_ymd_and_hms(2025, 11, 1, 9, 0, 0).unwrap(),
+            Local.with_ymd_and_hms(2025, 11, 1, 10, 0, 0).unwrap(),
+        );
+        task.block(Some("waiting on review".to_string()));
+
+        let perf = TimeAccountability::from_task(&task);
+        assert_eq!(perf.wasted_time, 0);
+        assert_eq!(perf.earned_time, 0);
+    }
+
     #[test]
     fn test_efficiency_score() {
         let date = Local.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap();
@@ -266,4 +385,20 @@ mod tests {
         assert!((daily.efficiency_score() - 91.67).abs() < 0.1);
         assert_eq!(daily.grade(), "A");
     }
+
+    #[test]
+    fn test_by_segment() {
+        let mut morning = Task::new("Run".to_string(), at(1, 8, 0), at(1, 9, 0));
+        morning.status = TaskStatus::Completed;
+        let mut evening = Task::new("Read".to_string(), at(1, 20, 0), at(1, 21, 0));
+        evening.status = TaskStatus::Skipped;
+
+        let segments = DailyAccountability::by_segment(&[morning, evening]);
+        let find = |segment| segments.iter().find(|(s, _)| *s == segment).unwrap().1.clone();
+
+        assert_eq!(find(DaySegment::Morning).earned_time, 60);
+        assert_eq!(find(DaySegment::Evening).earned_time, 0);
+        assert_eq!(find(DaySegment::Evening).wasted_time, 60);
+        assert_eq!(find(DaySegment::Afternoon).earned_time, 0);
+    }
 }
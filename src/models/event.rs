@@ -0,0 +1,48 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+/// 이벤트 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventType {
+    /// 작업 추가
+    TaskAdded,
+    /// 작업 시작
+    TaskStarted,
+    /// 작업 일시정지
+    TaskPaused,
+    /// 작업 완료
+    TaskCompleted,
+    /// 작업 삭제
+    TaskDeleted,
+    /// 작업 막힘
+    TaskBlocked,
+    /// 작업 막힘 해제
+    TaskUnblocked,
+    /// 작업 건너뜀
+    TaskSkipped,
+}
+
+/// 이벤트 로그 항목 (events.log에 한 줄씩 기록)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleEvent {
+    /// 발생 시각
+    pub timestamp: DateTime<Local>,
+    /// 이벤트 타입
+    pub event_type: EventType,
+    /// 대상 작업 ID
+    pub task_id: String,
+    /// 대상 작업 제목
+    pub task_title: String,
+}
+
+impl ScheduleEvent {
+    /// 현재 시각으로 이벤트 생성
+    pub fn new(event_type: EventType, task_id: &str, task_title: &str) -> Self {
+        Self {
+            timestamp: Local::now(),
+            event_type,
+            task_id: task_id.to_string(),
+            task_title: task_title.to_string(),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use super::{Schedule, TaskStatus};
+
+/// 주간 목표 하나
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeeklyGoal {
+    /// 목표 설명
+    pub description: String,
+
+    /// 목표 시간 (분), 시간 목표가 아니면 None
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_minutes: Option<i64>,
+
+    /// 완료 시간을 셀 작업 태그
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// 직접 완료 표시했는지
+    #[serde(default)]
+    pub done: bool,
+}
+
+impl WeeklyGoal {
+    /// 새 목표 생성
+    pub fn new(description: String, target_minutes: Option<i64>, tag: Option<String>) -> Self {
+        Self {
+            description,
+            target_minutes,
+            tag,
+            done: false,
+        }
+    }
+
+    /// 태그가 붙은 완료 작업의 시간 합계 (분), 태그가 없으면 None
+    pub fn completed_minutes(&self, schedules: &[Schedule]) -> Option<i64> {
+        let tag = self.tag.as_deref()?;
+
+        Some(
+            schedules
+                .iter()
+                .flat_map(|s| &s.tasks)
+                .filter(|t| t.status == TaskStatus::Completed)
+                .filter(|t| t.tags.iter().any(|task_tag| task_tag == tag))
+                .map(|t| t.plausible_actual_minutes().unwrap_or(t.estimated_duration_minutes))
+                .sum(),
+        )
+    }
+
+    /// 목표 시간 대비 진행률 (0.0~), 시간 목표가 아니면 None
+    pub fn progress_ratio(&self, schedules: &[Schedule]) -> Option<f64> {
+        let target = self.target_minutes.filter(|t| *t > 0)?;
+        let completed = self.completed_minutes(schedules)?;
+        Some(completed as f64 / target as f64)
+    }
+
+    /// 달성했는지 (완료 표시 또는 목표 시간 도달)
+    pub fn is_met(&self, schedules: &[Schedule]) -> bool {
+        self.done || self.progress_ratio(schedules).is_some_and(|ratio| ratio >= 1.0)
+    }
+}
+
+/// ISO 주 단위로 저장되는 주간 목표 목록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeeklyGoals {
+    /// ISO 주 ("2025-W45")
+    pub week: String,
+
+    /// 목표 목록
+    #[serde(default)]
+    pub goals: Vec<WeeklyGoal>,
+}
+
+impl WeeklyGoals {
+    /// `date`가 속한 주의 빈 목표 목록
+    pub fn for_date(date: NaiveDate) -> Self {
+        Self {
+            week: Self::week_key(date),
+            goals: Vec::new(),
+        }
+    }
+
+    /// `date`가 속한 ISO 주 키 ("2025-W45")
+    pub fn week_key(date: NaiveDate) -> String {
+        let week = date.iso_week();
+        format!("{}-W{:02}", week.year(), week.week())
+    }
+
+    /// `date`가 속한 주의 월요일~일요일
+    pub fn week_range(date: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let week = date.iso_week();
+        let monday = NaiveDate::from_isoywd_opt(week.year(), week.week(), Weekday::Mon).unwrap();
+        (monday, monday + Duration::days(6))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{at, day_schedule};
+    use crate::models::Task;
+
+    #[test]
+    fn test_tag_goal_sums_completed_minutes() {
+
+        let mut monday = Schedule::new(at(3, 0, 0));
+        let mut deep = Task::new("Design".to_string(), at(3, 9, 0), at(3, 11, 0));
+        deep.tags = vec!["deep".to_string()];
+        deep.status = TaskStatus::Completed;
+        deep.actual_duration_minutes = Some(90);
+        monday.add_task(deep).unwrap();
+
+        let mut unfinished = Task::new("Refactor".to_string(), at(3, 13, 0), at(3, 15, 0));
+        unfinished.tags = vec!["deep".to_string()];
+        monday.add_task(unfinished).unwrap();
+
+        let mut tuesday = day_schedule();
+        let mut deep = Task::new("Write".to_string(), at(4, 9, 0), at(4, 10, 0));
+        deep.tags = vec!["deep".to_string()];
+        deep.status = TaskStatus::Completed;
+        tuesday.add_task(deep).unwrap();
+
+        let mut email = Task::new("Email".to_string(), at(4, 10, 0), at(4, 11, 0));
+        email.status = TaskStatus::Completed;
+        tuesday.add_task(email).unwrap();
+
+        let schedules = [monday, tuesday];
+        let goal = WeeklyGoal::new("Deep work".to_string(), Some(300), Some("deep".to_string()));
+        assert_eq!(goal.completed_minutes(&schedules), Some(150));
+        assert_eq!(goal.progress_ratio(&schedules), Some(0.5));
+        assert!(!goal.is_met(&schedules));
+
+        let plain = WeeklyGoal::new("Finish report".to_string(), None, None);
+        assert_eq!(plain.completed_minutes(&schedules), None);
+    }
+
+    #[test]
+    fn test_week_key_and_range() {
+        let date = NaiveDate::from_ymd_opt(2025, 11, 5).unwrap();
+        assert_eq!(WeeklyGoals::week_key(date), "2025-W45");
+        assert_eq!(
+            WeeklyGoals::week_range(date),
+            (
+                NaiveDate::from_ymd_opt(2025, 11, 3).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 11, 9).unwrap()
+            )
+        );
+    }
+}
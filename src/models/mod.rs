@@ -1,11 +1,22 @@
 pub mod accountability;
+pub mod event;
+pub mod goal;
 pub mod pomodoro;
 pub mod schedule;
 pub mod stats;
 pub mod task;
+pub mod template;
 
-pub use accountability::{DailyAccountability, TimeAccountability};
+pub use accountability::{DailyAccountability, DaySegment, TimeAccountability};
+pub use event::{EventType, ScheduleEvent};
+pub use goal::{WeeklyGoal, WeeklyGoals};
 pub use pomodoro::PomodoroSession;
-pub use schedule::{ChangeType, Schedule, ScheduleChange};
-pub use stats::{DailyStats, StreakInfo};
-pub use task::{Task, TaskStatus};
+pub use schedule::{
+    average_start_delay, busiest_hour, habit_candidates, BreakWarning, ChangeType, ConflictPolicy, DeadlineIssue, DeadlineWarning, EstimateIssue, EstimateWarning, Schedule,
+    HabitCandidate, ScheduleChange, ScheduleWarning, SimChange, TaskConflict, CLEAN_POMODORO_LENGTHS, HABIT_MIN_DAYS,
+};
+pub use stats::{linear_trend, moving_average, DailyStats, StreakInfo, STREAK_THRESHOLD};
+pub use task::{
+    Interruption, Priority, Recurrence, Task, TaskSnapshot, TaskStatus, WorkSession, DEADLINE_WARNING_MINUTES, FOCUS_SCORE_RANGE,
+};
+pub use template::{ScheduleTemplate, TemplateTask};
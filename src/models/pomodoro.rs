@@ -1,6 +1,9 @@
 use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 
+/// 긴 휴식 주기 기본값 (4번째 Pomodoro마다)
+pub const DEFAULT_LONG_BREAK_EVERY: u32 = 4;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PomodoroSession {
     /// 총 Pomodoro 수
@@ -12,6 +15,10 @@ pub struct PomodoroSession {
     /// 현재 Pomodoro 시작 시간
     #[serde(skip_serializing_if = "Option::is_none")]
     pub current_start: Option<DateTime<Local>>,
+
+    /// 일시정지 전까지 누적된 현재 Pomodoro 경과 시간 (초)
+    #[serde(default)]
+    pub accumulated_seconds: i64,
     
     /// Pomodoro 길이 (분, 기본 25분)
     #[serde(default = "default_pomodoro_duration")]
@@ -21,14 +28,19 @@ pub struct PomodoroSession {
     #[serde(default = "default_short_break")]
     pub short_break: u32,
     
-    /// 긴 휴식 (분, 기본 15분, 4 pomodoro 후)
+    /// 긴 휴식 (분, 기본 15분, `long_break_every`번째 pomodoro 후)
     #[serde(default = "default_long_break")]
     pub long_break: u32,
+
+    /// 몇 번째 Pomodoro마다 긴 휴식을 할지 (기본 4)
+    #[serde(default = "default_long_break_every")]
+    pub long_break_every: u32,
 }
 
 fn default_pomodoro_duration() -> u32 { 25 }
 fn default_short_break() -> u32 { 5 }
 fn default_long_break() -> u32 { 15 }
+fn default_long_break_every() -> u32 { DEFAULT_LONG_BREAK_EVERY }
 
 impl Default for PomodoroSession {
     fn default() -> Self {
@@ -36,40 +48,92 @@ impl Default for PomodoroSession {
             total_pomodoros: 0,
             completed_pomodoros: 0,
             current_start: None,
+            accumulated_seconds: 0,
             pomodoro_duration: 25,
             short_break: 5,
             long_break: 15,
+            long_break_every: DEFAULT_LONG_BREAK_EVERY,
         }
     }
 }
 
 impl PomodoroSession {
-    pub fn new(_estimated_minutes: i64) -> Self {
+    pub fn new(estimated_minutes: i64) -> Self {
         // 기본값 25분으로 total 계산 (나중에 custom duration으로 업데이트됨)
+        let total_pomodoros = ((estimated_minutes as f64 / 25.0).ceil() as u32).max(1);
         Self {
-            total_pomodoros: 1, // Task.start()에서 재계산됨
+            total_pomodoros,
             completed_pomodoros: 0,
             current_start: None,
+            accumulated_seconds: 0,
             pomodoro_duration: 25,
             short_break: 5,
             long_break: 15,
+            long_break_every: DEFAULT_LONG_BREAK_EVERY,
         }
     }
 
     pub fn start_pomodoro(&mut self) {
         self.current_start = Some(Local::now());
+        self.accumulated_seconds = 0;
+    }
+
+    /// 현재 Pomodoro 일시정지 (경과 시간 누적)
+    pub fn pause_pomodoro(&mut self) {
+        if let Some(start) = self.current_start.take() {
+            self.accumulated_seconds += (Local::now() - start).num_seconds();
+        }
+    }
+
+    /// 일시정지된 Pomodoro 재개 (누적 시간 유지)
+    pub fn resume_pomodoro(&mut self) {
+        if self.current_start.is_none() {
+            self.current_start = Some(Local::now());
+        }
+    }
+
+    /// 현재 Pomodoro가 `max_minutes`보다 오래전에 시작됐으면 버림 (앱이 죽은 경우)
+    ///
+    /// 버렸으면 true를 반환합니다.
+    pub fn abandon_if_stale(&mut self, max_minutes: i64, now: DateTime<Local>) -> bool {
+        match self.current_start {
+            Some(start) if (now - start).num_minutes() > max_minutes => {
+                self.current_start = None;
+                self.accumulated_seconds = 0;
+                true
+            }
+            _ => false,
+        }
     }
 
     pub fn complete_pomodoro(&mut self) {
         self.completed_pomodoros += 1;
         self.current_start = None;
+        self.accumulated_seconds = 0;
     }
 
+    /// 현재 Pomodoro 경과 시간 (일시정지 전 누적분 포함)
+    ///
+    /// 시계가 시작 시각보다 뒤로 가 있으면 None을 반환합니다.
     pub fn elapsed_minutes(&self) -> Option<i64> {
-        self.current_start.map(|start| {
-            let now = Local::now();
-            (now - start).num_minutes()
-        })
+        if self.current_start.is_none() && self.accumulated_seconds == 0 {
+            return None;
+        }
+
+        let now = Local::now();
+        if let Some(start) = self.current_start.filter(|start| now < *start) {
+            log::warn!(
+                "Clock is behind the pomodoro start ({}); ignoring elapsed time",
+                start.format("%Y-%m-%d %H:%M")
+            );
+            return None;
+        }
+
+        let running = self
+            .current_start
+            .map(|start| (now - start).num_seconds())
+            .unwrap_or(0);
+        Some((self.accumulated_seconds + running) / 60)
     }
 
     pub fn remaining_minutes(&self) -> Option<i64> {
@@ -82,8 +146,9 @@ impl PomodoroSession {
         self.completed_pomodoros >= self.total_pomodoros
     }
 
+    /// 다음 휴식 길이 (`long_break_every`번째마다 긴 휴식, 0이면 1로 취급)
     pub fn next_break_duration(&self) -> u32 {
-        if (self.completed_pomodoros + 1) % 4 == 0 {
+        if (self.completed_pomodoros + 1) % self.long_break_every.max(1) == 0 {
             self.long_break
         } else {
             self.short_break
@@ -95,6 +160,15 @@ impl PomodoroSession {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_elapsed_minutes_ignores_future_start() {
+        let mut session = PomodoroSession::new(50);
+        session.current_start = Some(Local::now() + chrono::Duration::minutes(30));
+
+        assert_eq!(session.elapsed_minutes(), None);
+        assert_eq!(session.remaining_minutes(), None);
+    }
+
     #[test]
     fn test_pomodoro_creation() {
         let session = PomodoroSession::new(50);
@@ -120,4 +194,37 @@ mod tests {
         session.completed_pomodoros = 3;
         assert_eq!(session.next_break_duration(), 15); // 4th break: long
     }
+
+    #[test]
+    fn test_break_duration_custom_cadence() {
+        let mut session = PomodoroSession::new(150);
+        session.long_break_every = 3;
+
+        session.completed_pomodoros = 1;
+        assert_eq!(session.next_break_duration(), 5); // 2nd break: short
+        session.completed_pomodoros = 2;
+        assert_eq!(session.next_break_duration(), 15); // 3rd break: long
+        session.completed_pomodoros = 3;
+        assert_eq!(session.next_break_duration(), 5);
+
+        // 예전에 저장된 세션은 기본 주기
+        let old: PomodoroSession = serde_json::from_str(r#"{"total_pomodoros": 4, "completed_pomodoros": 0}"#).unwrap();
+        assert_eq!(old.long_break_every, DEFAULT_LONG_BREAK_EVERY);
+    }
+
+    #[test]
+    fn test_pause_resume_keeps_elapsed() {
+        let mut session = PomodoroSession::new(25);
+        session.start_pomodoro();
+        // 10분 경과한 것으로 가정
+        session.current_start = Some(Local::now() - chrono::Duration::minutes(10));
+
+        session.pause_pomodoro();
+        assert!(session.current_start.is_none());
+        assert_eq!(session.elapsed_minutes(), Some(10));
+
+        session.resume_pomodoro();
+        assert_eq!(session.elapsed_minutes(), Some(10));
+        assert_eq!(session.remaining_minutes(), Some(15));
+    }
 }
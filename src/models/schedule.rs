@@ -1,7 +1,10 @@
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveTime, Timelike};
 use serde::{Deserialize, Serialize};
 
+use super::accountability::DailyAccountability;
 use super::task::{Task, TaskStatus};
+use crate::i18n::tr_with;
+use crate::time::resolve_local;
 
 /// 스케줄 변경 타입
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +41,19 @@ pub struct ScheduleChange {
 }
 
 impl ScheduleChange {
+    /// 작업 생성 변경 생성
+    pub fn task_created(task_title: String, time_range: String) -> Self {
+        Self {
+            timestamp: Local::now(),
+            change_type: ChangeType::TaskCreated,
+            task_title: Some(task_title.clone()),
+            old_time: None,
+            new_time: Some(time_range.clone()),
+            affected_tasks_count: None,
+            description: tr_with("change.task_created", &[("title", &task_title), ("time", &time_range)]),
+        }
+    }
+
     /// 작업 이동 변경 생성
     pub fn task_moved(task_title: String, old_time: String, new_time: String) -> Self {
         Self {
@@ -47,13 +63,32 @@ impl ScheduleChange {
             old_time: Some(old_time.clone()),
             new_time: Some(new_time.clone()),
             affected_tasks_count: None,
-            description: format!("\"{}\"를 {}에서 {}로 이동", task_title, old_time, new_time),
+            description: tr_with(
+                "change.task_moved",
+                &[("title", &task_title), ("old", &old_time), ("new", &new_time)],
+            ),
+        }
+    }
+
+    /// 창 안에서 시작 전 작업들을 같은 간격으로 다시 배치한 변경 생성 (`window`는 "HH:MM-HH:MM")
+    pub fn schedule_rebalanced(window: String, affected_count: usize) -> Self {
+        Self {
+            timestamp: Local::now(),
+            change_type: ChangeType::ScheduleShifted,
+            task_title: None,
+            old_time: None,
+            new_time: Some(window.clone()),
+            affected_tasks_count: Some(affected_count),
+            description: tr_with(
+                "change.schedule_rebalanced",
+                &[("window", &window), ("count", &affected_count)],
+            ),
         }
     }
 
     /// 스케줄 미루기/당기기 변경 생성
     pub fn schedule_shifted(from_task: String, minutes: i64, affected_count: usize) -> Self {
-        let direction = if minutes > 0 { "미루기" } else { "당기기" };
+        let message = if minutes > 0 { "change.schedule_delayed" } else { "change.schedule_advanced" };
         let abs_minutes = minutes.abs();
         Self {
             timestamp: Local::now(),
@@ -62,9 +97,25 @@ impl ScheduleChange {
             old_time: None,
             new_time: None,
             affected_tasks_count: Some(affected_count),
-            description: format!(
-                "\"{}\" 이후 {}개 작업 {}분 {} (영향: {}개 작업)",
-                from_task, affected_count, abs_minutes, direction, affected_count
+            description: tr_with(
+                message,
+                &[("title", &from_task), ("count", &affected_count), ("minutes", &abs_minutes)],
+            ),
+        }
+    }
+
+    /// 두 작업을 하나로 합친 변경 생성
+    pub fn tasks_merged(task_title: String, old_times: String, new_time: String) -> Self {
+        Self {
+            timestamp: Local::now(),
+            change_type: ChangeType::TaskUpdated,
+            task_title: Some(task_title.clone()),
+            old_time: Some(old_times.clone()),
+            new_time: Some(new_time.clone()),
+            affected_tasks_count: Some(2),
+            description: tr_with(
+                "change.tasks_merged",
+                &[("title", &task_title), ("old", &old_times), ("new", &new_time)],
             ),
         }
     }
@@ -78,9 +129,299 @@ impl ScheduleChange {
             old_time: Some(old_time.clone()),
             new_time: Some(new_time.clone()),
             affected_tasks_count: None,
-            description: format!("\"{}\" 시간 변경: {} → {}", task_title, old_time, new_time),
+            description: tr_with(
+                "change.task_updated",
+                &[("title", &task_title), ("old", &old_time), ("new", &new_time)],
+            ),
+        }
+    }
+}
+
+/// Pomodoro로 깔끔하게 나눠지는 길이 (분)
+pub const CLEAN_POMODORO_LENGTHS: [i64; 5] = [15, 25, 45, 60, 90];
+
+/// 시간 기록 관련 문제 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EstimateIssue {
+    /// 예상 시간이 Pomodoro 단위로 나눠지지 않음
+    UnevenPomodoros,
+    /// 완료한 작업의 실제 시간이 음수 (시계 변경이나 수동 편집)
+    NegativeActual,
+}
+
+/// 예상 시간이 Pomodoro 단위로 나눠지지 않거나 실제 시간이 잘못된 작업에 대한 경고
+#[derive(Debug, Clone, PartialEq)]
+pub struct EstimateWarning {
+    /// 작업 ID
+    pub task_id: String,
+    /// 작업 제목
+    pub task_title: String,
+    /// 현재 예상 시간 (분), `NegativeActual`이면 기록된 실제 시간
+    pub duration_minutes: i64,
+    /// 가장 가까운 깔끔한 길이 (분), `NegativeActual`이면 0
+    pub suggested_minutes: i64,
+    /// 문제 종류
+    pub issue: EstimateIssue,
+}
+
+impl EstimateWarning {
+    /// 사용자에게 보여줄 경고 문구
+    pub fn message(&self) -> String {
+        if self.issue == EstimateIssue::NegativeActual {
+            return format!(
+                "'{}' has a negative actual duration ({} min); its end time is before its start",
+                self.task_title, self.duration_minutes
+            );
+        }
+
+        let pomodoro = CLEAN_POMODORO_LENGTHS
+            .iter()
+            .rev()
+            .find(|len| self.suggested_minutes % *len == 0)
+            .copied()
+            .unwrap_or(self.suggested_minutes);
+
+        format!(
+            "'{}' is {} min; {} min fits {} × {}-min pomodoros",
+            self.task_title,
+            self.duration_minutes,
+            self.suggested_minutes,
+            self.suggested_minutes / pomodoro,
+            pomodoro
+        )
+    }
+}
+
+/// 새 작업이 기존 작업과 겹칠 때의 처리 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictPolicy {
+    /// 추가하지 않고 충돌 정보 반환
+    #[default]
+    Reject,
+    /// 겹치지 않는 시각까지 새 작업을 뒤로 미룸
+    Shift,
+    /// 겹쳐도 그대로 추가
+    Allow,
+}
+
+/// 작업 시간 충돌 정보
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaskConflict {
+    /// 추가하려던 작업 제목
+    pub task_title: String,
+    /// 추가하려던 작업 시간 ("HH:MM-HH:MM")
+    pub task_time: String,
+    /// 겹치는 기존 작업 ID
+    pub existing_id: String,
+    /// 겹치는 기존 작업 제목
+    pub existing_title: String,
+    /// 겹치는 기존 작업 시간 ("HH:MM-HH:MM")
+    pub existing_time: String,
+}
+
+impl TaskConflict {
+    fn new(task: &Task, existing: &Task) -> Self {
+        let time = |t: &Task| format!("{}-{}", t.start_time.format("%H:%M"), t.end_time.format("%H:%M"));
+        Self {
+            task_title: task.title.clone(),
+            task_time: time(task),
+            existing_id: existing.id.clone(),
+            existing_title: existing.title.clone(),
+            existing_time: time(existing),
+        }
+    }
+
+    /// 사용자에게 보여줄 문구
+    pub fn message(&self) -> String {
+        format!(
+            "'{}' ({}) overlaps '{}' ({})",
+            self.task_title, self.task_time, self.existing_title, self.existing_time
+        )
+    }
+}
+
+/// 연속된 작업 사이 휴식이 최소 휴식 시간보다 짧다는 경고
+#[derive(Debug, Clone, PartialEq)]
+pub struct BreakWarning {
+    /// 앞 작업 제목
+    pub before_title: String,
+    /// 뒤 작업 제목
+    pub after_title: String,
+    /// 실제 간격 (분)
+    pub gap_minutes: i64,
+    /// 설정된 최소 휴식 시간 (분)
+    pub min_break_minutes: i64,
+}
+
+impl BreakWarning {
+    /// 사용자에게 보여줄 경고 문구
+    pub fn message(&self) -> String {
+        format!(
+            "Only {} min between '{}' and '{}' (minimum break: {} min)",
+            self.gap_minutes, self.before_title, self.after_title, self.min_break_minutes
+        )
+    }
+}
+
+/// `Schedule::validate`가 찾은 경고
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScheduleWarning {
+    /// 예상 시간/실제 시간 문제
+    Estimate(EstimateWarning),
+    /// 최소 휴식 시간 부족
+    Break(BreakWarning),
+    /// 마감을 넘겼거나 넘길 예정
+    Deadline(DeadlineWarning),
+}
+
+impl ScheduleWarning {
+    /// 사용자에게 보여줄 경고 문구
+    pub fn message(&self) -> String {
+        match self {
+            Self::Estimate(warning) => warning.message(),
+            Self::Break(warning) => warning.message(),
+            Self::Deadline(warning) => warning.message(),
+        }
+    }
+}
+
+/// 시뮬레이션용 가상 변경
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimChange {
+    /// 예상 시간 변경
+    Estimate { task_id: String, minutes: i64 },
+    /// 주어진 시간(분)에 완료
+    Complete { task_id: String, actual_minutes: i64 },
+    /// 건너뛰기
+    Skip { task_id: String },
+}
+
+impl SimChange {
+    /// 대상 작업 ID
+    pub fn task_id(&self) -> &str {
+        match self {
+            Self::Estimate { task_id, .. } | Self::Complete { task_id, .. } | Self::Skip { task_id } => {
+                task_id
+            }
+        }
+    }
+}
+
+/// 마감 관련 문제 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadlineIssue {
+    /// 예약한 종료 시간이 마감보다 늦음
+    ScheduledPastDeadline,
+    /// 마감이 지났는데 완료되지 않음
+    Missed,
+}
+
+/// 마감 경고
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeadlineWarning {
+    /// 작업 ID
+    pub task_id: String,
+    /// 작업 제목
+    pub task_title: String,
+    /// 마감 시각
+    pub deadline: DateTime<Local>,
+    /// 문제 종류
+    pub issue: DeadlineIssue,
+}
+
+impl DeadlineWarning {
+    /// 사용자에게 보여줄 경고 문구
+    pub fn message(&self) -> String {
+        match self.issue {
+            DeadlineIssue::ScheduledPastDeadline => format!(
+                "'{}' is scheduled to end after its deadline ({})",
+                self.task_title,
+                self.deadline.format("%m-%d %H:%M")
+            ),
+            DeadlineIssue::Missed => format!(
+                "'{}' missed its deadline ({})",
+                self.task_title,
+                self.deadline.format("%m-%d %H:%M")
+            ),
+        }
+    }
+}
+
+/// 완료한 작업의 지켜진 시간 (초과한 만큼 차감)
+fn earned_minutes(task: &Task) -> i64 {
+    let estimated = task.estimated_duration_minutes;
+    let actual = task.plausible_actual_minutes().unwrap_or(estimated);
+
+    if actual <= estimated {
+        // 예상 시간 내 완료 또는 빨리 완료 -> 예상 시간만큼 획득
+        estimated
+    } else {
+        // 초과 완료 -> 예상 시간에서 초과분만큼 차감
+        (estimated - (actual - estimated)).max(0)
+    }
+}
+
+/// 휴식용 작업인지 (`break` 태그)
+fn is_break(task: &Task) -> bool {
+    task.tags.iter().any(|tag| tag == "break")
+}
+
+/// `from`에서 고정 시간대(시작순)를 빼고 `amount`만큼 지난 시각
+fn advance_skipping_fixed(
+    blocks: &[(DateTime<Local>, DateTime<Local>)],
+    from: DateTime<Local>,
+    amount: Duration,
+) -> DateTime<Local> {
+    let (mut cursor, mut left) = (from, amount);
+    for &(block_start, block_end) in blocks {
+        if block_end <= cursor {
+            continue;
+        }
+        if block_start >= cursor + left {
+            break;
+        }
+        left -= (block_start - cursor).max(Duration::zero());
+        cursor = block_end;
+    }
+    cursor + left
+}
+
+/// `start`부터 `length` 동안 고정 시간대(시작순)와 겹치지 않는 가장 이른 시작 시각
+fn skip_fixed<T>(blocks: &[(T, T)], mut start: T, length: Duration) -> T
+where
+    T: Copy + Ord + std::ops::Add<Duration, Output = T>,
+{
+    for &(block_start, block_end) in blocks {
+        if start < block_end && start + length > block_start {
+            start = block_end;
+        }
+    }
+    start
+}
+
+/// 시작순 시간대 중 겹치거나 붙은 것을 하나로 합침
+fn merge_blocks<T: Copy + Ord>(blocks: &[(T, T)]) -> Vec<(T, T)> {
+    let mut merged: Vec<(T, T)> = Vec::with_capacity(blocks.len());
+    for &(start, end) in blocks {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
         }
     }
+    merged
+}
+
+/// 깔끔하지 않은 길이면 가장 가까운 깔끔한 길이 (동률이면 긴 쪽)
+fn nearest_clean_length(minutes: i64) -> Option<i64> {
+    if CLEAN_POMODORO_LENGTHS.iter().any(|len| minutes % len == 0) {
+        return None;
+    }
+
+    CLEAN_POMODORO_LENGTHS
+        .iter()
+        .flat_map(|len| (1..=minutes / len + 1).map(move |n| n * len))
+        .min_by_key(|candidate| ((candidate - minutes).abs(), -candidate))
 }
 
 /// 하루 스케줄
@@ -119,6 +460,14 @@ pub struct Schedule {
     /// 페널티 시간 (계산된 값)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_penalty: Option<i64>,
+
+    /// 이 날짜에서 지운 반복 작업의 `recurrence_id` (다시 만들지 않음)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_recurrences: Vec<String>,
+
+    /// 불러올 때 겹치는 작업이 발견됐는지 (저장하지 않음)
+    #[serde(skip)]
+    pub has_conflicts: bool,
 }
 
 impl Schedule {
@@ -134,6 +483,8 @@ impl Schedule {
             total_wasted: None,
             total_bonus: None,
             total_penalty: None,
+            skipped_recurrences: Vec::new(),
+            has_conflicts: false,
         }
     }
 
@@ -160,12 +511,70 @@ impl Schedule {
     /// 작업 추가
     pub fn add_task(&mut self, task: Task) -> Result<(), String> {
         // 시간 충돌 검사
-        for existing_task in &self.tasks {
-            if self.has_time_conflict(&task, existing_task) {
-                return Err(format!(
-                    "Time conflict with task: {}",
-                    existing_task.title
-                ));
+        if let Some(existing_task) = self.conflicting_task(&task) {
+            return Err(format!(
+                "Time conflict with task: {}",
+                existing_task.title
+            ));
+        }
+
+        self.tasks.push(task);
+        Ok(())
+    }
+
+    /// 이 날짜에 해당하는 반복 작업 인스턴스 추가
+    ///
+    /// 같은 `recurrence_id`가 이미 있거나 (시작한 인스턴스는 그대로 둠), 이 날짜에서 지웠거나,
+    /// 종료일이 지난 반복은 건너뜁니다. 추가한 개수를 반환합니다.
+    pub fn materialize_recurring(&mut self, sources: &[Task]) -> usize {
+        let date = self.date.date_naive();
+        let mut added = 0;
+
+        for source in sources.iter().filter(|t| t.is_due_on(date)) {
+            let already_there = self
+                .tasks
+                .iter()
+                .any(|t| t.recurrence_id.is_some() && t.recurrence_id == source.recurrence_id);
+            let deleted_here = source
+                .recurrence_id
+                .as_ref()
+                .is_some_and(|id| self.skipped_recurrences.contains(id));
+            if already_there || deleted_here {
+                continue;
+            }
+
+            match self.add_task(source.recurrence_instance(date)) {
+                Ok(()) => added += 1,
+                Err(e) => log::warn!("Skipping recurring task '{}': {}", source.title, e),
+            }
+        }
+
+        self.sort_by_time();
+        added
+    }
+
+    /// 충돌 처리 방식에 따라 작업 추가
+    ///
+    /// `Shift`는 겹치는 작업이 끝나는 시각으로 계속 미루며, 날짜를 넘기면 충돌로 처리합니다.
+    pub fn add_task_with_policy(&mut self, mut task: Task, policy: ConflictPolicy) -> Result<(), TaskConflict> {
+        match policy {
+            ConflictPolicy::Allow => {}
+            ConflictPolicy::Reject => {
+                if let Some(existing) = self.conflicting_task(&task) {
+                    return Err(TaskConflict::new(&task, existing));
+                }
+            }
+            ConflictPolicy::Shift => {
+                let day = task.start_time.date_naive();
+                while let Some(existing) = self.conflicting_task(&task) {
+                    let delta = existing.end_time - task.start_time;
+                    if delta <= Duration::zero()
+                        || task.end_time + delta > resolve_local((day + Duration::days(1)).and_hms_opt(0, 0, 0).unwrap()) {
+                        return Err(TaskConflict::new(&task, existing));
+                    }
+                    task.start_time += delta;
+                    task.end_time += delta;
+                }
             }
         }
 
@@ -173,13 +582,168 @@ impl Schedule {
         Ok(())
     }
 
+    /// 주어진 작업과 시간이 겹치는 첫 번째 작업
+    pub fn conflicting_task(&self, task: &Task) -> Option<&Task> {
+        self.tasks
+            .iter()
+            .find(|existing| existing.id != task.id && self.has_time_conflict(task, existing))
+    }
+
+    /// 시간이 겹치는 작업 쌍이 있는지 (외부에서 편집된 파일 등)
+    pub fn has_overlaps(&self) -> bool {
+        self.tasks
+            .iter()
+            .enumerate()
+            .any(|(i, a)| self.tasks[i + 1..].iter().any(|b| self.has_time_conflict(a, b)))
+    }
+
+    /// 하루(00:00 ~ 다음날 00:00) 중 작업이 없는 빈 시간대 목록 (시간순)
+    pub fn gaps(&self) -> Vec<(DateTime<Local>, DateTime<Local>)> {
+        let day = self.date.date_naive();
+        let day_start = resolve_local(day.and_hms_opt(0, 0, 0).unwrap());
+        let day_end = day_start + Duration::days(1);
+
+        let mut busy: Vec<_> = self
+            .tasks
+            .iter()
+            .map(|t| (t.start_time, t.end_time))
+            .collect();
+        busy.sort_by_key(|(start, _)| *start);
+
+        let mut gaps = Vec::new();
+        let mut cursor = day_start;
+        for (start, end) in busy {
+            if start > cursor {
+                gaps.push((cursor, start.min(day_end)));
+            }
+            cursor = cursor.max(end);
+        }
+        if cursor < day_end {
+            gaps.push((cursor, day_end));
+        }
+
+        gaps.retain(|(start, end)| start < end);
+        gaps
+    }
+
+    /// `now`까지 깨어 있는 시간(`wake`~`sleep`) 중 작업이 없던 시간 (분)
+    pub fn idle_minutes(&self, wake: NaiveTime, sleep: NaiveTime, now: DateTime<Local>) -> i64 {
+        let day = self.date.date_naive();
+        let window_start = resolve_local(day.and_time(wake));
+        let window_end = resolve_local(day.and_time(sleep)).min(now);
+
+        self.gaps()
+            .into_iter()
+            .map(|(start, end)| (start.max(window_start), end.min(window_end)))
+            .filter(|(start, end)| start < end)
+            .map(|(start, end)| (end - start).num_minutes())
+            .sum()
+    }
+
+    /// 요청한 시작 시간에 가장 가까운, `duration_minutes` 길이의 빈 시간대
+    pub fn suggest_slot(
+        &self,
+        requested_start: DateTime<Local>,
+        duration_minutes: i64,
+    ) -> Option<(DateTime<Local>, DateTime<Local>)> {
+        let duration = Duration::minutes(duration_minutes);
+
+        self.gaps()
+            .into_iter()
+            .filter(|(start, end)| *end - *start >= duration)
+            .map(|(start, end)| {
+                // 빈 시간대 안에서 요청 시간에 가장 가까운 위치
+                let slot_start = requested_start.clamp(start, end - duration);
+                (slot_start, slot_start + duration)
+            })
+            .min_by_key(|(slot_start, _)| (*slot_start - requested_start).num_minutes().abs())
+    }
+
     /// 작업 제거
+    ///
+    /// 반복 작업 인스턴스를 지우면 이 날짜에는 다시 만들지 않습니다.
     pub fn remove_task(&mut self, task_id: &str) -> Option<Task> {
-        if let Some(pos) = self.tasks.iter().position(|t| t.id == task_id) {
-            Some(self.tasks.remove(pos))
-        } else {
-            None
+        let pos = self.tasks.iter().position(|t| t.id == task_id)?;
+        let task = self.tasks.remove(pos);
+        if let Some(id) = &task.recurrence_id {
+            if !self.skipped_recurrences.contains(id) {
+                self.skipped_recurrences.push(id.clone());
+            }
+        }
+        Some(task)
+    }
+
+    /// 붙어 있거나 겹치는 두 작업을 하나로 합침
+    ///
+    /// 합친 작업은 앞 작업 시작부터 뒤 작업 끝까지이고, 제목과 메모를 이어 붙이고
+    /// 태그는 합집합, 실제 시간은 합계입니다. 둘 다 완료했을 때만 완료 상태로 남습니다.
+    /// 원래 두 작업은 제거되고 합친 작업의 ID를 반환합니다.
+    pub fn merge_tasks(&mut self, first_id: &str, second_id: &str) -> Result<String, String> {
+        if first_id == second_id {
+            return Err("Cannot merge a task with itself".to_string());
+        }
+        let find = |id: &str| {
+            self.find_task(id)
+                .ok_or_else(|| format!("Task not found: {}", id))
+        };
+        let (a, b) = (find(first_id)?, find(second_id)?);
+        let (first, second) = if a.start_time <= b.start_time { (a, b) } else { (b, a) };
+
+        if second.start_time > first.end_time {
+            return Err(format!(
+                "'{}' and '{}' are not adjacent ({} min gap)",
+                first.title,
+                second.title,
+                (second.start_time - first.end_time).num_minutes()
+            ));
+        }
+        if let Some(running) = [first, second]
+            .into_iter()
+            .find(|t| matches!(t.status, TaskStatus::InProgress | TaskStatus::Paused))
+        {
+            return Err(format!("'{}' is in progress; complete it before merging", running.title));
+        }
+
+        let mut merged = Task::new(
+            format!("{} + {}", first.title, second.title),
+            first.start_time,
+            first.end_time.max(second.end_time),
+        );
+        merged.tags = first.tags.clone();
+        for tag in &second.tags {
+            if !merged.tags.contains(tag) {
+                merged.tags.push(tag.clone());
+            }
+        }
+        merged.notes = match (&first.notes, &second.notes) {
+            (Some(a), Some(b)) => Some(format!("{}\n{}", a, b)),
+            (a, b) => a.clone().or_else(|| b.clone()),
+        };
+        merged.link = first.link.clone().or_else(|| second.link.clone());
+        merged.fixed = first.fixed || second.fixed;
+        merged.priority = first.priority.max(second.priority);
+        merged.deadline = first.deadline.into_iter().chain(second.deadline).min();
+        merged.actual_duration_minutes = match (first.actual_duration_minutes, second.actual_duration_minutes) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+        };
+        merged.actual_start_time = first.actual_start_time.into_iter().chain(second.actual_start_time).min();
+        merged.actual_end_time = first.actual_end_time.into_iter().chain(second.actual_end_time).max();
+        merged.sessions = first.sessions.iter().chain(&second.sessions).cloned().collect();
+        merged.sessions.sort_by_key(|s| s.start);
+        merged.interruptions = first.interruptions.iter().chain(&second.interruptions).cloned().collect();
+        merged.interruptions.sort_by_key(|i| i.at);
+        if first.status == TaskStatus::Completed && second.status == TaskStatus::Completed {
+            merged.status = TaskStatus::Completed;
         }
+
+        let (first_id, second_id) = (first.id.clone(), second.id.clone());
+        self.remove_task(&first_id);
+        self.remove_task(&second_id);
+        let merged_id = merged.id.clone();
+        self.tasks.push(merged);
+        self.sort_by_time();
+        Ok(merged_id)
     }
 
     /// 작업 찾기
@@ -192,17 +756,77 @@ impl Schedule {
         self.tasks.iter_mut().find(|t| t.id == task_id)
     }
 
-    /// 현재 진행 중인 작업
+    /// 현재 진행 중인 작업 (고정한 작업 우선, 없으면 첫 진행 중 작업)
     pub fn get_current_task(&self) -> Option<&Task> {
-        self.tasks.iter().find(|t| t.is_current())
+        self.tasks
+            .iter()
+            .find(|t| t.focused && t.is_current())
+            .or_else(|| self.tasks.iter().find(|t| t.is_current()))
+    }
+
+    /// 작업 하나를 현재 작업으로 고정 (다른 작업의 고정은 해제)
+    pub fn focus_task(&mut self, task_id: &str) -> Result<&Task, String> {
+        let index = self
+            .tasks
+            .iter()
+            .position(|t| t.id == task_id)
+            .ok_or_else(|| format!("Task not found: {}", task_id))?;
+
+        if matches!(self.tasks[index].status, TaskStatus::Completed | TaskStatus::Skipped) {
+            return Err(format!("Task '{}' is already finished", self.tasks[index].title));
+        }
+
+        for (i, task) in self.tasks.iter_mut().enumerate() {
+            task.focused = i == index;
+        }
+        Ok(&self.tasks[index])
     }
 
-    /// 다음 작업 (Pending 상태 중 가장 빠른 시작 시간)
+    /// 다음 작업 (Pending 상태 중 가장 빠른 시작 시간, 같으면 ID 순)
     pub fn get_next_task(&self) -> Option<&Task> {
         self.tasks
             .iter()
             .filter(|t| t.status == TaskStatus::Pending)
-            .min_by_key(|t| t.start_time)
+            .min_by(|a, b| {
+                a.start_time
+                    .cmp(&b.start_time)
+                    .then_with(|| b.priority.cmp(&a.priority))
+                    .then_with(|| a.id.cmp(&b.id))
+            })
+    }
+
+    /// 시작 시간이 지났는데 아직 시작하지 않은 작업 중 가장 이른 것
+    pub fn first_unstarted_overdue(&self, now: DateTime<Local>) -> Option<&Task> {
+        self.tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Pending && t.start_time < now)
+            .min_by(|a, b| a.start_time.cmp(&b.start_time).then_with(|| a.id.cmp(&b.id)))
+    }
+
+    /// 다음 대기 작업을 시작하고 돌려줌 (없으면 None)
+    pub fn start_next_task(&mut self) -> Option<&Task> {
+        let next_id = self.get_next_task()?.id.clone();
+        let task = self.find_task_mut(&next_id)?;
+        task.start();
+        Some(task)
+    }
+
+    /// 완료한 작업의 평균 집중도 (점수가 있는 작업만, 없으면 None)
+    pub fn average_focus_score(&self) -> Option<f64> {
+        let scores: Vec<f64> = self
+            .tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Completed)
+            .filter_map(|t| t.focus_score)
+            .map(f64::from)
+            .collect();
+
+        (!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64)
+    }
+
+    /// 시작한 작업의 평균 시작 지연 (분), 시작한 작업이 없으면 None
+    pub fn average_start_delay(&self) -> Option<f64> {
+        average_start_delay(&self.tasks)
     }
 
     /// 완료율 계산 (%)
@@ -220,6 +844,62 @@ impl Schedule {
         (completed as f64 / self.tasks.len() as f64) * 100.0
     }
 
+    /// 남은 계획 시간 (분) - 대기/진행 중(일시정지 포함) 작업의 예상 시간 합
+    pub fn remaining_planned_minutes(&self) -> i64 {
+        self.tasks
+            .iter()
+            .filter(|t| {
+                matches!(
+                    t.status,
+                    TaskStatus::Pending
+                    | TaskStatus::InProgress
+                    | TaskStatus::Paused
+                    | TaskStatus::Blocked
+                )
+            })
+            .map(|t| t.estimated_duration_minutes)
+            .sum()
+    }
+
+    /// 남은 작업을 `now`부터 쉬지 않고 했을 때 끝나는 시각 (남은 작업이 없으면 None)
+    ///
+    /// 진행 중인 작업은 이미 한 만큼을 빼고, 막힌 작업은 지금 할 수 없으므로 제외합니다.
+    pub fn projected_finish_time(&self, now: DateTime<Local>) -> Option<DateTime<Local>> {
+        let remaining: i64 = self
+            .tasks
+            .iter()
+            .filter(|t| {
+                !t.fixed
+                    && matches!(
+                        t.status,
+                        TaskStatus::Pending | TaskStatus::InProgress | TaskStatus::Paused
+                    )
+            })
+            .map(|t| {
+                let done = t.active_minutes_at(now).unwrap_or(0);
+                (t.estimated_duration_minutes - done).max(0)
+            })
+            .sum();
+        if remaining <= 0 {
+            return None;
+        }
+
+        // 고정 시간대에는 일할 수 없으므로 건너뜀
+        Some(advance_skipping_fixed(&self.fixed_blocks(), now, Duration::minutes(remaining)))
+    }
+
+    /// 고정 작업 시간대 (시작순)
+    fn fixed_blocks(&self) -> Vec<(DateTime<Local>, DateTime<Local>)> {
+        let mut blocks: Vec<_> = self
+            .tasks
+            .iter()
+            .filter(|t| t.fixed)
+            .map(|t| (t.start_time, t.end_time))
+            .collect();
+        blocks.sort();
+        blocks
+    }
+
     /// 시간 정확도 계산 (%)
     pub fn time_accuracy(&self) -> Option<f64> {
         let completed_tasks: Vec<_> = self
@@ -252,18 +932,7 @@ impl Schedule {
         self.tasks
             .iter()
             .filter(|t| t.status == TaskStatus::Completed)
-            .map(|t| {
-                let estimated = t.estimated_duration_minutes;
-                let actual = t.actual_duration_minutes.unwrap_or(estimated);
-
-                if actual <= estimated {
-                    // 예상 시간 내 완료 또는 빨리 완료 -> 예상 시간만큼 획득
-                    estimated
-                } else {
-                    // 초과 완료 -> 예상 시간에서 초과분만큼 차감
-                    (estimated - (actual - estimated)).max(0)
-                }
-            })
+            .map(earned_minutes)
             .sum()
     }
 
@@ -276,7 +945,8 @@ impl Schedule {
             .iter()
             .filter(|t| {
                 // 완료되지 않았고, 종료 시간이 현재보다 과거인 task만
-                t.status != TaskStatus::Completed && t.end_time < now
+                // (외부 요인으로 막힌 작업은 낭비로 보지 않음)
+                !matches!(t.status, TaskStatus::Completed | TaskStatus::Blocked) && t.end_time < now
             })
             .map(|t| {
                 if t.status == TaskStatus::Skipped {
@@ -341,43 +1011,605 @@ impl Schedule {
         (earned / planned * 100.0).min(100.0)
     }
 
-    /// 시간 충돌 검사
-    fn has_time_conflict(&self, task1: &Task, task2: &Task) -> bool {
-        // 시작 시간이 겹치는지 확인
-        (task1.start_time >= task2.start_time && task1.start_time < task2.end_time)
-            || (task1.end_time > task2.start_time && task1.end_time <= task2.end_time)
-            || (task1.start_time <= task2.start_time && task1.end_time >= task2.end_time)
-    }
+    /// `now`까지 끝났어야 할 작업만 기준으로 한 효율 점수 (%)
+    ///
+    /// 완료·건너뛴 작업과 종료 시간이 지난 작업만 계획 시간에 넣으므로, 아직 남은 작업이
+    /// 점수를 깎지 않습니다. 해당 작업이 없으면 None.
+    pub fn efficiency_score_so_far(&self, now: DateTime<Local>) -> Option<f64> {
+        let due: Vec<&Task> = self
+            .tasks
+            .iter()
+            .filter(|t| match t.status {
+                TaskStatus::Completed | TaskStatus::Skipped => true,
+                TaskStatus::Blocked => false,
+                _ => t.end_time <= now,
+            })
+            .collect();
 
-    /// 시간순 정렬
-    pub fn sort_by_time(&mut self) {
-        self.tasks.sort_by_key(|t| t.start_time);
-    }
-}
+        let planned: i64 = due.iter().map(|t| t.estimated_duration_minutes).sum();
+        if planned == 0 {
+            return None;
+        }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use chrono::Duration;
+        let earned: i64 = due
+            .iter()
+            .filter(|t| t.status == TaskStatus::Completed)
+            .map(|t| earned_minutes(t))
+            .sum();
 
-    #[test]
-    fn test_schedule_creation() {
-        let schedule = Schedule::today();
-        assert_eq!(schedule.tasks.len(), 0);
+        Some((earned as f64 / planned as f64 * 100.0).min(100.0))
     }
 
-    #[test]
-    fn test_add_task() {
-        let mut schedule = Schedule::today();
-        let start = Local::now();
-        let end = start + Duration::hours(2);
-        let task = Task::new("Test".to_string(), start, end);
+    /// 스케줄 검사: 시간 기록 문제, 최소 휴식 시간보다 붙어 있는 작업, `now` 기준 마감 문제
+    pub fn validate(&self, min_break_minutes: i64, now: DateTime<Local>) -> Vec<ScheduleWarning> {
+        let estimates = self.estimate_warnings().into_iter().map(ScheduleWarning::Estimate);
+        let breaks = self
+            .break_warnings(min_break_minutes)
+            .into_iter()
+            .map(ScheduleWarning::Break);
+        let deadlines = self.deadline_warnings(now).into_iter().map(ScheduleWarning::Deadline);
 
-        assert!(schedule.add_task(task).is_ok());
-        assert_eq!(schedule.tasks.len(), 1);
+        estimates.chain(breaks).chain(deadlines).collect()
     }
 
-    #[test]
+    /// 예상 시간이 Pomodoro로 깔끔하게 나눠지지 않거나 실제 시간이 음수인 작업 찾기
+    ///
+    /// 가장 짧은 Pomodoro보다 짧은 작업과 `break` 태그 작업은 제외합니다.
+    fn estimate_warnings(&self) -> Vec<EstimateWarning> {
+        let uneven = self
+            .tasks
+            .iter()
+            .filter(|t| t.estimated_duration_minutes >= CLEAN_POMODORO_LENGTHS[0])
+            .filter(|t| !is_break(t))
+            .filter_map(|t| {
+                nearest_clean_length(t.estimated_duration_minutes).map(|suggested| EstimateWarning {
+                    task_id: t.id.clone(),
+                    task_title: t.title.clone(),
+                    duration_minutes: t.estimated_duration_minutes,
+                    suggested_minutes: suggested,
+                    issue: EstimateIssue::UnevenPomodoros,
+                })
+            });
+
+        let negative = self
+            .tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Completed)
+            .filter_map(|t| {
+                let minutes = t.recorded_duration_minutes().filter(|m| *m < 0)?;
+                Some(EstimateWarning {
+                    task_id: t.id.clone(),
+                    task_title: t.title.clone(),
+                    duration_minutes: minutes,
+                    suggested_minutes: 0,
+                    issue: EstimateIssue::NegativeActual,
+                })
+            });
+
+        uneven.chain(negative).collect()
+    }
+
+    /// 최소 휴식 시간보다 가깝게 붙어 있는 연속 작업 (break 태그 작업은 제외)
+    pub fn break_warnings(&self, min_break_minutes: i64) -> Vec<BreakWarning> {
+        if min_break_minutes <= 0 {
+            return Vec::new();
+        }
+
+        let mut tasks: Vec<&Task> = self.tasks.iter().collect();
+        tasks.sort_by_key(|t| t.start_time);
+
+        tasks
+            .windows(2)
+            .filter(|pair| !pair.iter().any(|t| is_break(t)))
+            .filter_map(|pair| {
+                let gap = (pair[1].start_time - pair[0].end_time).num_minutes();
+                (gap < min_break_minutes).then(|| BreakWarning {
+                    before_title: pair[0].title.clone(),
+                    after_title: pair[1].title.clone(),
+                    gap_minutes: gap,
+                    min_break_minutes,
+                })
+            })
+            .collect()
+    }
+
+    /// 최소 휴식 시간이 생기도록 뒤 작업들을 미루고 미룬 횟수를 반환
+    ///
+    /// 미룰 때마다 `ScheduleShifted` 변경을 기록하며, 실패하면 스케줄은 그대로 둡니다.
+    pub fn enforce_breaks(&mut self, min_break_minutes: i64) -> Result<usize, String> {
+        if min_break_minutes <= 0 {
+            return Ok(0);
+        }
+
+        self.sort_by_time();
+        let original = self.tasks.clone();
+        let mut changes = Vec::new();
+
+        for index in 1..self.tasks.len() {
+            let (before, after) = (&self.tasks[index - 1], &self.tasks[index]);
+            if is_break(before) || is_break(after) || before.fixed || after.fixed {
+                continue;
+            }
+
+            let gap = (after.start_time - before.end_time).num_minutes();
+            if gap >= min_break_minutes {
+                continue;
+            }
+
+            let shift = min_break_minutes - gap;
+            let title = after.title.clone();
+            if let Err(e) = self.shift_range(index, shift) {
+                self.tasks = original;
+                return Err(e);
+            }
+            changes.push(ScheduleChange::schedule_shifted(
+                title,
+                shift,
+                self.tasks.len() - index,
+            ));
+        }
+
+        let count = changes.len();
+        for change in changes {
+            self.add_change(change);
+        }
+        Ok(count)
+    }
+
+    /// 아직 시작하지 않은 작업을 순서와 길이는 그대로 두고 사이 간격이 같도록 다시 배치
+    ///
+    /// 대기(Pending) 작업만 옮기고 나머지 상태의 작업은 그대로 둡니다. 완료/진행 중 작업이
+    /// 끝난 뒤부터 배치하며, 첫 작업은 창의 시작에, 마지막 작업은 창의 끝에 맞춰지고 나머지
+    /// 분은 앞쪽 간격에 1분씩 더합니다. 고정 작업과 일시정지/막힌/건너뛴 작업은 그 시간대를
+    /// 비워 둡니다. 옮긴 작업 수를 반환합니다.
+    pub fn rebalance(&mut self, window_start: DateTime<Local>, window_end: DateTime<Local>) -> Result<usize, String> {
+        let is_anchor = |t: &Task| t.fixed || t.status != TaskStatus::Pending;
+        let start = self
+            .tasks
+            .iter()
+            .filter(|t| !t.fixed && matches!(t.status, TaskStatus::Completed | TaskStatus::InProgress))
+            .map(|t| t.end_time)
+            .fold(window_start, DateTime::max);
+
+        self.sort_by_time();
+        let movable: Vec<usize> = (0..self.tasks.len()).filter(|&i| !is_anchor(&self.tasks[i])).collect();
+        if movable.is_empty() {
+            return Ok(0);
+        }
+
+        let mut blocks = self.fixed_blocks();
+        blocks.extend(
+            self.tasks
+                .iter()
+                .filter(|t| {
+                    !t.fixed && matches!(t.status, TaskStatus::Paused | TaskStatus::Blocked | TaskStatus::Skipped)
+                })
+                .map(|t| (t.start_time, t.end_time)),
+        );
+        blocks.sort();
+        let blocks = merge_blocks(&blocks);
+        let blocked: i64 = blocks
+            .iter()
+            .map(|(s, e)| ((*e).min(window_end) - (*s).max(start)).num_minutes().max(0))
+            .sum();
+        let busy: i64 = movable.iter().map(|&i| self.task_length(i)).sum();
+        let available = (window_end - start).num_minutes() - blocked;
+        let free = available - busy;
+        if free < 0 {
+            return Err(format!(
+                "Tasks need {} min but only {} min are left in the window",
+                busy,
+                available.max(0)
+            ));
+        }
+
+        let gaps = (movable.len() as i64 - 1).max(1);
+        let (gap, extra) = (free / gaps, free % gaps);
+        let original = self.tasks.clone();
+        let mut cursor = start;
+
+        for (n, &i) in movable.iter().enumerate() {
+            let length = Duration::minutes(self.task_length(i));
+            let task = &mut self.tasks[i];
+            task.start_time = skip_fixed(&blocks, cursor, length);
+            task.end_time = task.start_time + length;
+            let spacing = Duration::minutes(gap + i64::from((n as i64) < extra));
+            cursor = advance_skipping_fixed(&blocks, task.end_time, spacing);
+        }
+
+        if let Some(late) = movable.iter().map(|&i| &self.tasks[i]).find(|t| t.end_time > window_end) {
+            let title = late.title.clone();
+            self.tasks = original;
+            return Err(format!("'{}' doesn't fit in the window around fixed tasks", title));
+        }
+        self.sort_by_time();
+
+        let window = format!("{}-{}", start.format("%H:%M"), window_end.format("%H:%M"));
+        self.add_change(ScheduleChange::schedule_rebalanced(window, movable.len()));
+        Ok(movable.len())
+    }
+
+    /// 예약된 작업 길이 (분)
+    fn task_length(&self, index: usize) -> i64 {
+        let task = &self.tasks[index];
+        (task.end_time - task.start_time).num_minutes()
+    }
+
+    /// 가상 변경을 적용했을 때의 시간 성과 (스케줄은 바뀌지 않음, 없는 ID는 무시)
+    pub fn simulate(&self, changes: &[SimChange]) -> DailyAccountability {
+        let mut tasks = self.tasks.clone();
+
+        for change in changes {
+            let Some(task) = tasks.iter_mut().find(|t| t.id == change.task_id()) else {
+                continue;
+            };
+
+            match change {
+                SimChange::Estimate { minutes, .. } => task.estimated_duration_minutes = *minutes,
+                SimChange::Complete { actual_minutes, .. } => {
+                    task.status = TaskStatus::Completed;
+                    task.actual_duration_minutes = Some(*actual_minutes);
+                }
+                SimChange::Skip { .. } => task.status = TaskStatus::Skipped,
+            }
+        }
+
+        DailyAccountability::from_tasks(self.date, &tasks)
+    }
+
+    /// 마감을 넘겨 예약됐거나 이미 마감을 놓친 미완료 작업 (건너뛴 작업은 제외)
+    pub fn deadline_warnings(&self, now: DateTime<Local>) -> Vec<DeadlineWarning> {
+        self.tasks
+            .iter()
+            .filter(|t| !matches!(t.status, TaskStatus::Completed | TaskStatus::Skipped))
+            .filter_map(|t| {
+                let deadline = t.deadline?;
+                let issue = if now > deadline {
+                    DeadlineIssue::Missed
+                } else if t.end_time > deadline {
+                    DeadlineIssue::ScheduledPastDeadline
+                } else {
+                    return None;
+                };
+
+                Some(DeadlineWarning {
+                    task_id: t.id.clone(),
+                    task_title: t.title.clone(),
+                    deadline,
+                    issue,
+                })
+            })
+            .collect()
+    }
+
+    /// 시간대(0~23시)별 완료된 집중 시간 (분)
+    ///
+    /// 완료된 작업의 실제 작업 구간을 걸쳐 있는 시간대에 나눠 더합니다.
+    pub fn focus_minutes_by_hour(&self) -> [i64; 24] {
+        let mut minutes = [0i64; 24];
+        self.for_each_focus_slice(|at, slice| minutes[at.hour() as usize] += slice);
+        minutes
+    }
+
+    /// 요일(월=0)과 시간대별 완료된 집중 시간 (분)
+    ///
+    /// 자정을 넘긴 작업은 다음 요일 칸에 나눠 더합니다.
+    pub fn focus_minutes_by_weekday_hour(&self) -> [[i64; 24]; 7] {
+        let mut minutes = [[0i64; 24]; 7];
+        self.for_each_focus_slice(|at, slice| {
+            minutes[at.weekday().num_days_from_monday() as usize][at.hour() as usize] += slice;
+        });
+        minutes
+    }
+
+    /// 완료된 작업의 실제 작업 구간을 시간 경계로 잘라 (구간 시작, 분) 단위로 전달
+    fn for_each_focus_slice(&self, mut add: impl FnMut(DateTime<Local>, i64)) {
+        for task in self.tasks.iter().filter(|t| t.status == TaskStatus::Completed) {
+            let start = task.actual_start_time.unwrap_or(task.start_time);
+            let end = match (task.actual_end_time, task.actual_duration_minutes) {
+                (Some(end), _) if task.actual_start_time.is_some() => end,
+                (_, Some(actual)) => start + Duration::minutes(actual),
+                _ => task.end_time,
+            };
+
+            let mut cursor = start;
+            while cursor < end {
+                let next_hour = (cursor + Duration::hours(1))
+                    .with_minute(0)
+                    .and_then(|t| t.with_second(0))
+                    .and_then(|t| t.with_nanosecond(0))
+                    .unwrap_or(end);
+                let slice_end = next_hour.min(end);
+                add(cursor, (slice_end - cursor).num_minutes());
+                cursor = slice_end;
+            }
+        }
+    }
+
+    /// 완료된 집중 시간이 가장 많은 시간대 (없으면 None)
+    pub fn busiest_hour(&self) -> Option<u32> {
+        busiest_hour(&self.focus_minutes_by_hour())
+    }
+
+    /// 이전 스케줄의 미완료 작업을 같은 시각으로 이 스케줄에 복사
+    ///
+    /// 새 ID와 `Pending` 상태로 복사하며, 기존 작업과 충돌하는 작업은 건너뜁니다.
+    /// (옮겨진 수, 건너뛴 수)를 반환합니다.
+    pub fn carry_over_from(&mut self, previous: &Schedule) -> (usize, usize) {
+        let day_offset = self.date.date_naive() - previous.date.date_naive();
+        let mut carried = 0;
+        let mut skipped = 0;
+
+        for task in previous.tasks.iter().filter(|t| {
+            matches!(
+                t.status,
+                TaskStatus::Pending
+                    | TaskStatus::InProgress
+                    | TaskStatus::Paused
+                    | TaskStatus::Blocked
+            )
+        }) {
+            let mut copy = task.clone_to_date(task.start_time.date_naive() + day_offset);
+            if task.status == TaskStatus::Blocked {
+                copy.block(task.blocked_reason.clone());
+            }
+
+            if self.add_task(copy).is_ok() {
+                carried += 1;
+            } else {
+                skipped += 1;
+            }
+        }
+
+        self.sort_by_time();
+        (carried, skipped)
+    }
+
+    /// `from_index`부터의 모든 작업을 `minutes`만큼 이동
+    ///
+    /// 이동 후 작업이 그날(00:00 ~ 다음날 00:00)을 벗어나거나,
+    /// 당기기로 `from_index` 이전 작업과 겹치면 아무것도 바꾸지 않고 에러를 반환합니다.
+    pub fn shift_range(&mut self, from_index: usize, minutes: i64) -> Result<(), String> {
+        if from_index >= self.tasks.len() {
+            return Err("Task index out of bounds".to_string());
+        }
+
+        let offset = Duration::minutes(minutes);
+        let day_start = self.date.date_naive().and_hms_opt(0, 0, 0).unwrap();
+        let day_end = day_start + Duration::days(1);
+
+        // 고정 작업은 그대로 두고, 고정 시간대에 걸리는 작업은 그 뒤로 밀며
+        // 이후 작업은 밀린 작업과 겹치지 않게 함
+        let blocks: Vec<_> = self
+            .fixed_blocks()
+            .into_iter()
+            .map(|(start, end)| (start.naive_local(), end.naive_local()))
+            .collect();
+        let mut pushed_until = None;
+        let shifted: Vec<_> = self.tasks[from_index..]
+            .iter()
+            .map(|t| {
+                if t.fixed {
+                    return (t.start_time.naive_local(), t.end_time.naive_local());
+                }
+                let length = t.end_time - t.start_time;
+                let wanted = (t.start_time.naive_local() + offset).max(pushed_until.unwrap_or_default());
+                let start = skip_fixed(&blocks, wanted, length);
+                if pushed_until.map_or(true, |until| start + length > until) {
+                    pushed_until = Some(start + length);
+                }
+                (start, start + length)
+            })
+            .collect();
+
+        for (task, (start, end)) in self.tasks[from_index..].iter().zip(&shifted) {
+            if task.fixed {
+                continue;
+            }
+            if *start < day_start || *end > day_end {
+                return Err(format!(
+                    "Shifting by {} min moves '{}' outside {}",
+                    minutes,
+                    task.title,
+                    self.date.format("%Y-%m-%d")
+                ));
+            }
+        }
+
+        let first_moved = self.tasks[from_index..].iter().zip(&shifted).find(|(t, _)| !t.fixed);
+        let previous = self.tasks[..from_index].iter().max_by_key(|t| t.end_time);
+        if let (Some((task, (start, _))), Some(previous)) = (first_moved, previous) {
+            if *start < previous.end_time.naive_local() {
+                return Err(format!(
+                    "Shifting by {} min makes '{}' overlap '{}' (ends {})",
+                    minutes,
+                    task.title,
+                    previous.title,
+                    previous.end_time.format("%H:%M")
+                ));
+            }
+        }
+
+        for (task, (start, end)) in self.tasks[from_index..].iter_mut().zip(shifted) {
+            if !task.fixed {
+                task.start_time = resolve_local(start);
+                task.end_time = resolve_local(end);
+            }
+        }
+        self.sort_by_time();
+
+        Ok(())
+    }
+
+    /// `today` 이전에 시작된 채 진행 중인 작업을 일시정지
+    ///
+    /// 앱이 밤새 꺼져 있던 경우 Pomodoro가 몇 시간째 진행 중인 것처럼 보이지 않도록
+    /// 진행 중인 Pomodoro는 버리고, 예정 종료 시각(늦어도 시작한 날 자정)에 일시정지한 것으로
+    /// 기록해 밤사이는 작업 시간에서 빠집니다. 일시정지한 작업의 (ID, 제목)을 반환합니다.
+    pub fn pause_stale_tasks(&mut self, today: NaiveDate) -> Vec<(String, String)> {
+        let mut paused = Vec::new();
+
+        for task in self.tasks.iter_mut().filter(|t| {
+            t.status == TaskStatus::InProgress
+                && t.actual_start_time.is_some_and(|s| s.date_naive() < today)
+        }) {
+            if let Some(ref mut session) = task.pomodoro {
+                session.current_start = None;
+            }
+            let started = task.actual_start_time.unwrap_or(task.start_time);
+            let day_end = resolve_local((started.date_naive() + Duration::days(1)).and_hms_opt(0, 0, 0).unwrap());
+            task.pause_at(task.end_time.min(day_end).max(started));
+            paused.push((task.id.clone(), task.title.clone()));
+        }
+
+        paused
+    }
+
+    /// 작업 예상 시간보다 오래 진행 중으로 남은 Pomodoro를 모두 버림
+    ///
+    /// 포모도로 길이가 예상 시간보다 길면 포모도로 길이를 기준으로 합니다.
+    /// Pomodoro를 버린 작업의 제목을 반환합니다.
+    pub fn abandon_all_stale_pomodoros(&mut self, now: DateTime<Local>) -> Vec<String> {
+        let mut abandoned = Vec::new();
+
+        for task in self.tasks.iter_mut() {
+            let limit = task.estimated_duration_minutes;
+            if let Some(ref mut session) = task.pomodoro {
+                if session.abandon_if_stale(limit.max(session.pomodoro_duration as i64), now) {
+                    abandoned.push(task.title.clone());
+                }
+            }
+        }
+
+        abandoned
+    }
+
+    /// 시간 충돌 검사
+    fn has_time_conflict(&self, task1: &Task, task2: &Task) -> bool {
+        // 시작 시간이 겹치는지 확인
+        (task1.start_time >= task2.start_time && task1.start_time < task2.end_time)
+            || (task1.end_time > task2.start_time && task1.end_time <= task2.end_time)
+            || (task1.start_time <= task2.start_time && task1.end_time >= task2.end_time)
+    }
+
+    /// 시간순 정렬
+    pub fn sort_by_time(&mut self) {
+        self.tasks.sort_by_key(|t| t.start_time);
+    }
+}
+
+/// 여러 작업의 평균 시작 지연 (분), 시작한 작업이 없으면 None
+pub fn average_start_delay<'a>(tasks: impl IntoIterator<Item = &'a Task>) -> Option<f64> {
+    let delays: Vec<i64> = tasks.into_iter().filter_map(Task::start_delay_minutes).collect();
+    (!delays.is_empty()).then(|| delays.iter().sum::<i64>() as f64 / delays.len() as f64)
+}
+
+/// 이 일수 이상 연속으로 같은 작업이 있으면 습관으로 봄
+pub const HABIT_MIN_DAYS: usize = 3;
+
+/// 며칠 연속 같은 시간에 추가한 작업 (반복 작업 후보)
+#[derive(Debug, Clone, PartialEq)]
+pub struct HabitCandidate {
+    /// 가장 최근 작업 제목
+    pub title: String,
+    /// 시작 시각
+    pub start: NaiveTime,
+    /// 종료 시각
+    pub end: NaiveTime,
+    /// 연속 일수
+    pub days: usize,
+    /// 연속 구간의 마지막 날짜
+    pub last_date: NaiveDate,
+    /// 마지막 날짜의 작업 ID
+    pub task_id: String,
+}
+
+/// 제목 (대소문자 무시)과 시간대가 같은 작업이 `min_days`일 이상 연속된 경우
+///
+/// 이미 반복 작업인 것은 제외합니다. 작업마다 가장 최근의 연속 구간 중 가장 긴 것을
+/// 연속 일수가 많은 순으로 반환합니다.
+pub fn habit_candidates(schedules: &[Schedule], min_days: usize) -> Vec<HabitCandidate> {
+    use std::collections::BTreeMap;
+
+    type HabitKey = (String, NaiveTime, NaiveTime);
+    let mut occurrences: BTreeMap<HabitKey, BTreeMap<NaiveDate, &Task>> = BTreeMap::new();
+    for schedule in schedules {
+        for task in schedule.tasks.iter().filter(|t| t.recurrence_id.is_none()) {
+            let key = (
+                task.title.trim().to_lowercase(),
+                task.start_time.time(),
+                task.end_time.time(),
+            );
+            occurrences.entry(key).or_default().insert(task.start_time.date_naive(), task);
+        }
+    }
+
+    let mut candidates = Vec::new();
+    for ((_, start, end), days) in occurrences {
+        // (연속 일수, 마지막 날짜) - 길이가 같으면 나중 구간
+        let mut best: Option<(usize, NaiveDate)> = None;
+        let mut run: Option<(usize, NaiveDate)> = None;
+        for date in days.keys().copied() {
+            run = match run {
+                Some((length, last)) if last.succ_opt() == Some(date) => Some((length + 1, date)),
+                _ => Some((1, date)),
+            };
+            if run.map(|r| r.0) >= best.map(|b| b.0) {
+                best = run;
+            }
+        }
+
+        if let Some((length, last_date)) = best.filter(|(length, _)| *length >= min_days) {
+            let task = days[&last_date];
+            candidates.push(HabitCandidate {
+                title: task.title.clone(),
+                start,
+                end,
+                days: length,
+                last_date,
+                task_id: task.id.clone(),
+            });
+        }
+    }
+
+    candidates.sort_by(|a, b| b.days.cmp(&a.days).then_with(|| a.start.cmp(&b.start)));
+    candidates
+}
+
+/// 시간대별 분 배열에서 가장 큰 시간대 (동률이면 이른 시간, 모두 0이면 None)
+pub fn busiest_hour(minutes: &[i64; 24]) -> Option<u32> {
+    minutes
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| **m > 0)
+        .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(&a.0)))
+        .map(|(hour, _)| hour as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{at, day_schedule};
+    use crate::models::Priority;
+
+    #[test]
+    fn test_schedule_creation() {
+        let schedule = Schedule::today();
+        assert_eq!(schedule.tasks.len(), 0);
+    }
+
+    #[test]
+    fn test_add_task() {
+        let mut schedule = Schedule::today();
+        let start = Local::now();
+        let end = start + Duration::hours(2);
+        let task = Task::new("Test".to_string(), start, end);
+
+        assert!(schedule.add_task(task).is_ok());
+        assert_eq!(schedule.tasks.len(), 1);
+    }
+
+    #[test]
     fn test_time_conflict() {
         let mut schedule = Schedule::today();
         let start = Local::now();
@@ -393,6 +1625,67 @@ mod tests {
         assert!(schedule.add_task(task2).is_err()); // 시간 충돌
     }
 
+    #[test]
+    fn test_suggest_slot() {
+        let mut schedule = Schedule::new(at(1, 0, 0));
+        schedule
+            .add_task(Task::new("Work".to_string(), at(1, 9, 0), at(1, 12, 0)))
+            .unwrap();
+        schedule
+            .add_task(Task::new("Lunch".to_string(), at(1, 12, 0), at(1, 13, 30)))
+            .unwrap();
+
+        // 12:30에 45분 요청 -> 점심 이후 13:30-14:15가 가장 가까움
+        let slot = schedule.suggest_slot(at(1, 12, 30), 45).unwrap();
+        assert_eq!(slot, (at(1, 13, 30), at(1, 14, 15)));
+
+        // 빈 시간대 안의 요청은 그대로
+        let slot = schedule.suggest_slot(at(1, 15, 0), 30).unwrap();
+        assert_eq!(slot, (at(1, 15, 0), at(1, 15, 30)));
+    }
+
+    #[test]
+    fn test_carry_over_from() {
+
+        let mut previous = Schedule::new(at(1, 0, 0));
+        let mut done = Task::new("Done".to_string(), at(1, 9, 0), at(1, 10, 0));
+        done.complete();
+        previous.add_task(done).unwrap();
+        previous
+            .add_task(Task::new("Unfinished".to_string(), at(1, 10, 0), at(1, 11, 0)))
+            .unwrap();
+        previous
+            .add_task(Task::new("Clashes".to_string(), at(1, 14, 0), at(1, 15, 0)))
+            .unwrap();
+
+        let mut schedule = Schedule::new(at(2, 0, 0));
+        schedule
+            .add_task(Task::new("Meeting".to_string(), at(2, 14, 0), at(2, 15, 0)))
+            .unwrap();
+
+        let (carried, skipped) = schedule.carry_over_from(&previous);
+        assert_eq!((carried, skipped), (1, 1));
+
+        let unfinished = schedule.tasks.iter().find(|t| t.title == "Unfinished").unwrap();
+        assert_eq!(unfinished.status, TaskStatus::Pending);
+        assert_eq!(unfinished.start_time, at(2, 10, 0));
+        assert_ne!(unfinished.id, previous.tasks[1].id);
+        assert!(!schedule.tasks.iter().any(|t| t.title == "Done"));
+    }
+
+    #[test]
+    fn test_gaps() {
+        let mut schedule = Schedule::new(at(1, 0, 0));
+        schedule
+            .add_task(Task::new("Work".to_string(), at(1, 9, 0), at(1, 12, 0)))
+            .unwrap();
+
+        let gaps = schedule.gaps();
+        assert_eq!(gaps.len(), 2);
+        assert_eq!(gaps[0], (at(1, 0, 0), at(1, 9, 0)));
+        assert_eq!(gaps[1].0, at(1, 12, 0));
+    }
+
     #[test]
     fn test_completion_rate() {
         let mut schedule = Schedule::today();
@@ -412,4 +1705,622 @@ mod tests {
 
         assert_eq!(schedule.completion_rate(), 50.0);
     }
+
+    #[test]
+    fn test_remaining_planned_minutes() {
+        let mut schedule = Schedule::today();
+        let start = Local::now();
+
+        let mut done = Task::new("Done".to_string(), start, start + Duration::hours(1));
+        done.complete();
+        let mut active = Task::new(
+            "Active".to_string(),
+            start + Duration::hours(1),
+            start + Duration::minutes(140),
+        );
+        active.start();
+        let pending = Task::new(
+            "Pending".to_string(),
+            start + Duration::hours(3),
+            start + Duration::hours(5),
+        );
+
+        schedule.add_task(done).unwrap();
+        schedule.add_task(active).unwrap();
+        schedule.add_task(pending).unwrap();
+
+        assert_eq!(schedule.remaining_planned_minutes(), 200);
+    }
+
+    #[test]
+    fn test_projected_finish_time() {
+        let mut schedule = day_schedule();
+        schedule.add_task(Task::new("Email".to_string(), at(4, 15, 0), at(4, 15, 30))).unwrap();
+        schedule.add_task(Task::new("Review".to_string(), at(4, 16, 0), at(4, 16, 30))).unwrap();
+
+        assert_eq!(schedule.projected_finish_time(at(4, 14, 0)), Some(at(4, 15, 0)));
+
+        // 진행 중인 작업은 이미 한 시간만큼 줄어듦
+        schedule.tasks[0].status = TaskStatus::InProgress;
+        schedule.tasks[0].actual_start_time = Some(at(4, 13, 50));
+        assert_eq!(schedule.projected_finish_time(at(4, 14, 0)), Some(at(4, 14, 50)));
+
+        for task in &mut schedule.tasks {
+            task.status = TaskStatus::Completed;
+        }
+        assert_eq!(schedule.projected_finish_time(at(4, 14, 0)), None);
+    }
+
+    fn shift_fixture() -> Schedule {
+        let mut schedule = day_schedule();
+        schedule
+            .add_task(Task::new("Morning".to_string(), at(4, 9, 0), at(4, 10, 0)))
+            .unwrap();
+        schedule
+            .add_task(Task::new("Late".to_string(), at(4, 10, 30), at(4, 11, 30)))
+            .unwrap();
+        schedule
+    }
+
+    #[test]
+    fn test_shift_range_negative_collision() {
+        let mut schedule = shift_fixture();
+
+        let err = schedule.shift_range(1, -45).unwrap_err();
+        assert!(err.contains("overlap 'Morning'"));
+        assert_eq!(schedule.tasks[1].start_time.format("%H:%M").to_string(), "10:30");
+    }
+
+    #[test]
+    fn test_shift_range_negative_safe() {
+        let mut schedule = shift_fixture();
+
+        schedule.shift_range(1, -30).unwrap();
+        assert_eq!(schedule.tasks[1].start_time.format("%H:%M").to_string(), "10:00");
+        assert_eq!(schedule.tasks[1].end_time.format("%H:%M").to_string(), "11:00");
+        assert_eq!(schedule.tasks[0].start_time.format("%H:%M").to_string(), "09:00");
+    }
+
+    #[test]
+    fn test_shift_range_past_midnight() {
+        let mut schedule = shift_fixture();
+        assert!(schedule.shift_range(0, 13 * 60).is_err());
+    }
+
+    #[test]
+    fn test_blocked_task_skipped_and_not_wasted() {
+        let mut schedule = Schedule::today();
+        let start = Local::now() - Duration::hours(3);
+
+        let mut blocked = Task::new("Waiting".to_string(), start, start + Duration::hours(1));
+        blocked.block(Some("waiting on review".to_string()));
+        let pending = Task::new(
+            "Next".to_string(),
+            start + Duration::hours(1),
+            start + Duration::hours(2),
+        );
+
+        schedule.add_task(blocked).unwrap();
+        schedule.add_task(pending).unwrap();
+
+        assert_eq!(schedule.get_next_task().unwrap().title, "Next");
+        // 막힌 작업은 종료 시간이 지나도 낭비가 아님 (대기 작업만 60분)
+        assert_eq!(schedule.total_wasted(), 60);
+    }
+
+    #[test]
+    fn test_focus_minutes_by_hour() {
+        let mut schedule = day_schedule();
+
+        let mut task = Task::new("Deep work".to_string(), at(4, 9, 0), at(4, 11, 0));
+        task.status = TaskStatus::Completed;
+        task.actual_start_time = Some(at(4, 9, 0));
+        task.actual_end_time = Some(at(4, 11, 0));
+        task.actual_duration_minutes = Some(120);
+        schedule.add_task(task).unwrap();
+
+        let minutes = schedule.focus_minutes_by_hour();
+        assert_eq!(minutes[9], 60);
+        assert_eq!(minutes[10], 60);
+        assert_eq!(minutes.iter().sum::<i64>(), 120);
+        assert_eq!(schedule.busiest_hour(), Some(9));
+    }
+
+    #[test]
+    fn test_focus_minutes_by_weekday_hour() {
+        // 2025-11-03은 월요일
+        let mut schedule = Schedule::new(at(3, 0, 0));
+
+        let mut task = Task::new("Planning".to_string(), at(3, 10, 0), at(3, 11, 0));
+        task.complete();
+        task.actual_start_time = Some(at(3, 10, 0));
+        task.actual_end_time = Some(at(3, 11, 0));
+        task.actual_duration_minutes = Some(60);
+        schedule.add_task(task).unwrap();
+        schedule
+            .add_task(Task::new("Not done".to_string(), at(3, 14, 0), at(3, 15, 0)))
+            .unwrap();
+
+        let grid = schedule.focus_minutes_by_weekday_hour();
+        assert_eq!(grid[0][10], 60);
+        assert_eq!(grid.iter().flatten().sum::<i64>(), 60);
+    }
+
+    #[test]
+    fn test_validate_uneven_estimates() {
+        let mut schedule = day_schedule();
+        schedule
+            .add_task(Task::new("Odd".to_string(), at(4, 9, 0), at(4, 9, 37)))
+            .unwrap();
+        schedule
+            .add_task(Task::new("Even".to_string(), at(4, 10, 0), at(4, 10, 50)))
+            .unwrap();
+
+        let warnings = schedule.validate(0, at(4, 8, 0));
+        assert_eq!(warnings.len(), 1);
+        let ScheduleWarning::Estimate(warning) = &warnings[0] else {
+            panic!("expected an estimate warning");
+        };
+        assert_eq!(warning.task_title, "Odd");
+        assert_eq!(warning.suggested_minutes, 30);
+        assert!(warnings[0].message().contains("2 × 15-min"));
+    }
+
+    #[test]
+    fn test_rebalance_spreads_tasks_evenly() {
+        let mut schedule = day_schedule();
+        schedule.add_task(Task::new("A".to_string(), at(4, 9, 0), at(4, 10, 0))).unwrap();
+        schedule.add_task(Task::new("B".to_string(), at(4, 10, 0), at(4, 10, 30))).unwrap();
+        schedule.add_task(Task::new("C".to_string(), at(4, 10, 30), at(4, 12, 0))).unwrap();
+
+        assert_eq!(schedule.rebalance(at(4, 9, 0), at(4, 18, 0)), Ok(3));
+
+        let times: Vec<_> = schedule.tasks.iter().map(|t| (t.start_time, t.end_time)).collect();
+        assert_eq!(
+            times,
+            vec![(at(4, 9, 0), at(4, 10, 0)), (at(4, 13, 0), at(4, 13, 30)), (at(4, 16, 30), at(4, 18, 0))]
+        );
+        let gaps: Vec<i64> = schedule
+            .tasks
+            .windows(2)
+            .map(|pair| (pair[1].start_time - pair[0].end_time).num_minutes())
+            .collect();
+        assert_eq!(gaps, vec![180, 180]);
+        assert!(matches!(schedule.changes.last().unwrap().change_type, ChangeType::ScheduleShifted));
+
+        assert!(schedule.rebalance(at(4, 9, 0), at(4, 11, 0)).is_err());
+    }
+
+    #[test]
+    fn test_rebalance_only_moves_pending_tasks() {
+        let mut schedule = day_schedule();
+        let statuses = [
+            ("Paused", TaskStatus::Paused),
+            ("Blocked", TaskStatus::Blocked),
+            ("Skipped", TaskStatus::Skipped),
+            ("A", TaskStatus::Pending),
+            ("B", TaskStatus::Pending),
+        ];
+        for (hour, (title, status)) in (9..).zip(statuses) {
+            let mut task = Task::new(title.to_string(), at(4, hour, 0), at(4, hour, 30));
+            task.status = status;
+            schedule.add_task(task).unwrap();
+        }
+
+        assert_eq!(schedule.rebalance(at(4, 9, 0), at(4, 12, 0)), Ok(2));
+
+        let time = |title: &str| {
+            let task = schedule.tasks.iter().find(|t| t.title == title).unwrap();
+            (task.start_time, task.end_time)
+        };
+        assert_eq!(time("Paused"), (at(4, 9, 0), at(4, 9, 30)));
+        assert_eq!(time("Blocked"), (at(4, 10, 0), at(4, 10, 30)));
+        assert_eq!(time("Skipped"), (at(4, 11, 0), at(4, 11, 30)));
+        // 일시정지/막힌/건너뛴 작업 시간대는 비워 둠
+        assert_eq!(time("A"), (at(4, 9, 30), at(4, 10, 0)));
+        assert_eq!(time("B"), (at(4, 11, 30), at(4, 12, 0)));
+        assert!(!schedule.has_overlaps());
+    }
+
+    #[test]
+    fn test_rebalance_counts_overlapping_blocks_once() {
+        let mut schedule = day_schedule();
+        let mut lunch = Task::new("Lunch".to_string(), at(4, 12, 0), at(4, 13, 0));
+        lunch.fixed = true;
+        let mut call = Task::new("Call".to_string(), at(4, 12, 30), at(4, 13, 30));
+        call.status = TaskStatus::Paused;
+        // add_task를 거치지 않은 겹치는 작업
+        schedule.tasks.push(lunch);
+        schedule.tasks.push(call);
+        schedule.add_task(Task::new("A".to_string(), at(4, 9, 0), at(4, 10, 25))).unwrap();
+        schedule.add_task(Task::new("B".to_string(), at(4, 10, 30), at(4, 11, 55))).unwrap();
+
+        // 막힌 시간은 12:00-13:30의 90분이므로 10분이 남음
+        assert_eq!(schedule.rebalance(at(4, 9, 0), at(4, 13, 30)), Ok(2));
+        let b = schedule.tasks.iter().find(|t| t.title == "B").unwrap();
+        assert_eq!((b.start_time, b.end_time), (at(4, 10, 35), at(4, 12, 0)));
+    }
+
+    #[test]
+    fn test_add_task_with_policy() {
+        let mut schedule = day_schedule();
+        schedule.add_task(Task::new("Standup".to_string(), at(4, 9, 0), at(4, 9, 30))).unwrap();
+        schedule.add_task(Task::new("Review".to_string(), at(4, 9, 30), at(4, 10, 0))).unwrap();
+
+        let overlapping = || Task::new("Write".to_string(), at(4, 9, 15), at(4, 10, 15));
+        let conflict = schedule
+            .add_task_with_policy(overlapping(), ConflictPolicy::Reject)
+            .unwrap_err();
+        assert_eq!(conflict.existing_title, "Standup");
+        assert_eq!(conflict.message(), "'Write' (09:15-10:15) overlaps 'Standup' (09:00-09:30)");
+        assert_eq!(schedule.tasks.len(), 2);
+
+        schedule.add_task_with_policy(overlapping(), ConflictPolicy::Shift).unwrap();
+        let shifted = schedule.tasks.iter().find(|t| t.title == "Write").unwrap();
+        assert_eq!((shifted.start_time, shifted.end_time), (at(4, 10, 0), at(4, 11, 0)));
+
+        schedule.add_task_with_policy(overlapping(), ConflictPolicy::Allow).unwrap();
+        assert!(schedule.has_overlaps());
+
+        let late = Task::new("Late".to_string(), at(4, 23, 0), at(4, 23, 50));
+        schedule.add_task(Task::new("Night".to_string(), at(4, 22, 30), at(4, 23, 30))).unwrap();
+        assert!(schedule.add_task_with_policy(late, ConflictPolicy::Shift).is_err());
+    }
+
+    #[test]
+    fn test_validate_negative_actual() {
+        let mut schedule = day_schedule();
+        let mut task = Task::new("Backwards".to_string(), at(4, 9, 0), at(4, 10, 0));
+        task.status = TaskStatus::Completed;
+        task.actual_start_time = Some(at(4, 10, 30));
+        task.actual_end_time = Some(at(4, 10, 0));
+        schedule.add_task(task).unwrap();
+
+        let warnings = schedule.validate(0, at(4, 12, 0));
+        assert_eq!(warnings.len(), 1);
+        let ScheduleWarning::Estimate(warning) = &warnings[0] else {
+            panic!("expected an estimate warning");
+        };
+        assert_eq!(warning.issue, EstimateIssue::NegativeActual);
+        assert_eq!(warning.duration_minutes, -30);
+        assert!(schedule.efficiency_score_so_far(at(4, 12, 0)).unwrap() >= 0.0);
+    }
+
+    #[test]
+    fn test_next_task_tie_break_by_id() {
+        let mut schedule = day_schedule();
+
+        let mut first = Task::new("B".to_string(), at(4, 9, 0), at(4, 10, 0));
+        first.id = "bbb".to_string();
+        let mut second = Task::new("A".to_string(), at(4, 9, 0), at(4, 10, 0));
+        second.id = "aaa".to_string();
+        // 같은 시작 시간은 add_task에서 거부되므로 직접 추가
+        schedule.tasks.push(first);
+        schedule.tasks.push(second);
+
+        assert_eq!(schedule.get_next_task().unwrap().id, "aaa");
+        schedule.tasks.reverse();
+        assert_eq!(schedule.get_next_task().unwrap().id, "aaa");
+
+        // 시작 시간이 같으면 ID보다 우선순위가 먼저
+        schedule.tasks.iter_mut().find(|t| t.id == "bbb").unwrap().priority = Priority::High;
+        assert_eq!(schedule.get_next_task().unwrap().id, "bbb");
+
+        // 더 이른 작업은 우선순위가 낮아도 먼저
+        let mut early = Task::new("Early".to_string(), at(4, 8, 0), at(4, 9, 0));
+        early.priority = Priority::Low;
+        schedule.tasks.push(early);
+        assert_eq!(schedule.get_next_task().unwrap().title, "Early");
+    }
+
+    #[test]
+    fn test_first_unstarted_overdue() {
+        let mut schedule = day_schedule();
+        schedule.add_task(Task::new("Later".to_string(), at(4, 14, 0), at(4, 15, 0))).unwrap();
+        schedule.add_task(Task::new("Missed".to_string(), at(4, 9, 0), at(4, 10, 0))).unwrap();
+
+        assert_eq!(schedule.first_unstarted_overdue(at(4, 11, 0)).unwrap().title, "Missed");
+        assert!(schedule.first_unstarted_overdue(at(4, 8, 0)).is_none());
+
+        schedule.tasks.iter_mut().find(|t| t.title == "Missed").unwrap().start();
+        assert!(schedule.first_unstarted_overdue(at(4, 11, 0)).is_none());
+    }
+
+    #[test]
+    fn test_deadline_warnings() {
+        let mut schedule = day_schedule();
+
+        let mut late = Task::new("Report".to_string(), at(4, 9, 0), at(4, 11, 0));
+        late.deadline = Some(at(4, 10, 30));
+        let mut fine = Task::new("Slides".to_string(), at(4, 13, 0), at(4, 14, 0));
+        fine.deadline = Some(at(4, 15, 0));
+        schedule.add_task(late).unwrap();
+        schedule.add_task(fine).unwrap();
+
+        // 아침: 예약이 마감을 넘는 작업만
+        let warnings = schedule.deadline_warnings(at(4, 8, 0));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].task_title, "Report");
+        assert_eq!(warnings[0].issue, DeadlineIssue::ScheduledPastDeadline);
+
+        // 마감이 지난 뒤: 미완료 작업은 Missed, 완료된 작업은 제외
+        schedule.tasks[0].complete();
+        let warnings = schedule.deadline_warnings(at(4, 15, 30));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].task_title, "Slides");
+        assert_eq!(warnings[0].issue, DeadlineIssue::Missed);
+
+        // validate도 같은 경고를 보고
+        assert_eq!(
+            schedule.validate(0, at(4, 15, 30)),
+            vec![ScheduleWarning::Deadline(warnings[0].clone())]
+        );
+
+        // 건너뛴 작업도 제외
+        schedule.tasks[1].skip();
+        assert!(schedule.deadline_warnings(at(4, 15, 30)).is_empty());
+    }
+
+    #[test]
+    fn test_break_warnings_for_adjacent_tasks() {
+        let mut schedule = day_schedule();
+        schedule.add_task(Task::new("Code".to_string(), at(4, 9, 0), at(4, 10, 0))).unwrap();
+        schedule.add_task(Task::new("Review".to_string(), at(4, 10, 0), at(4, 11, 0))).unwrap();
+        schedule.add_task(Task::new("Email".to_string(), at(4, 11, 30), at(4, 12, 0))).unwrap();
+
+        let warnings = schedule.break_warnings(10);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].before_title, "Code");
+        assert_eq!(warnings[0].after_title, "Review");
+        assert_eq!(warnings[0].gap_minutes, 0);
+        assert!(schedule.break_warnings(0).is_empty());
+
+        // validate도 같은 경고를 보고
+        assert_eq!(
+            schedule.validate(10, at(4, 8, 0)),
+            vec![ScheduleWarning::Break(warnings[0].clone())]
+        );
+        assert!(schedule.validate(0, at(4, 8, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_enforce_breaks_shifts_later_tasks() {
+        let mut schedule = day_schedule();
+        schedule.add_task(Task::new("Code".to_string(), at(4, 9, 0), at(4, 10, 0))).unwrap();
+        schedule.add_task(Task::new("Review".to_string(), at(4, 10, 0), at(4, 11, 0))).unwrap();
+
+        assert_eq!(schedule.enforce_breaks(10), Ok(1));
+        assert_eq!(schedule.tasks[1].start_time, at(4, 10, 10));
+        assert_eq!(schedule.tasks[1].end_time, at(4, 11, 10));
+        assert!(schedule.break_warnings(10).is_empty());
+        assert!(matches!(
+            schedule.changes.last().unwrap().change_type,
+            ChangeType::ScheduleShifted
+        ));
+    }
+
+    #[test]
+    fn test_simulate_early_completion_raises_efficiency() {
+        let mut schedule = day_schedule();
+        let mut done = Task::new("Code".to_string(), at(4, 9, 0), at(4, 10, 0));
+        done.status = TaskStatus::Completed;
+        done.actual_duration_minutes = Some(60);
+        schedule.add_task(done).unwrap();
+        schedule.add_task(Task::new("Docs".to_string(), at(4, 10, 0), at(4, 11, 0))).unwrap();
+        let docs_id = schedule.tasks[1].id.clone();
+
+        let current = DailyAccountability::from_tasks(schedule.date, &schedule.tasks);
+        let projected = schedule.simulate(&[SimChange::Complete {
+            task_id: docs_id.clone(),
+            actual_minutes: 45,
+        }]);
+
+        assert!(projected.efficiency_score() > current.efficiency_score());
+        assert_eq!(projected.total_bonus, 15);
+        // 원본은 그대로
+        assert_eq!(schedule.find_task(&docs_id).unwrap().status, TaskStatus::Pending);
+
+        let skipped = schedule.simulate(&[SimChange::Skip { task_id: docs_id }]);
+        assert_eq!(skipped.total_wasted, 60);
+    }
+
+    #[test]
+    fn test_efficiency_score_so_far_ignores_upcoming_tasks() {
+        let mut schedule = day_schedule();
+        let mut done = Task::new("Code".to_string(), at(4, 9, 0), at(4, 10, 0));
+        done.status = TaskStatus::Completed;
+        done.actual_duration_minutes = Some(60);
+        schedule.add_task(done).unwrap();
+        schedule.add_task(Task::new("Missed".to_string(), at(4, 10, 0), at(4, 11, 0))).unwrap();
+        schedule.add_task(Task::new("Later".to_string(), at(4, 15, 0), at(4, 17, 0))).unwrap();
+
+        assert_eq!(day_schedule().efficiency_score_so_far(at(4, 12, 0)), None);
+        assert_eq!(schedule.efficiency_score_so_far(at(4, 10, 30)), Some(100.0));
+        assert_eq!(schedule.efficiency_score_so_far(at(4, 12, 0)), Some(50.0));
+        assert_eq!(schedule.efficiency_score(), 25.0);
+    }
+
+    #[test]
+    fn test_recurring_task_past_until_is_not_materialized() {
+        let mut standup = Task::new("Standup".to_string(), at(3, 9, 0), at(3, 10, 0));
+        standup.recurrence = Some(crate::models::Recurrence::Daily);
+        standup.recurrence_id = Some(standup.id.clone());
+        standup.recurrence_until = Some(at(4, 0, 0).date_naive());
+
+        let mut today = Schedule::new(at(5, 0, 0));
+        assert_eq!(today.materialize_recurring(std::slice::from_ref(&standup)), 0);
+        assert!(today.tasks.is_empty());
+
+        standup.recurrence_until = None;
+        assert_eq!(today.materialize_recurring(std::slice::from_ref(&standup)), 1);
+        assert_eq!(today.materialize_recurring(std::slice::from_ref(&standup)), 0);
+        assert_eq!(today.tasks[0].start_time, at(5, 9, 0));
+        assert_eq!(today.tasks[0].status, TaskStatus::Pending);
+        assert_ne!(today.tasks[0].id, standup.id);
+    }
+
+    #[test]
+    fn test_recurring_task_keeps_started_and_respects_deletion() {
+        let mut standup = Task::new("Standup".to_string(), at(3, 9, 0), at(3, 10, 0));
+        standup.recurrence = Some(crate::models::Recurrence::Weekdays);
+        standup.recurrence_id = Some(standup.id.clone());
+        let sources = std::slice::from_ref(&standup);
+
+        // 이미 시작한 인스턴스는 덮어쓰지 않음
+        let mut today = day_schedule();
+        today.materialize_recurring(sources);
+        let instance_id = today.tasks[0].id.clone();
+        today.tasks[0].start();
+        assert_eq!(today.materialize_recurring(sources), 0);
+        assert_eq!(today.tasks.len(), 1);
+        assert_eq!(today.tasks[0].status, TaskStatus::InProgress);
+
+        // 지운 날에는 다시 만들지 않고, 다른 날에는 그대로 생김
+        today.remove_task(&instance_id);
+        assert_eq!(today.materialize_recurring(sources), 0);
+        assert!(today.tasks.is_empty());
+        let json = serde_json::to_string(&today).unwrap();
+        let mut reloaded: Schedule = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded.materialize_recurring(sources), 0);
+        assert_eq!(Schedule::new(at(5, 0, 0)).materialize_recurring(sources), 1);
+
+        // 주말은 건너뜀 (2025-11-08은 토요일)
+        assert_eq!(Schedule::new(at(8, 0, 0)).materialize_recurring(sources), 0);
+    }
+
+    #[test]
+    fn test_start_delay_and_average() {
+        let mut late = Task::new("Code".to_string(), at(4, 9, 0), at(4, 10, 0));
+        late.actual_start_time = Some(at(4, 9, 10));
+        assert_eq!(late.start_delay_minutes(), Some(10));
+
+        let mut early = Task::new("Docs".to_string(), at(4, 11, 0), at(4, 12, 0));
+        early.actual_start_time = Some(at(4, 10, 56));
+        let untouched = Task::new("Later".to_string(), at(4, 15, 0), at(4, 16, 0));
+        assert_eq!(untouched.start_delay_minutes(), None);
+
+        assert_eq!(average_start_delay([&late, &early, &untouched]), Some(3.0));
+        assert_eq!(average_start_delay([&untouched]), None);
+    }
+
+    #[test]
+    fn test_focused_task_is_current() {
+        let mut schedule = day_schedule();
+        let mut build = Task::new("Build".to_string(), at(4, 9, 0), at(4, 10, 0));
+        build.status = TaskStatus::InProgress;
+        let mut review = Task::new("Review".to_string(), at(4, 10, 0), at(4, 11, 0));
+        review.status = TaskStatus::InProgress;
+        let review_id = review.id.clone();
+        schedule.add_task(build).unwrap();
+        schedule.add_task(review).unwrap();
+
+        assert_eq!(schedule.get_current_task().unwrap().title, "Build");
+
+        schedule.focus_task(&review_id).unwrap();
+        assert_eq!(schedule.get_current_task().unwrap().title, "Review");
+        assert_eq!(schedule.tasks.iter().filter(|t| t.focused).count(), 1);
+
+        let build_id = schedule.tasks[0].id.clone();
+        schedule.focus_task(&build_id).unwrap();
+        assert!(!schedule.find_task(&review_id).unwrap().focused);
+        assert_eq!(schedule.get_current_task().unwrap().title, "Build");
+
+        schedule.find_task_mut(&build_id).unwrap().complete();
+        assert_eq!(schedule.get_current_task().unwrap().title, "Review");
+    }
+
+    #[test]
+    fn test_merge_back_to_back_tasks() {
+        let mut schedule = day_schedule();
+        let mut draft = Task::new("Draft".to_string(), at(4, 9, 0), at(4, 10, 0));
+        draft.tags = vec!["writing".to_string()];
+        draft.actual_duration_minutes = Some(50);
+        let mut edit = Task::new("Edit".to_string(), at(4, 10, 0), at(4, 11, 0));
+        edit.tags = vec!["writing".to_string(), "review".to_string()];
+        edit.notes = Some("Check figures".to_string());
+        edit.actual_duration_minutes = Some(40);
+        let lunch = Task::new("Lunch".to_string(), at(4, 12, 0), at(4, 13, 0));
+        let (draft_id, edit_id, lunch_id) = (draft.id.clone(), edit.id.clone(), lunch.id.clone());
+        for task in [draft, edit, lunch] {
+            schedule.add_task(task).unwrap();
+        }
+
+        assert!(schedule.merge_tasks(&edit_id, &lunch_id).unwrap_err().contains("not adjacent"));
+        assert!(schedule.merge_tasks(&draft_id, &draft_id).is_err());
+
+        let merged_id = schedule.merge_tasks(&edit_id, &draft_id).unwrap();
+        assert_eq!(schedule.tasks.len(), 2);
+        assert!(schedule.find_task(&draft_id).is_none());
+        let merged = schedule.find_task(&merged_id).unwrap();
+        assert_eq!(merged.title, "Draft + Edit");
+        assert_eq!((merged.start_time, merged.end_time), (at(4, 9, 0), at(4, 11, 0)));
+        assert_eq!(merged.estimated_duration_minutes, 120);
+        assert_eq!(merged.actual_duration_minutes, Some(90));
+        assert_eq!(merged.tags, vec!["writing", "review"]);
+        assert_eq!(merged.notes.as_deref(), Some("Check figures"));
+        assert_eq!(merged.status, TaskStatus::Pending);
+    }
+
+    #[test]
+    fn test_habit_candidates_need_three_consecutive_days() {
+        let schedules: Vec<Schedule> = (3..=7)
+            .map(|d| {
+                let mut schedule = Schedule::new(at(d, 0, 0));
+                if d >= 5 {
+                    let title = if d == 7 { "gym" } else { "Gym" };
+                    schedule.add_task(Task::new(title.to_string(), at(d, 7, 0), at(d, 8, 0))).unwrap();
+                }
+                if d == 3 || d == 4 || d == 6 {
+                    schedule.add_task(Task::new("Read".to_string(), at(d, 21, 0), at(d, 22, 0))).unwrap();
+                }
+                schedule
+            })
+            .collect();
+
+        let candidates = habit_candidates(&schedules, HABIT_MIN_DAYS);
+        assert_eq!(candidates.len(), 1);
+        let gym = &candidates[0];
+        assert_eq!((gym.title.as_str(), gym.days), ("gym", 3));
+        assert_eq!(gym.last_date, at(7, 0, 0).date_naive());
+        assert_eq!(gym.task_id, schedules[4].tasks[0].id);
+
+        assert!(habit_candidates(&schedules[..4], HABIT_MIN_DAYS).is_empty());
+    }
+
+    #[test]
+    fn test_replan_moves_tasks_around_fixed_block() {
+        let mut schedule = day_schedule();
+        let mut lunch = Task::new("Lunch".to_string(), at(4, 12, 0), at(4, 13, 0));
+        lunch.fixed = true;
+        let lunch_id = lunch.id.clone();
+        schedule.add_task(Task::new("Write".to_string(), at(4, 11, 0), at(4, 11, 45))).unwrap();
+        schedule.add_task(lunch).unwrap();
+        schedule.add_task(Task::new("Review".to_string(), at(4, 13, 0), at(4, 13, 30))).unwrap();
+        schedule.add_task(Task::new("Code".to_string(), at(4, 13, 30), at(4, 14, 0))).unwrap();
+        schedule.sort_by_time();
+
+        // 30분 미루면 Write가 점심에 걸리므로 점심 뒤로, Review와 Code는 그 뒤에 차례로 이어짐
+        schedule.shift_range(0, 30).unwrap();
+        let times = |s: &Schedule, title: &str| {
+            let task = s.tasks.iter().find(|t| t.title == title).unwrap();
+            (task.start_time, task.end_time)
+        };
+        assert_eq!(times(&schedule, "Lunch"), (at(4, 12, 0), at(4, 13, 0)));
+        assert_eq!(times(&schedule, "Write"), (at(4, 13, 0), at(4, 13, 45)));
+        assert_eq!(times(&schedule, "Review"), (at(4, 13, 45), at(4, 14, 15)));
+        assert_eq!(times(&schedule, "Code"), (at(4, 14, 15), at(4, 14, 45)));
+        assert_eq!(schedule.tasks[0].id, lunch_id);
+        assert!(!schedule.has_overlaps());
+
+        // 재배치도 점심 시간을 비워 둠 (Code는 빼고 확인)
+        schedule.tasks.retain(|t| t.title != "Code");
+        schedule.rebalance(at(4, 11, 0), at(4, 15, 0)).unwrap();
+        assert_eq!(times(&schedule, "Lunch"), (at(4, 12, 0), at(4, 13, 0)));
+        assert_eq!(times(&schedule, "Write"), (at(4, 11, 0), at(4, 11, 45)));
+        assert_eq!(times(&schedule, "Review"), (at(4, 14, 30), at(4, 15, 0)));
+        assert!(!schedule.has_overlaps());
+
+        // 남은 75분은 점심을 건너뛰어 끝남
+        assert_eq!(schedule.projected_finish_time(at(4, 11, 30)), Some(at(4, 13, 45)));
+    }
 }
@@ -1,6 +1,8 @@
 use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 
+use super::{Schedule, TaskStatus};
+
 /// 하루 통계
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DailyStats {
@@ -38,8 +40,31 @@ impl DailyStats {
             break_time_minutes: 0,
         }
     }
+
+    /// 스케줄 기준으로 완료율, 정확도, 작업 수, 집중 시간 갱신 (휴식 시간은 유지)
+    pub fn update_from(&mut self, schedule: &Schedule) {
+        self.completion_rate = schedule.completion_rate();
+        self.time_accuracy = schedule.time_accuracy();
+        self.total_tasks = schedule.tasks.len();
+        self.completed_tasks = schedule
+            .tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Completed)
+            .count();
+
+        // 집중 시간 계산 (완료된 작업의 실제 시간 합)
+        self.focus_time_minutes = schedule
+            .tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Completed)
+            .filter_map(|t| t.actual_duration_minutes)
+            .sum();
+    }
 }
 
+/// Streak 성공으로 간주하는 최소 완료율 (%)
+pub const STREAK_THRESHOLD: f64 = 70.0;
+
 /// Streak 정보
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StreakInfo {
@@ -67,7 +92,7 @@ impl StreakInfo {
         let now = Local::now();
 
         // 70% 이상 완료 시 성공으로 간주
-        if completion_rate >= 70.0 {
+        if completion_rate >= STREAK_THRESHOLD {
             self.current_streak += 1;
 
             if self.current_streak > self.best_streak {
@@ -93,10 +118,61 @@ impl Default for StreakInfo {
     }
 }
 
+/// 최소제곱 직선의 기울기 (x가 한 단위 늘 때 y 변화량)
+///
+/// 점이 2개 미만이거나 x가 모두 같으면 0입니다.
+pub fn linear_trend(points: &[(f64, f64)]) -> f64 {
+    if points.len() < 2 {
+        return 0.0;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let (cov, var) = points.iter().fold((0.0, 0.0), |(cov, var), (x, y)| {
+        (cov + (x - mean_x) * (y - mean_y), var + (x - mean_x).powi(2))
+    });
+    if var == 0.0 {
+        0.0
+    } else {
+        cov / var
+    }
+}
+
+/// 직전 `window`개 값의 이동 평균 (앞쪽은 있는 값만으로 평균)
+pub fn moving_average(values: &[f64], window: usize) -> Vec<f64> {
+    let window = window.max(1);
+    (0..values.len())
+        .map(|i| {
+            let slice = &values[(i + 1).saturating_sub(window)..=i];
+            slice.iter().sum::<f64>() / slice.len() as f64
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_linear_trend() {
+        let rising: Vec<(f64, f64)> = (0..5).map(|i| (i as f64, 60.0 + 2.0 * i as f64)).collect();
+        assert!((linear_trend(&rising) - 2.0).abs() < 1e-9);
+
+        let falling = [(0.0, 90.0), (2.0, 80.0), (3.0, 75.0)];
+        assert!(linear_trend(&falling) < 0.0);
+
+        assert_eq!(linear_trend(&[(1.0, 50.0)]), 0.0);
+        assert_eq!(linear_trend(&[(1.0, 50.0), (1.0, 70.0)]), 0.0);
+    }
+
+    #[test]
+    fn test_moving_average() {
+        assert_eq!(moving_average(&[10.0, 20.0, 30.0, 40.0], 2), vec![10.0, 15.0, 25.0, 35.0]);
+        assert_eq!(moving_average(&[10.0, 20.0], 0), vec![10.0, 20.0]);
+        assert!(moving_average(&[], 3).is_empty());
+    }
+
     #[test]
     fn test_daily_stats_creation() {
         let stats = DailyStats::new(Local::now());
@@ -1,7 +1,18 @@
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Datelike, Local, NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::time::resolve_local;
+
+/// 마감 임박으로 표시하는 기준 (분)
+pub const DEADLINE_WARNING_MINUTES: i64 = 60;
+
+/// 집중도 점수 범위 (1~10)
+pub const FOCUS_SCORE_RANGE: std::ops::RangeInclusive<u8> = 1..=10;
+
+/// 이보다 긴 실제 시간은 잘못 기록된 값으로 봄 (분)
+pub const MAX_PLAUSIBLE_ACTUAL_MINUTES: i64 = 24 * 60;
+
 /// Task 상태
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TaskStatus {
@@ -15,6 +26,149 @@ pub enum TaskStatus {
     Paused,
     /// 건너뜀
     Skipped,
+    /// 외부 요인으로 막힘 (다른 사람 대기 등)
+    Blocked,
+}
+
+impl TaskStatus {
+    /// 색 없이도 구분되는 ASCII 기호 (접근성 모드용)
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Pending => "[ ]",
+            Self::InProgress => "[>]",
+            Self::Completed => "[x]",
+            Self::Paused => "[=]",
+            Self::Skipped => "[-]",
+            Self::Blocked => "[!]",
+        }
+    }
+
+    /// 상태 이름
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::InProgress => "in progress",
+            Self::Completed => "done",
+            Self::Paused => "paused",
+            Self::Skipped => "skipped",
+            Self::Blocked => "blocked",
+        }
+    }
+}
+
+/// 작업 우선순위 (낮음 < 보통 < 높음 < 긴급)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Priority {
+    /// 낮음
+    Low,
+    /// 보통 (기본)
+    #[default]
+    Medium,
+    /// 높음
+    High,
+    /// 긴급
+    Critical,
+}
+
+impl Priority {
+    /// "low", "medium", "high", "critical" 파싱 (대소문자 무시)
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            "critical" => Ok(Self::Critical),
+            _ => Err(format!("Invalid priority: {} (use low, medium, high or critical)", s)),
+        }
+    }
+
+    /// 제목 앞에 붙이는 표시 (보통은 표시 없음)
+    pub fn marker(self) -> &'static str {
+        match self {
+            Self::Low => "↓",
+            Self::Medium => "",
+            Self::High => "!",
+            Self::Critical => "!!",
+        }
+    }
+}
+
+/// 반복 규칙
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Recurrence {
+    /// 매일
+    Daily,
+    /// 월~금
+    Weekdays,
+    /// 지정한 요일마다
+    Weekly(Vec<Weekday>),
+}
+
+impl Recurrence {
+    /// "daily", "weekdays" 또는 "mon,wed,fri" 형식 파싱
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "daily" => Ok(Self::Daily),
+            "weekdays" => Ok(Self::Weekdays),
+            days => {
+                let weekdays = days
+                    .split(',')
+                    .map(|day| {
+                        day.trim()
+                            .parse::<Weekday>()
+                            .map_err(|_| format!("Invalid repeat rule: {} (use daily, weekdays or mon,wed,...)", s))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Self::Weekly(weekdays))
+            }
+        }
+    }
+
+    /// 해당 날짜에 반복되는지
+    pub fn occurs_on(&self, date: NaiveDate) -> bool {
+        match self {
+            Self::Daily => true,
+            Self::Weekdays => !matches!(date.weekday(), Weekday::Sat | Weekday::Sun),
+            Self::Weekly(days) => days.contains(&date.weekday()),
+        }
+    }
+}
+
+/// 작업을 실제로 한 구간 하나 (시작~일시정지/완료)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkSession {
+    /// 시작 시각
+    pub start: DateTime<Local>,
+
+    /// 끝난 시각 (진행 중이면 None)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<DateTime<Local>>,
+}
+
+impl WorkSession {
+    /// 구간 길이 (초, 진행 중이면 `now`까지)
+    pub fn seconds_at(&self, now: DateTime<Local>) -> i64 {
+        (self.end.unwrap_or(now) - self.start).num_seconds().max(0)
+    }
+}
+
+/// 작업 중 방해받은 기록 하나 (`sched interrupt`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interruption {
+    /// 방해받은 시각
+    pub at: DateTime<Local>,
+
+    /// 무엇 때문인지 (예: "Slack ping")
+    pub reason: String,
+}
+
+/// 작업 하나의 상태를 저장해 둔 것 (`Task::snapshot`, `Task::restore_from`)
+///
+/// ID를 뺀 모든 필드를 담으므로 저장소를 거치지 않고 실험한 변경을 되돌릴 수 있습니다.
+#[derive(Debug, Clone)]
+pub struct TaskSnapshot {
+    task: Task,
 }
 
 /// 하나의 작업 (Task)
@@ -50,6 +204,46 @@ pub struct Task {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
 
+    /// 관련 문서/이슈 링크
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+
+    /// 막힌 이유 (Blocked 시)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_reason: Option<String>,
+
+    /// 마감 시각 (예약한 시간 블록과 별개인 실제 기한)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<DateTime<Local>>,
+
+    /// 직접 입력한 진행률 (0.0 ~ 1.0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manual_progress: Option<f64>,
+
+    /// 완료 시 스스로 매긴 집중도 (1~10)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub focus_score: Option<u8>,
+
+    /// 여러 작업이 진행 중일 때 현재 작업으로 보여줄지
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub focused: bool,
+
+    /// 옮길 수 없는 고정 시간 (점심, 출퇴근 등) - 재배치/미루기에서 그대로 둠
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub fixed: bool,
+
+    /// 우선순위 (예전 파일에는 없으므로 기본값 보통)
+    #[serde(default)]
+    pub priority: Priority,
+
+    /// 현재 일시정지(또는 막힘)가 시작된 시각
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused_at: Option<DateTime<Local>>,
+
+    /// 지금까지 누적된 일시정지 시간 (분)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub paused_minutes: i64,
+
     /// 실제 시작된 시간 (InProgress 시)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub actual_start_time: Option<DateTime<Local>>,
@@ -58,13 +252,37 @@ pub struct Task {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub actual_end_time: Option<DateTime<Local>>,
 
+    /// 실제로 작업한 구간들 (시작/재개마다 하나씩)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sessions: Vec<WorkSession>,
+
+    /// 작업 중 방해받은 기록 (시간순)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub interruptions: Vec<Interruption>,
+
     /// 사용자 정의 Pomodoro 시간 (분, 기본값 25분)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_pomodoro_duration: Option<u32>,
 
+    /// 사용자 정의 긴 휴식 주기 (몇 번째 Pomodoro마다, 기본값 4)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_long_break_every: Option<u32>,
+
     /// Pomodoro session (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pomodoro: Option<super::pomodoro::PomodoroSession>,
+
+    /// 반복 규칙
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<Recurrence>,
+
+    /// 같은 반복 작업의 인스턴스를 묶는 ID (처음 만든 작업의 ID)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence_id: Option<String>,
+
+    /// 이 날짜 이후로는 반복하지 않음 (해당 날짜 포함)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence_until: Option<NaiveDate>,
 }
 
 impl Task {
@@ -86,23 +304,95 @@ impl Task {
             status: TaskStatus::Pending,
             tags: Vec::new(),
             notes: None,
+            link: None,
+            blocked_reason: None,
+            deadline: None,
+            manual_progress: None,
+            focus_score: None,
+            focused: false,
+            fixed: false,
+            priority: Priority::default(),
+            paused_at: None,
+            paused_minutes: 0,
+            sessions: Vec::new(),
+            interruptions: Vec::new(),
             actual_start_time: None,
             actual_end_time: None,
             custom_pomodoro_duration: None,
+            custom_long_break_every: None,
             pomodoro: None,
+            recurrence: None,
+            recurrence_id: None,
+            recurrence_until: None,
         }
     }
 
+    /// 현재 상태 스냅샷
+    pub fn snapshot(&self) -> TaskSnapshot {
+        TaskSnapshot { task: self.clone() }
+    }
+
+    /// 스냅샷 시점의 상태로 되돌림 (ID는 유지)
+    pub fn restore_from(&mut self, snapshot: TaskSnapshot) {
+        let id = std::mem::take(&mut self.id);
+        *self = snapshot.task;
+        self.id = id;
+    }
+
+    /// 반복 작업이 해당 날짜에 생겨야 하는지 (종료일 포함)
+    pub fn is_due_on(&self, date: NaiveDate) -> bool {
+        let Some(recurrence) = &self.recurrence else {
+            return false;
+        };
+
+        recurrence.occurs_on(date) && self.recurrence_until.map_or(true, |until| date <= until)
+    }
+
+    /// 같은 시각으로 `date`에 옮긴 새 작업 (새 ID, `Pending`, 실제 기록 없음)
+    ///
+    /// 자정을 넘기는 작업은 종료도 같은 날 수만큼 뒤로 옮겨 길이를 유지합니다.
+    /// 제목, 태그, 메모, 링크, 우선순위, 포모도로 설정, 고정 여부만 복사합니다.
+    pub fn clone_to_date(&self, date: NaiveDate) -> Task {
+        let offset = date - self.start_time.date_naive();
+        let shift = |time: DateTime<Local>| resolve_local(time.naive_local() + offset);
+
+        let mut task = Task::new(self.title.clone(), shift(self.start_time), shift(self.end_time));
+        task.tags = self.tags.clone();
+        task.notes = self.notes.clone();
+        task.link = self.link.clone();
+        task.custom_pomodoro_duration = self.custom_pomodoro_duration;
+        task.custom_long_break_every = self.custom_long_break_every;
+        task.fixed = self.fixed;
+        task.priority = self.priority;
+        task
+    }
+
+    /// 같은 시간대로 `date`에 새로 만든 반복 인스턴스
+    pub fn recurrence_instance(&self, date: NaiveDate) -> Task {
+        let mut task = self.clone_to_date(date);
+        task.recurrence = self.recurrence.clone();
+        task.recurrence_id = self.recurrence_id.clone();
+        task.recurrence_until = self.recurrence_until;
+        task
+    }
+
     /// 작업 시작
     pub fn start(&mut self) {
+        let now = Local::now();
         self.status = TaskStatus::InProgress;
-        self.actual_start_time = Some(Local::now());
+        self.actual_start_time = Some(now);
+        self.paused_at = None;
+        self.paused_minutes = 0;
+        self.open_session(now);
 
         // Pomodoro 세션 시작
         if self.pomodoro.is_none() {
             let pomodoro_duration = self.custom_pomodoro_duration.unwrap_or(25);
             let mut session = super::pomodoro::PomodoroSession::new(self.estimated_duration_minutes);
             session.pomodoro_duration = pomodoro_duration;
+            if let Some(every) = self.custom_long_break_every {
+                session.long_break_every = every;
+            }
             // total_pomodoros를 custom duration 기준으로 재계산
             session.total_pomodoros = ((self.estimated_duration_minutes as f64 / pomodoro_duration as f64).ceil() as u32).max(1);
             session.start_pomodoro();
@@ -114,12 +404,19 @@ impl Task {
 
     /// 작업 일시정지
     pub fn pause(&mut self) {
+        self.pause_at(Local::now());
+    }
+
+    /// `at` 시각에 일시정지한 것으로 기록
+    pub fn pause_at(&mut self, at: DateTime<Local>) {
         if self.status == TaskStatus::InProgress {
             self.status = TaskStatus::Paused;
+            self.paused_at = Some(at);
+            self.close_session(at);
 
-            // Pomodoro도 일시정지 (current_start를 None으로)
+            // Pomodoro도 일시정지 (경과 시간은 누적해 둠)
             if let Some(ref mut session) = self.pomodoro {
-                session.current_start = None;
+                session.pause_pomodoro();
             }
         }
     }
@@ -127,49 +424,231 @@ impl Task {
     /// 작업 재개
     pub fn resume(&mut self) {
         if self.status == TaskStatus::Paused {
+            let now = Local::now();
             self.status = TaskStatus::InProgress;
+            self.end_pause(now);
+            self.open_session(now);
 
             // Pomodoro도 재개
             if let Some(ref mut session) = self.pomodoro {
-                session.start_pomodoro();
+                session.resume_pomodoro();
             }
         }
     }
 
     /// 작업 완료
     pub fn complete(&mut self) {
+        let now = Local::now();
         self.status = TaskStatus::Completed;
-        self.actual_end_time = Some(Local::now());
+        self.focused = false;
+        self.actual_end_time = Some(now);
+        self.end_pause(now);
+        self.close_session(now);
+
+        // 처음 시작부터 구간을 기록했으면 구간 합계가 실제 소요 시간
+        if let Some(seconds) = self.session_seconds(now) {
+            self.actual_duration_minutes = Some(seconds / 60);
+            return;
+        }
 
-        // 실제 소요 시간 계산
+        // 실제 소요 시간 계산 (시계가 뒤로 가면 0으로)
         if let Some(start) = self.actual_start_time {
             let end = self.actual_end_time.unwrap();
-            self.actual_duration_minutes = Some((end - start).num_minutes());
+            let minutes = (end - start).num_minutes();
+            if minutes < 0 {
+                log::warn!(
+                    "'{}' ended before it started ({} min); recording 0 min",
+                    self.title,
+                    minutes
+                );
+            }
+            self.actual_duration_minutes = Some(minutes.max(0));
         }
     }
 
-    /// 작업 건너뛰기
+    /// 방해 기록 추가 (`at` 시각)
+    pub fn log_interruption(&mut self, reason: String, at: DateTime<Local>) {
+        self.interruptions.push(Interruption { at, reason });
+    }
+
+    /// 작업 건너뛰기 (진행 중이면 열린 작업 구간을 닫음)
     pub fn skip(&mut self) {
+        if self.status == TaskStatus::InProgress {
+            self.close_session(Local::now());
+        }
         self.status = TaskStatus::Skipped;
     }
 
-    /// 경과 시간 (분)
-    pub fn elapsed_minutes(&self) -> Option<i64> {
-        if let Some(start) = self.actual_start_time {
+    /// 외부 요인으로 막힘 처리 (진행 중이면 Pomodoro도 일시정지)
+    pub fn block(&mut self, reason: Option<String>) {
+        if let Some(ref mut session) = self.pomodoro {
+            session.pause_pomodoro();
+        }
+        if self.status == TaskStatus::InProgress {
             let now = Local::now();
-            Some((now - start).num_minutes())
+            self.paused_at = Some(now);
+            self.close_session(now);
+        }
+        self.status = TaskStatus::Blocked;
+        self.blocked_reason = reason;
+    }
+
+    /// 태그 이름 변경 (이미 새 태그가 있으면 옛 태그만 제거), 바뀌었으면 true
+    pub fn rename_tag(&mut self, old: &str, new: &str) -> bool {
+        if old == new {
+            return false;
+        }
+        let Some(index) = self.tags.iter().position(|tag| tag == old) else {
+            return false;
+        };
+
+        if self.tags.iter().any(|tag| tag == new) {
+            self.tags.remove(index);
         } else {
-            None
+            self.tags[index] = new.to_string();
+        }
+        true
+    }
+
+    /// 막힘 해제 (시작한 적 있으면 Paused, 아니면 Pending)
+    pub fn unblock(&mut self) {
+        if self.status == TaskStatus::Blocked {
+            self.status = if self.actual_start_time.is_some() {
+                TaskStatus::Paused
+            } else {
+                TaskStatus::Pending
+            };
+            self.blocked_reason = None;
+        }
+    }
+
+    /// 기록된 실제 시간 (분), 종료가 시작보다 앞서면 음수
+    pub fn recorded_duration_minutes(&self) -> Option<i64> {
+        match (self.actual_start_time, self.actual_end_time) {
+            (Some(start), Some(end)) if end < start => Some((end - start).num_minutes()),
+            _ => self.actual_duration_minutes,
+        }
+    }
+
+    /// 믿을 수 있는 실제 시간 (음수이거나 하루를 넘으면 None)
+    pub fn plausible_actual_minutes(&self) -> Option<i64> {
+        self.recorded_duration_minutes()
+            .filter(|minutes| (0..=MAX_PLAUSIBLE_ACTUAL_MINUTES).contains(minutes))
+    }
+
+    /// 예정 시각 대비 실제 시작 지연 (분, 늦으면 양수, 일찍이면 음수), 시작 안 했으면 None
+    pub fn start_delay_minutes(&self) -> Option<i64> {
+        self.actual_start_time
+            .map(|actual| (actual - self.start_time).num_minutes())
+    }
+
+    /// 경과 시간 (분), 시계가 시작 시각보다 뒤로 가 있으면 None
+    pub fn elapsed_minutes(&self) -> Option<i64> {
+        let start = self.actual_start_time?;
+        let now = Local::now();
+        if now < start {
+            log::debug!(
+                "Clock is behind the start of '{}' ({}); ignoring elapsed time",
+                self.title,
+                start.format("%Y-%m-%d %H:%M")
+            );
+            return None;
+        }
+        Some((now - start).num_minutes())
+    }
+
+    /// 진행률 직접 설정 (0.0 ~ 1.0)
+    pub fn set_progress(&mut self, fraction: f64) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err(format!("Progress must be between 0 and 1, got {}", fraction));
+        }
+        self.manual_progress = Some(fraction);
+        Ok(())
+    }
+
+    /// 새 작업 구간 시작 (열린 구간이 있으면 먼저 닫음)
+    fn open_session(&mut self, now: DateTime<Local>) {
+        self.close_session(now);
+        self.sessions.push(WorkSession { start: now, end: None });
+    }
+
+    /// 열린 작업 구간을 `at`에 닫음 (시작보다 앞이면 시작 시각으로)
+    pub fn close_session(&mut self, at: DateTime<Local>) {
+        if let Some(session) = self.sessions.last_mut().filter(|s| s.end.is_none()) {
+            session.end = Some(at.max(session.start));
+        }
+    }
+
+    /// 작업 구간 합계 (초)
+    ///
+    /// 구간 기록이 처음 시작부터 있지 않으면 (예전 데이터) None입니다.
+    pub fn session_seconds(&self, now: DateTime<Local>) -> Option<i64> {
+        let first = self.sessions.first()?;
+        if Some(first.start) != self.actual_start_time {
+            return None;
+        }
+        Some(self.sessions.iter().map(|s| s.seconds_at(now)).sum())
+    }
+
+    /// 진행 중 일시정지 상태를 끝내고 그 시간을 누적
+    fn end_pause(&mut self, now: DateTime<Local>) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.paused_minutes += (now - paused_at).num_minutes().max(0);
         }
     }
 
+    /// `now` 기준 실제 작업한 시간 (분, 일시정지 시간 제외)
+    pub fn active_minutes_at(&self, now: DateTime<Local>) -> Option<i64> {
+        let start = self.actual_start_time?;
+        let end = self.actual_end_time.unwrap_or(now);
+        let current_pause = self.paused_at.map_or(0, |p| (end - p).num_minutes().max(0));
+
+        Some(((end - start).num_minutes() - self.paused_minutes - current_pause).max(0))
+    }
+
+    /// 진행률 (0.0 ~ 1.0)
+    ///
+    /// 직접 입력한 값이 있으면 그 값을, 없으면 일시정지를 뺀 작업 시간 / 예상 시간을 씁니다.
+    pub fn progress_ratio(&self) -> f64 {
+        self.progress_ratio_at(Local::now())
+    }
+
+    /// `now` 기준 진행률 (0.0 ~ 1.0)
+    pub fn progress_ratio_at(&self, now: DateTime<Local>) -> f64 {
+        if let Some(manual) = self.manual_progress {
+            return manual.clamp(0.0, 1.0);
+        }
+        if self.status == TaskStatus::Completed {
+            return 1.0;
+        }
+        if self.estimated_duration_minutes <= 0 {
+            return 0.0;
+        }
+
+        let active = self.active_minutes_at(now).unwrap_or(0);
+        (active as f64 / self.estimated_duration_minutes as f64).clamp(0.0, 1.0)
+    }
+
+    /// 마감이 `within` 이내로 다가왔거나 지났는데 완료되지 않았는지
+    pub fn is_near_deadline(&self, now: DateTime<Local>, within: chrono::Duration) -> bool {
+        self.status != TaskStatus::Completed
+            && self.deadline.is_some_and(|deadline| deadline - now <= within)
+    }
+
+    /// 지금 마감 임박 표시가 필요한지 (`DEADLINE_WARNING_MINUTES` 기준)
+    pub fn is_deadline_urgent(&self) -> bool {
+        self.is_near_deadline(Local::now(), chrono::Duration::minutes(DEADLINE_WARNING_MINUTES))
+    }
+
     /// 예상 시간 초과 여부
     pub fn is_overdue(&self) -> bool {
-        if let Some(elapsed) = self.elapsed_minutes() {
-            elapsed > self.estimated_duration_minutes
-        } else {
-            false
-        }
+        self.is_overdue_with_grace(0)
+    }
+
+    /// 경과 시간이 예상 시간 + `grace_minutes`를 넘었는지
+    pub fn is_overdue_with_grace(&self, grace_minutes: u32) -> bool {
+        self.elapsed_minutes()
+            .is_some_and(|elapsed| elapsed > self.estimated_duration_minutes + grace_minutes as i64)
     }
 
     /// 현재 진행 중인지
@@ -178,11 +657,60 @@ impl Task {
     }
 }
 
+fn is_zero(value: &i64) -> bool {
+    *value == 0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::at;
     use chrono::Duration;
 
+    #[test]
+    fn test_restore_from_snapshot() {
+        let start = Local::now() - Duration::hours(2);
+        let mut task = Task::new("Estimate".to_string(), start, start + Duration::hours(1));
+        task.tags = vec!["work".to_string()];
+        let snapshot = task.snapshot();
+
+        task.start();
+        task.complete();
+        task.estimated_duration_minutes = 90;
+        task.end_time = start + Duration::minutes(90);
+        task.tags.clear();
+        assert_eq!(task.status, TaskStatus::Completed);
+        assert!(task.actual_duration_minutes.is_some());
+
+        let id = task.id.clone();
+        task.restore_from(snapshot);
+        assert_eq!(task.id, id);
+        assert_eq!(task.status, TaskStatus::Pending);
+        assert_eq!(task.estimated_duration_minutes, 60);
+        assert_eq!(task.end_time, start + Duration::hours(1));
+        assert_eq!(task.actual_start_time, None);
+        assert_eq!(task.actual_duration_minutes, None);
+        assert!(task.sessions.is_empty());
+        assert!(task.pomodoro.is_none());
+        assert_eq!(task.tags, ["work"]);
+    }
+
+    #[test]
+    fn test_rename_tag() {
+        let start = Local::now();
+        let mut task = Task::new("Review".to_string(), start, start + Duration::hours(1));
+        task.tags = vec!["proj-x".to_string(), "code".to_string()];
+
+        assert!(!task.rename_tag("proj-x", "proj-x"));
+        assert_eq!(task.tags, ["proj-x", "code"]);
+
+        assert!(task.rename_tag("proj-x", "code"));
+        assert_eq!(task.tags, ["code"]);
+        assert!(task.rename_tag("code", "acme"));
+        assert_eq!(task.tags, ["acme"]);
+        assert!(!task.rename_tag("missing", "acme"));
+    }
+
     #[test]
     fn test_task_creation() {
         let start = Local::now();
@@ -211,6 +739,52 @@ mod tests {
         assert!(task.actual_duration_minutes.is_some());
     }
 
+    #[test]
+    fn test_elapsed_minutes_ignores_future_start() {
+        let start = Local::now();
+        let mut task = Task::new("Test".to_string(), start, start + Duration::hours(1));
+        task.start();
+        assert_eq!(task.elapsed_minutes(), Some(0));
+
+        task.actual_start_time = Some(Local::now() + Duration::hours(2));
+        assert_eq!(task.elapsed_minutes(), None);
+    }
+
+    #[test]
+    fn test_complete_clamps_backwards_clock() {
+        let start = Local::now();
+        let mut task = Task::new("Test".to_string(), start, start + Duration::hours(1));
+        task.start();
+        task.actual_start_time = Some(Local::now() + Duration::minutes(30));
+
+        task.complete();
+        assert_eq!(task.actual_duration_minutes, Some(0));
+        assert!(task.recorded_duration_minutes().unwrap() < 0);
+        assert_eq!(task.plausible_actual_minutes(), None);
+    }
+
+    #[test]
+    fn test_work_sessions_sum_into_actual_duration() {
+        let start = Local::now();
+        let mut task = Task::new("Test".to_string(), start, start + Duration::hours(2));
+        task.start();
+        task.pause();
+        task.resume();
+        assert_eq!(task.sessions.len(), 2);
+        assert!(task.sessions[0].end.is_some());
+        assert!(task.sessions[1].end.is_none());
+
+        // 30분 작업 → 1시간 쉼 → 20분 작업
+        let first = Local::now() - Duration::minutes(110);
+        task.actual_start_time = Some(first);
+        task.sessions[0] = WorkSession { start: first, end: Some(first + Duration::minutes(30)) };
+        task.sessions[1].start = Local::now() - Duration::minutes(20);
+
+        task.complete();
+        assert!(task.sessions.iter().all(|s| s.end.is_some()));
+        assert_eq!(task.actual_duration_minutes, Some(50));
+    }
+
     #[test]
     fn test_task_pause_resume() {
         let start = Local::now();
@@ -224,4 +798,173 @@ mod tests {
         task.resume();
         assert_eq!(task.status, TaskStatus::InProgress);
     }
+
+    #[test]
+    fn test_task_link_serialization() {
+        let start = Local::now();
+        let mut task = Task::new("Test".to_string(), start, start + Duration::hours(1));
+
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(!json.contains("\"link\""));
+
+        task.link = Some("https://example.com/issue/1".to_string());
+        let json = serde_json::to_string(&task).unwrap();
+        let loaded: Task = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.link.as_deref(), Some("https://example.com/issue/1"));
+    }
+
+    #[test]
+    fn test_priority_serialization_and_default() {
+        let start = Local::now();
+        let mut task = Task::new("Ship".to_string(), start, start + Duration::hours(1));
+        task.priority = Priority::parse("High").unwrap();
+
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"priority\":\"High\""));
+
+        // 예전 파일에는 priority가 없음
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value.as_object_mut().unwrap().remove("priority");
+        let old: Task = serde_json::from_value(value).unwrap();
+        assert_eq!(old.priority, Priority::Medium);
+
+        assert!(Priority::Critical > Priority::High && Priority::Low < Priority::Medium);
+        assert!(Priority::parse("urgent").is_err());
+    }
+
+    #[test]
+    fn test_manual_progress() {
+        let start = Local::now();
+        let mut task = Task::new("Long".to_string(), start, start + Duration::hours(4));
+        task.start();
+
+        task.set_progress(0.4).unwrap();
+        assert_eq!(task.progress_ratio(), 0.4);
+        assert!(task.set_progress(1.5).is_err());
+
+        let json = serde_json::to_string(&task).unwrap();
+        let restored: Task = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.manual_progress, Some(0.4));
+        assert_eq!(restored.progress_ratio(), 0.4);
+    }
+
+    #[test]
+    fn test_progress_ratio_from_elapsed_excludes_pauses() {
+        let now = Local::now();
+        let mut task = Task::new("Write".to_string(), now, now + Duration::hours(1));
+        task.status = TaskStatus::InProgress;
+        task.actual_start_time = Some(now - Duration::minutes(40));
+        assert_eq!(task.progress_ratio_at(now), 40.0 / 60.0);
+
+        task.paused_minutes = 10;
+        assert_eq!(task.progress_ratio_at(now), 0.5);
+
+        // 지금 일시정지 중인 시간도 빠짐
+        task.status = TaskStatus::Paused;
+        task.paused_at = Some(now - Duration::minutes(15));
+        assert_eq!(task.progress_ratio_at(now), 15.0 / 60.0);
+    }
+
+    #[test]
+    fn test_progress_ratio_prefers_manual_progress() {
+        let now = Local::now();
+        let mut task = Task::new("Write".to_string(), now, now + Duration::hours(1));
+        task.status = TaskStatus::InProgress;
+        task.actual_start_time = Some(now - Duration::minutes(45));
+
+        task.set_progress(0.2).unwrap();
+        assert_eq!(task.progress_ratio_at(now), 0.2);
+    }
+
+    #[test]
+    fn test_progress_ratio_clamped_at_one() {
+        let now = Local::now();
+        let mut task = Task::new("Write".to_string(), now, now + Duration::hours(1));
+        task.status = TaskStatus::InProgress;
+        task.actual_start_time = Some(now - Duration::minutes(90));
+
+        assert_eq!(task.progress_ratio_at(now), 1.0);
+        assert_eq!(Task::new("Later".to_string(), now, now + Duration::hours(1)).progress_ratio_at(now), 0.0);
+    }
+
+    #[test]
+    fn test_overdue_grace_boundary() {
+        let now = Local::now();
+        let mut task = Task::new("Review".to_string(), now, now + Duration::minutes(60));
+        task.status = TaskStatus::InProgress;
+
+        task.actual_start_time = Some(now - Duration::minutes(65));
+        assert!(task.is_overdue());
+        assert!(!task.is_overdue_with_grace(5));
+
+        task.actual_start_time = Some(now - Duration::minutes(66));
+        assert!(task.is_overdue_with_grace(5));
+    }
+
+    #[test]
+    fn test_clone_to_date_keeps_clock_times() {
+        let mut task = Task::new(
+            "Night shift".to_string(),
+            at(3, 22, 0),
+            at(4, 2, 0),
+        );
+        task.tags = vec!["work".to_string()];
+
+        let copy = task.clone_to_date(NaiveDate::from_ymd_opt(2025, 11, 10).unwrap());
+        assert_eq!(copy.start_time, at(10, 22, 0));
+        assert_eq!(copy.end_time, at(11, 2, 0));
+        assert_eq!(copy.estimated_duration_minutes, 240);
+        assert_eq!(copy.tags, task.tags);
+        assert_ne!(copy.id, task.id);
+    }
+
+    #[test]
+    fn test_clone_to_date_resets_progress() {
+        let mut task = Task::new("Report".to_string(), at(3, 9, 0), at(3, 10, 0));
+        task.start();
+        task.complete();
+        task.focused = true;
+
+        let copy = task.clone_to_date(NaiveDate::from_ymd_opt(2025, 11, 4).unwrap());
+        assert_eq!(copy.status, TaskStatus::Pending);
+        assert!(copy.actual_start_time.is_none());
+        assert!(copy.actual_end_time.is_none());
+        assert!(copy.actual_duration_minutes.is_none());
+        assert!(copy.pomodoro.is_none());
+        assert!(!copy.focused);
+        assert_eq!(copy.start_time, at(4, 9, 0));
+    }
+
+    #[test]
+    fn test_status_symbols_are_distinct() {
+        let statuses = [
+            TaskStatus::Pending,
+            TaskStatus::InProgress,
+            TaskStatus::Completed,
+            TaskStatus::Paused,
+            TaskStatus::Skipped,
+            TaskStatus::Blocked,
+        ];
+        let symbols: std::collections::HashSet<_> = statuses.iter().map(|s| s.symbol()).collect();
+        let labels: std::collections::HashSet<_> = statuses.iter().map(|s| s.label()).collect();
+        assert_eq!(symbols.len(), statuses.len());
+        assert_eq!(labels.len(), statuses.len());
+        assert!(symbols.iter().all(|s| s.is_ascii()));
+    }
+
+    #[test]
+    fn test_recurrence_parse_and_occurs_on() {
+        let saturday = NaiveDate::from_ymd_opt(2025, 11, 8).unwrap();
+        let monday = NaiveDate::from_ymd_opt(2025, 11, 10).unwrap();
+
+        assert!(Recurrence::parse("daily").unwrap().occurs_on(saturday));
+        assert!(!Recurrence::parse("Weekdays").unwrap().occurs_on(saturday));
+
+        let weekly = Recurrence::parse("mon, wed").unwrap();
+        assert_eq!(weekly, Recurrence::Weekly(vec![Weekday::Mon, Weekday::Wed]));
+        assert!(weekly.occurs_on(monday));
+        assert!(!weekly.occurs_on(saturday));
+
+        assert!(Recurrence::parse("sometimes").is_err());
+    }
 }
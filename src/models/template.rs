@@ -0,0 +1,105 @@
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+
+use super::{Schedule, Task};
+use crate::time::resolve_local;
+
+/// 템플릿에 포함된 작업 (날짜 없이 시각만 저장)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateTask {
+    /// 작업 제목
+    pub title: String,
+
+    /// 시작 시각
+    pub start_time: NaiveTime,
+
+    /// 종료 시각
+    pub end_time: NaiveTime,
+
+    /// 태그
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// 메모
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+/// 재사용 가능한 하루 스케줄 템플릿
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleTemplate {
+    /// 템플릿 이름
+    pub name: String,
+
+    /// 작업 목록
+    pub tasks: Vec<TemplateTask>,
+}
+
+impl ScheduleTemplate {
+    /// 기존 스케줄의 작업 시각/제목으로 템플릿 생성
+    pub fn from_schedule(name: String, schedule: &Schedule) -> Self {
+        let tasks = schedule
+            .tasks
+            .iter()
+            .map(|t| TemplateTask {
+                title: t.title.clone(),
+                start_time: t.start_time.time(),
+                end_time: t.end_time.time(),
+                tags: t.tags.clone(),
+                notes: t.notes.clone(),
+            })
+            .collect();
+
+        Self { name, tasks }
+    }
+
+    /// 스케줄 날짜에 맞춰 템플릿 작업 추가
+    ///
+    /// 기존 작업과 충돌하는 작업은 건너뜁니다. (추가된 수, 건너뛴 수)를 반환합니다.
+    pub fn apply_to(&self, schedule: &mut Schedule) -> (usize, usize) {
+        let date = schedule.date.date_naive();
+        let mut added = 0;
+        let mut skipped = 0;
+
+        for template_task in &self.tasks {
+            let mut task = Task::new(
+                template_task.title.clone(),
+                resolve_local(date.and_time(template_task.start_time)),
+                resolve_local(date.and_time(template_task.end_time)),
+            );
+            task.tags = template_task.tags.clone();
+            task.notes = template_task.notes.clone();
+
+            if schedule.add_task(task).is_ok() {
+                added += 1;
+            } else {
+                skipped += 1;
+            }
+        }
+
+        schedule.sort_by_time();
+        (added, skipped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::at;
+
+    #[test]
+    fn test_template_round_trip() {
+        let mut source = Schedule::new(at(1, 0, 0));
+        source
+            .add_task(Task::new("Deep work".to_string(), at(1, 9, 0), at(1, 11, 0)))
+            .unwrap();
+
+        let template = ScheduleTemplate::from_schedule("work".to_string(), &source);
+
+        let mut target = Schedule::new(at(3, 0, 0));
+        assert_eq!(template.apply_to(&mut target), (1, 0));
+        assert_eq!(target.tasks[0].title, "Deep work");
+        assert_eq!(target.tasks[0].start_time, at(3, 9, 0));
+        assert_eq!(target.tasks[0].estimated_duration_minutes, 120);
+    }
+}
@@ -0,0 +1,143 @@
+use chrono::{Local, NaiveDate};
+use serde::Serialize;
+use serde_json::json;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::models::DailyStats;
+use crate::storage::Storage;
+use crate::time::resolve_local;
+
+/// `sched serve` 기본 주소 (같은 기기에서만 접근 가능)
+pub const DEFAULT_BIND: &str = "127.0.0.1";
+
+/// `sched serve` 기본 포트
+pub const DEFAULT_PORT: u16 = 8080;
+
+/// 읽기 전용 JSON API 서버 시작 (Ctrl+C로 종료할 때까지 실행)
+pub fn serve(storage: &dyn Storage, bind: &str, port: u16) -> anyhow::Result<()> {
+    let server = Server::http((bind, port))
+        .map_err(|e| anyhow::anyhow!("Failed to listen on {}:{}: {}", bind, port, e))?;
+    println!("Serving read-only API on http://{}:{} (Ctrl+C to stop)", bind, port);
+    println!("Endpoints: /today, /date/YYYY-MM-DD, /stats, /streak");
+
+    for request in server.incoming_requests() {
+        if let Err(e) = handle_request(storage, request) {
+            log::warn!("Failed to answer request: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// 요청 하나에 응답 (GET만 허용)
+pub fn handle_request(storage: &dyn Storage, request: Request) -> std::io::Result<()> {
+    let (status, body) = if *request.method() == Method::Get {
+        route(storage, request.url())
+    } else {
+        (405, json!({ "error": "Read-only API: only GET is supported" }))
+    };
+    log::debug!("{} {} -> {}", request.method(), request.url(), status);
+
+    let header = Header::from_bytes("Content-Type", "application/json")
+        .expect("static header is valid");
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(header);
+    request.respond(response)
+}
+
+/// 경로에 맞는 (상태 코드, JSON 본문)
+pub fn route(storage: &dyn Storage, url: &str) -> (u16, serde_json::Value) {
+    let path = url.split('?').next().unwrap_or_default().trim_end_matches('/');
+
+    let result = match path {
+        "/today" => storage.load_today().map(|s| found(s, "No schedule for today")),
+        "/stats" => today_stats(storage).map(|s| found(s, "No schedule for today")),
+        "/streak" => storage.load_streak().map(|s| found(Some(s), "")),
+        _ => match path.strip_prefix("/date/") {
+            Some(ymd) => match NaiveDate::parse_from_str(ymd, "%Y-%m-%d") {
+                Ok(date) => storage
+                    .load_schedule(resolve_local(date.and_hms_opt(0, 0, 0).unwrap()))
+                    .map(|s| found(s, &format!("No schedule for {}", ymd))),
+                Err(_) => Ok((400, json!({ "error": format!("Invalid date: {} (use YYYY-MM-DD)", ymd) }))),
+            },
+            None => Ok((404, json!({ "error": format!("Unknown endpoint: {}", path) }))),
+        },
+    };
+
+    result.unwrap_or_else(|e| (500, json!({ "error": e.to_string() })))
+}
+
+/// 저장된 오늘 통계를 오늘 스케줄로 갱신한 값 (저장하지 않음)
+fn today_stats(storage: &dyn Storage) -> anyhow::Result<Option<DailyStats>> {
+    let Some(schedule) = storage.load_today()? else {
+        return Ok(None);
+    };
+    let mut stats = storage
+        .load_stats(Local::now())?
+        .unwrap_or_else(|| DailyStats::new(Local::now()));
+    stats.update_from(&schedule);
+    Ok(Some(stats))
+}
+
+/// 값이 있으면 200, 없으면 404
+fn found<T: Serialize>(value: Option<T>, missing: &str) -> (u16, serde_json::Value) {
+    match value.map(serde_json::to_value) {
+        Some(Ok(json)) => (200, json),
+        Some(Err(e)) => (500, json!({ "error": e.to_string() })),
+        None => (404, json!({ "error": missing })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Schedule, Task};
+    use crate::storage::JsonStorage;
+    use chrono::Duration;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    #[test]
+    fn test_serves_today_over_http() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let mut schedule = Schedule::today();
+        let start = Local::now();
+        schedule
+            .add_task(Task::new("Write report".to_string(), start, start + Duration::hours(1)))
+            .unwrap();
+        storage.save_schedule(&schedule).unwrap();
+
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        let handle = std::thread::spawn(move || {
+            let request = server.recv().unwrap();
+            handle_request(&storage, request).unwrap();
+        });
+
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        stream
+            .write_all(b"GET /today HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        handle.join().unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.contains("application/json"));
+        let body = response.split("\r\n\r\n").nth(1).unwrap();
+        let loaded: Schedule = serde_json::from_str(body).unwrap();
+        assert_eq!(loaded.tasks[0].title, "Write report");
+    }
+
+    #[test]
+    fn test_route_errors() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        assert_eq!(route(&storage, "/today").0, 404);
+        assert_eq!(route(&storage, "/date/2025-13-01").0, 400);
+        assert_eq!(route(&storage, "/tasks").0, 404);
+        assert_eq!(route(&storage, "/streak").0, 200);
+    }
+}
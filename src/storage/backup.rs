@@ -0,0 +1,129 @@
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// 아카이브 안에서 데이터 디렉토리 파일이 위치하는 경로
+const DATA_PREFIX: &str = "data";
+
+/// 아카이브 안의 설정 파일 경로
+const CONFIG_ENTRY: &str = "config/config.toml";
+
+/// 데이터 디렉토리 전체(와 설정 파일)를 zip으로 묶기
+///
+/// 아카이브에 담긴 파일 수를 반환합니다.
+pub fn create_backup(data_dir: &Path, config_path: Option<&Path>, out: &Path) -> anyhow::Result<usize> {
+    let mut zip = ZipWriter::new(File::create(out)?);
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut count = 0;
+
+    for path in collect_files(data_dir)? {
+        let relative = path.strip_prefix(data_dir)?;
+        let name = Path::new(DATA_PREFIX).join(relative);
+        zip.start_file(name.to_string_lossy().replace('\\', "/"), options)?;
+        zip.write_all(&fs::read(&path)?)?;
+        count += 1;
+    }
+
+    if let Some(config) = config_path.filter(|p| p.exists()) {
+        zip.start_file(CONFIG_ENTRY, options)?;
+        zip.write_all(&fs::read(config)?)?;
+        count += 1;
+    }
+
+    zip.finish()?;
+    Ok(count)
+}
+
+/// 백업 아카이브를 데이터 디렉토리(와 설정 파일 경로)에 풀기
+///
+/// 아카이브에 있는 파일만 덮어쓰며, 복원한 파일 수를 반환합니다.
+pub fn restore_backup(archive: &Path, data_dir: &Path, config_path: Option<&Path>) -> anyhow::Result<usize> {
+    let mut zip = ZipArchive::new(File::open(archive)?)?;
+    let mut count = 0;
+
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i)?;
+        if entry.is_dir() {
+            continue;
+        }
+
+        // 아카이브 밖으로 벗어나는 경로는 무시
+        let Some(name) = entry.enclosed_name().map(Path::to_path_buf) else {
+            continue;
+        };
+
+        let target = if name == Path::new(CONFIG_ENTRY) {
+            match config_path {
+                Some(path) => path.to_path_buf(),
+                None => continue,
+            }
+        } else if let Ok(relative) = name.strip_prefix(DATA_PREFIX) {
+            data_dir.join(relative)
+        } else {
+            continue;
+        };
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut contents = Vec::new();
+        entry.read_to_end(&mut contents)?;
+        fs::write(&target, contents)?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// 디렉토리 아래의 모든 파일 (하위 디렉토리 포함)
+fn collect_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(collect_files(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Schedule, Task};
+    use crate::storage::{JsonStorage, Storage};
+    use chrono::{Duration, Local};
+
+    #[test]
+    fn test_backup_round_trip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_dir = temp_dir.path().join("data");
+        let storage = JsonStorage::with_path(data_dir.clone()).unwrap();
+
+        let mut schedule = Schedule::today();
+        let start = Local::now();
+        schedule
+            .add_task(Task::new("Keep me".to_string(), start, start + Duration::hours(1)))
+            .unwrap();
+        storage.save_schedule(&schedule).unwrap();
+
+        let archive = temp_dir.path().join("backup.zip");
+        assert!(create_backup(&data_dir, None, &archive).unwrap() >= 2);
+
+        fs::remove_dir_all(&data_dir).unwrap();
+        restore_backup(&archive, &data_dir, None).unwrap();
+
+        let restored = JsonStorage::with_path(data_dir).unwrap();
+        let schedule = restored.load_today().unwrap().unwrap();
+        assert_eq!(schedule.tasks[0].title, "Keep me");
+    }
+}
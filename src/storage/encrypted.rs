@@ -0,0 +1,282 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use chrono::{DateTime, Local};
+
+use crate::models::{DailyStats, Schedule, ScheduleEvent, ScheduleTemplate, StreakInfo, Task, WeeklyGoals};
+
+use super::json_storage::{parse_schedule, settle_today};
+use super::{IntegrityReport, JsonStorage, Storage};
+
+/// 암호화 패스프레이즈를 읽는 환경 변수
+pub const PASSPHRASE_ENV: &str = "SCHEDULER_PASSPHRASE";
+
+/// 암호화된 스케줄 파일 앞에 붙는 헤더
+const MAGIC: &[u8] = b"SCHEDENC1\n";
+
+/// 키 유도용 솔트 파일 이름 (데이터 디렉토리 안)
+const SALT_FILE: &str = "encryption.salt";
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// 스케줄 파일을 암호화해서 저장하는 Storage
+///
+/// 스케줄 외의 데이터는 그대로 `JsonStorage`에 맡깁니다.
+/// 헤더가 없는 기존 평문 파일은 그대로 읽고, 다음 저장 때 암호화됩니다.
+pub struct EncryptedStorage {
+    inner: JsonStorage,
+    cipher: ChaCha20Poly1305,
+}
+
+impl EncryptedStorage {
+    /// 패스프레이즈에서 argon2로 키를 유도해 생성 (솔트는 데이터 디렉토리에 한 번 만들어 둠)
+    pub fn new(inner: JsonStorage, passphrase: &str) -> anyhow::Result<Self> {
+        let salt_path = inner.data_dir().join(SALT_FILE);
+        let salt = if salt_path.exists() {
+            fs::read(&salt_path)?
+        } else {
+            let mut salt = vec![0u8; SALT_LEN];
+            OsRng.fill_bytes(&mut salt);
+            if !inner.is_dry_run() {
+                fs::write(&salt_path, &salt)?;
+            }
+            salt
+        };
+
+        let mut key = [0u8; 32];
+        Argon2::default()
+            .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+            .map_err(|e| anyhow::anyhow!("Failed to derive encryption key: {}", e))?;
+
+        Ok(Self {
+            inner,
+            cipher: ChaCha20Poly1305::new(&key.into()),
+        })
+    }
+
+    /// `SCHEDULER_PASSPHRASE`가 있으면 암호화 Storage로 감싸고, 없으면 그대로 씀
+    ///
+    /// CLI, TUI, 데몬, 데스크톱 앱이 모두 이 함수로 Storage를 만듭니다.
+    pub fn from_env(inner: JsonStorage) -> anyhow::Result<Box<dyn Storage>> {
+        Self::from_passphrase(inner, std::env::var(PASSPHRASE_ENV).ok().as_deref())
+    }
+
+    /// 비밀번호가 있으면 암호화 Storage로 감싸고, 없거나 비어 있으면 그대로 씀
+    pub fn from_passphrase(inner: JsonStorage, passphrase: Option<&str>) -> anyhow::Result<Box<dyn Storage>> {
+        match passphrase {
+            Some(passphrase) if !passphrase.is_empty() => Ok(Box::new(Self::new(inner, passphrase)?)),
+            _ => Ok(Box::new(inner)),
+        }
+    }
+
+    /// 감싼 JsonStorage
+    pub fn inner(&self) -> &JsonStorage {
+        &self.inner
+    }
+
+    /// 헤더 + nonce + 암호문
+    fn encrypt(&self, plaintext: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext)
+            .map_err(|_| anyhow::anyhow!("Failed to encrypt schedule"))?;
+
+        let mut out = Vec::with_capacity(MAGIC.len() + NONCE_LEN + ciphertext.len());
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+
+    /// 헤더가 없으면 평문으로 보고 그대로 반환
+    fn decrypt(&self, bytes: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+        let Some(body) = bytes.strip_prefix(MAGIC) else {
+            return Ok(bytes);
+        };
+        if body.len() < NONCE_LEN {
+            anyhow::bail!("Encrypted schedule file is truncated");
+        }
+
+        let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow::anyhow!("Failed to decrypt schedule (wrong {}?)", PASSPHRASE_ENV))
+    }
+}
+
+impl Storage for EncryptedStorage {
+    fn save_schedule(&self, schedule: &Schedule) -> anyhow::Result<PathBuf> {
+        let plaintext = self.inner.encode_schedule(schedule)?;
+        self.inner.write_schedule_bytes(schedule, &self.encrypt(&plaintext)?)
+    }
+
+    fn load_schedule(&self, date: DateTime<Local>) -> anyhow::Result<Option<Schedule>> {
+        self.inner
+            .read_schedule_bytes(date)?
+            .map(|bytes| parse_schedule(&self.decrypt(bytes)?))
+            .transpose()
+    }
+
+    fn load_today(&self) -> anyhow::Result<Option<Schedule>> {
+        Ok(self.load_current()?.and_then(settle_today))
+    }
+
+    fn load_current(&self) -> anyhow::Result<Option<Schedule>> {
+        self.inner
+            .read_current_bytes()?
+            .map(|bytes| parse_schedule(&self.decrypt(bytes)?))
+            .transpose()
+    }
+
+    fn save_stats(&self, stats: &DailyStats) -> anyhow::Result<PathBuf> {
+        self.inner.save_stats(stats)
+    }
+
+    fn load_stats(&self, date: DateTime<Local>) -> anyhow::Result<Option<DailyStats>> {
+        self.inner.load_stats(date)
+    }
+
+    fn save_streak(&self, streak: &StreakInfo) -> anyhow::Result<PathBuf> {
+        self.inner.save_streak(streak)
+    }
+
+    fn load_streak(&self) -> anyhow::Result<StreakInfo> {
+        self.inner.load_streak()
+    }
+
+    fn append_event(&self, event: ScheduleEvent) -> anyhow::Result<()> {
+        self.inner.append_event(event)
+    }
+
+    fn load_events(&self) -> anyhow::Result<Vec<ScheduleEvent>> {
+        self.inner.load_events()
+    }
+
+    fn save_template(&self, template: &ScheduleTemplate) -> anyhow::Result<PathBuf> {
+        self.inner.save_template(template)
+    }
+
+    fn load_template(&self, name: &str) -> anyhow::Result<Option<ScheduleTemplate>> {
+        self.inner.load_template(name)
+    }
+
+    fn list_templates(&self) -> anyhow::Result<Vec<String>> {
+        self.inner.list_templates()
+    }
+
+    fn save_goals(&self, goals: &WeeklyGoals) -> anyhow::Result<PathBuf> {
+        self.inner.save_goals(goals)
+    }
+
+    fn load_goals(&self, week: &str) -> anyhow::Result<Option<WeeklyGoals>> {
+        self.inner.load_goals(week)
+    }
+
+    fn save_recurring(&self, sources: &[Task]) -> anyhow::Result<PathBuf> {
+        let plaintext = serde_json::to_vec_pretty(sources)?;
+        self.inner.write_recurring_bytes(&self.encrypt(&plaintext)?)
+    }
+
+    fn load_recurring(&self) -> anyhow::Result<Vec<Task>> {
+        match self.inner.read_recurring_bytes()? {
+            Some(bytes) => Ok(serde_json::from_slice(&self.decrypt(bytes)?)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn save_last_tick(&self, at: DateTime<Local>) -> anyhow::Result<PathBuf> {
+        self.inner.save_last_tick(at)
+    }
+
+    fn load_last_tick(&self) -> anyhow::Result<Option<DateTime<Local>>> {
+        self.inner.load_last_tick()
+    }
+
+    fn verify(&self) -> anyhow::Result<IntegrityReport> {
+        self.inner.verify_with(&|bytes| self.decrypt(bytes))
+    }
+
+    fn repair(&self) -> anyhow::Result<IntegrityReport> {
+        self.inner.repair_with(&|bytes| self.decrypt(bytes))
+    }
+
+    fn data_dir(&self) -> &Path {
+        self.inner.data_dir()
+    }
+
+    fn is_dry_run(&self) -> bool {
+        self.inner.is_dry_run()
+    }
+
+    fn is_encrypted(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Task;
+    use chrono::Duration;
+
+    #[test]
+    fn test_encrypted_round_trip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let inner = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let storage = EncryptedStorage::new(inner, "correct horse").unwrap();
+
+        let mut schedule = Schedule::today();
+        let start = Local::now();
+        let mut task = Task::new("Therapy".to_string(), start, start + Duration::hours(1));
+        task.notes = Some("private notes".to_string());
+        schedule.add_task(task).unwrap();
+        storage.save_schedule(&schedule).unwrap();
+
+        let path = temp_dir
+            .path()
+            .join("history")
+            .join(format!("{}.json", schedule.date.format("%Y-%m-%d")));
+        let on_disk = fs::read(path).unwrap();
+        assert!(on_disk.starts_with(MAGIC));
+        let text = String::from_utf8_lossy(&on_disk);
+        assert!(!text.contains("Therapy") && !text.contains("private notes"));
+
+        let loaded = storage.load_today().unwrap().unwrap();
+        assert_eq!(loaded.tasks[0].notes.as_deref(), Some("private notes"));
+
+        let inner = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let wrong = EncryptedStorage::new(inner, "wrong").unwrap();
+        assert!(wrong.load_schedule(schedule.date).is_err());
+    }
+
+    #[test]
+    fn test_verify_reads_encrypted_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let inner = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let storage = EncryptedStorage::new(inner, "correct horse").unwrap();
+        storage.save_schedule(&Schedule::today()).unwrap();
+
+        assert!(storage.verify().unwrap().is_clean());
+
+        let inner = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let wrong = EncryptedStorage::new(inner, "wrong").unwrap();
+        assert!(!wrong.verify().unwrap().corrupt_files.is_empty());
+    }
+
+    #[test]
+    fn test_reads_plaintext_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let plain = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let schedule = Schedule::today();
+        plain.save_schedule(&schedule).unwrap();
+
+        let storage = EncryptedStorage::new(plain, "secret").unwrap();
+        assert!(storage.load_schedule(schedule.date).unwrap().is_some());
+    }
+}
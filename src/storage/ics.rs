@@ -0,0 +1,290 @@
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+use crate::models::{Schedule, Task};
+use crate::time::resolve_local;
+
+/// iCalendar 한 줄 최대 길이 (옥텟)
+const MAX_LINE_OCTETS: usize = 75;
+
+/// ics 가져오기 결과
+#[derive(Debug, Default)]
+pub struct IcsImport {
+    /// 변환된 작업들
+    pub tasks: Vec<Task>,
+    /// 종일 일정 등 변환할 수 없어 건너뛴 VEVENT 수
+    pub skipped: usize,
+}
+
+/// 스케줄을 VCALENDAR 문자열로 변환 (작업당 VEVENT 하나)
+pub fn export_ics(schedule: &Schedule) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//scheduler//sched//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    let stamp = format_utc(Local::now());
+
+    for task in &schedule.tasks {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@scheduler", task.id));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!("DTSTART:{}", format_utc(task.start_time)));
+        lines.push(format!("DTEND:{}", format_utc(task.end_time)));
+        lines.push(format!("SUMMARY:{}", escape(&task.title)));
+        if let Some(notes) = &task.notes {
+            lines.push(format!("DESCRIPTION:{}", escape(notes)));
+        }
+        if !task.tags.is_empty() {
+            let tags: Vec<String> = task.tags.iter().map(|t| escape(t)).collect();
+            lines.push(format!("CATEGORIES:{}", tags.join(",")));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold(line))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+/// VCALENDAR 문자열에서 VEVENT들을 작업으로 변환
+///
+/// TZID가 붙은 시각은 로컬 시각으로 간주하고, 종일 일정과 시각이 없는 일정은 건너뜁니다.
+pub fn import_ics(content: &str) -> IcsImport {
+    let mut result = IcsImport::default();
+    let mut event: Option<Vec<(String, String, String)>> = None;
+
+    for line in unfold(content) {
+        let Some((head, value)) = line.split_once(':') else {
+            continue;
+        };
+        let (name, params) = match head.split_once(';') {
+            Some((name, params)) => (name.to_uppercase(), params.to_uppercase()),
+            None => (head.to_uppercase(), String::new()),
+        };
+
+        match (name.as_str(), value) {
+            ("BEGIN", "VEVENT") => event = Some(Vec::new()),
+            ("END", "VEVENT") => {
+                if let Some(props) = event.take() {
+                    match event_to_task(&props) {
+                        Some(task) => result.tasks.push(task),
+                        None => result.skipped += 1,
+                    }
+                }
+            }
+            _ => {
+                if let Some(props) = event.as_mut() {
+                    props.push((name, params, value.to_string()));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn event_to_task(props: &[(String, String, String)]) -> Option<Task> {
+    let get = |key: &str| props.iter().find(|(name, _, _)| name == key);
+
+    let (_, start_params, start_value) = get("DTSTART")?;
+    let start = parse_date_time(start_params, start_value)?;
+    let end = match get("DTEND") {
+        Some((_, params, value)) => parse_date_time(params, value)?,
+        None => start + parse_duration(&get("DURATION")?.2)?,
+    };
+
+    if end <= start {
+        return None;
+    }
+
+    let title = get("SUMMARY")
+        .map(|(_, _, v)| unescape(v))
+        .unwrap_or_else(|| "Untitled".to_string());
+
+    let mut task = Task::new(title, start, end);
+    task.notes = get("DESCRIPTION").map(|(_, _, v)| unescape(v));
+    task.tags = props
+        .iter()
+        .filter(|(name, _, _)| name == "CATEGORIES")
+        .flat_map(|(_, _, v)| split_list(v))
+        .collect();
+
+    Some(task)
+}
+
+/// DTSTART/DTEND 값 파싱 (종일 일정은 None)
+fn parse_date_time(params: &str, value: &str) -> Option<DateTime<Local>> {
+    if params.contains("VALUE=DATE") && !params.contains("VALUE=DATE-TIME") {
+        return None;
+    }
+
+    let value = value.trim();
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(Utc.from_utc_datetime(&naive).with_timezone(&Local));
+    }
+
+    if NaiveDate::parse_from_str(value, "%Y%m%d").is_ok() {
+        return None;
+    }
+
+    // 플로팅 시각 또는 TZID 지정 시각: 로컬 시각으로 취급
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    Some(resolve_local(naive))
+}
+
+/// 간단한 DURATION 값 파싱 (예: PT1H30M, PT45M)
+fn parse_duration(value: &str) -> Option<chrono::Duration> {
+    let rest = value.trim().strip_prefix("PT")?;
+    let mut minutes = 0i64;
+    let mut number = String::new();
+
+    for ch in rest.chars() {
+        match ch {
+            '0'..='9' => number.push(ch),
+            'H' => minutes += number.drain(..).as_str().parse::<i64>().ok()? * 60,
+            'M' => minutes += number.drain(..).as_str().parse::<i64>().ok()?,
+            'S' => number.clear(),
+            _ => return None,
+        }
+    }
+
+    (minutes > 0).then(|| chrono::Duration::minutes(minutes))
+}
+
+fn format_utc(time: DateTime<Local>) -> String {
+    time.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => out.push('\n'),
+                Some(other) => out.push(other),
+                None => {}
+            }
+        } else {
+            out.push(ch);
+        }
+    }
+
+    out
+}
+
+/// 이스케이프되지 않은 쉼표로 나누기 (CATEGORIES)
+fn split_list(value: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut escaped = false;
+
+    for ch in value.chars() {
+        if escaped {
+            current.push('\\');
+            current.push(ch);
+            escaped = false;
+        } else if ch == '\\' {
+            escaped = true;
+        } else if ch == ',' {
+            items.push(unescape(&current));
+            current.clear();
+        } else {
+            current.push(ch);
+        }
+    }
+    items.push(unescape(&current));
+
+    items.into_iter().filter(|s| !s.trim().is_empty()).collect()
+}
+
+/// 75옥텟을 넘는 줄을 접기 (이어지는 줄은 공백으로 시작)
+fn fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+
+    for ch in line.chars() {
+        if width + ch.len_utf8() > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(ch);
+        width += ch.len_utf8();
+    }
+
+    out
+}
+
+/// 접힌 줄 펼치기
+fn unfold(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for raw in content.lines() {
+        let raw = raw.trim_end_matches('\r');
+        match (raw.strip_prefix(' ').or_else(|| raw.strip_prefix('\t')), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{at, day_schedule};
+
+    #[test]
+    fn test_ics_round_trip() {
+        let mut schedule = day_schedule();
+
+        let mut review = Task::new("Review, then merge; ship".to_string(), at(4, 9, 0), at(4, 10, 30));
+        review.notes = Some("Line one\nLine two".to_string());
+        review.tags = vec!["work".to_string(), "code".to_string()];
+        schedule.add_task(review).unwrap();
+        schedule
+            .add_task(Task::new("A".repeat(120), at(4, 13, 0), at(4, 14, 0)))
+            .unwrap();
+
+        let ics = export_ics(&schedule);
+        assert!(ics.lines().all(|l| l.len() <= MAX_LINE_OCTETS + 1));
+
+        let imported = import_ics(&ics);
+        assert_eq!(imported.skipped, 0);
+        assert_eq!(imported.tasks.len(), 2);
+
+        for (original, task) in schedule.tasks.iter().zip(&imported.tasks) {
+            assert_eq!(task.title, original.title);
+            assert_eq!(task.start_time, original.start_time);
+            assert_eq!(task.end_time, original.end_time);
+            assert_eq!(task.notes, original.notes);
+            assert_eq!(task.tags, original.tags);
+        }
+    }
+
+    #[test]
+    fn test_ics_import_skips_all_day_events() {
+        let ics = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nSUMMARY:Holiday\r\nDTSTART;VALUE=DATE:20251104\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nSUMMARY:Call\r\nDTSTART;TZID=Europe/Paris:20251104T090000\r\nDURATION:PT45M\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+
+        let imported = import_ics(ics);
+        assert_eq!(imported.skipped, 1);
+        assert_eq!(imported.tasks.len(), 1);
+        assert_eq!(imported.tasks[0].estimated_duration_minutes, 45);
+    }
+}
@@ -1,38 +1,105 @@
-use std::fs;
-use std::path::PathBuf;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use chrono::{DateTime, Local};
 use directories::ProjectDirs;
 
-use crate::models::{DailyStats, Schedule, StreakInfo};
+use crate::config::Config;
+use crate::error::SchedError;
+use crate::models::{
+    DailyStats, Schedule, ScheduleEvent, ScheduleTemplate, StreakInfo, Task, WeeklyGoals,
+};
 
-use super::Storage;
+use super::{IntegrityReport, Storage, StorageFormat};
+
+/// 데이터 디렉토리를 바꾸는 환경 변수
+pub const DATA_DIR_ENV: &str = "SCHEDULER_DATA_DIR";
+
+/// MessagePack 스케줄 파일 앞에 붙는 헤더
+const MSGPACK_MAGIC: &[u8] = b"SCHEDMP1\n";
 
 /// JSON 파일 기반 Storage
 pub struct JsonStorage {
     data_dir: PathBuf,
+    format: StorageFormat,
+    /// true면 파일을 바꾸지 않고 바꿀 파일만 알림
+    dry_run: bool,
+    /// dry-run으로 건너뛴 파일 (순서대로)
+    skipped_writes: Mutex<Vec<PathBuf>>,
 }
 
 impl JsonStorage {
-    /// 새 JsonStorage 생성
+    /// 새 JsonStorage 생성 (`SCHEDULER_DATA_DIR`가 있으면 그 경로 사용)
     pub fn new() -> anyhow::Result<Self> {
-        let project_dirs = ProjectDirs::from("com", "scheduler", "scheduler")
-            .ok_or_else(|| anyhow::anyhow!("Failed to determine project directory"))?;
-
-        let data_dir = project_dirs.data_dir().to_path_buf();
-
-        // 디렉토리 생성
-        fs::create_dir_all(&data_dir)?;
-        fs::create_dir_all(data_dir.join("history"))?;
+        let data_dir = match std::env::var_os(DATA_DIR_ENV) {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => ProjectDirs::from("com", "scheduler", "scheduler")
+                .ok_or_else(|| anyhow::anyhow!("Failed to determine project directory"))?
+                .data_dir()
+                .to_path_buf(),
+        };
+
+        Self::with_path(data_dir)
+    }
 
-        Ok(Self { data_dir })
+    /// 프로필을 골라 생성 (`None`이면 기본 데이터 디렉토리)
+    pub fn for_profile(config: &Config, profile: Option<&str>) -> anyhow::Result<Self> {
+        match profile {
+            Some(name) => Self::with_path(config.profile_dir(name)?.to_path_buf()),
+            None => Self::new(),
+        }
     }
 
     /// 커스텀 경로로 생성 (테스트용)
     pub fn with_path(path: PathBuf) -> anyhow::Result<Self> {
         fs::create_dir_all(&path)?;
         fs::create_dir_all(path.join("history"))?;
-        Ok(Self { data_dir: path })
+        fs::create_dir_all(path.join("templates"))?;
+        Ok(Self {
+            data_dir: path,
+            format: StorageFormat::default(),
+            dry_run: false,
+            skipped_writes: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// 파일을 쓰거나 지우는 대신 stderr에 알리기만 함 (`--dry-run`)
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// dry-run으로 쓰지 않은 파일 목록
+    pub fn skipped_writes(&self) -> Vec<PathBuf> {
+        self.skipped_writes.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+
+    /// dry-run이면 `path`에 하려던 작업을 알리고 기록한 뒤 true
+    fn skip_write(&self, action: &str, path: &Path) -> bool {
+        if !self.dry_run {
+            return false;
+        }
+        eprintln!("[dry-run] Would {} {}", action, path.display());
+        if let Ok(mut skipped) = self.skipped_writes.lock() {
+            skipped.push(path.to_path_buf());
+        }
+        true
+    }
+
+    /// dry-run이 아니면 파일 쓰기
+    fn write_file(&self, path: &Path, contents: impl AsRef<[u8]>) -> anyhow::Result<()> {
+        if !self.skip_write("write", path) {
+            fs::write(path, contents)?;
+        }
+        Ok(())
+    }
+
+    /// 스케줄을 저장할 형식 지정 (기본 JSON)
+    pub fn with_format(mut self, format: StorageFormat) -> Self {
+        self.format = format;
+        self
     }
 
     /// 날짜를 파일명으로 변환
@@ -61,64 +128,200 @@ impl JsonStorage {
     fn streak_path(&self) -> PathBuf {
         self.data_dir.join("streak.json")
     }
-}
 
-impl Storage for JsonStorage {
-    fn save_schedule(&self, schedule: &Schedule) -> anyhow::Result<()> {
-        // history에 저장
-        let history_path = self.schedule_path(schedule.date);
-        let json = serde_json::to_string_pretty(schedule)?;
-        fs::write(history_path, json)?;
+    /// 데몬이 마지막으로 확인한 시각 파일 경로
+    fn last_tick_path(&self) -> PathBuf {
+        self.data_dir.join("last_tick.json")
+    }
 
-        // current.json 업데이트 (오늘 날짜인 경우)
-        let today = Local::now().date_naive();
-        let schedule_date = schedule.date.date_naive();
+    /// 반복 작업 원본 파일 경로
+    fn recurring_path(&self) -> PathBuf {
+        self.data_dir.join("recurring.json")
+    }
 
-        if schedule_date == today {
-            let current_path = self.current_schedule_path();
-            let json = serde_json::to_string_pretty(schedule)?;
-            fs::write(current_path, json)?;
+    /// 템플릿 디렉토리
+    fn templates_dir(&self) -> PathBuf {
+        self.data_dir.join("templates")
+    }
+
+    /// 템플릿 파일 경로 (이름이 템플릿 디렉토리를 벗어나면 에러)
+    fn template_path(&self, name: &str) -> anyhow::Result<PathBuf> {
+        if name.trim().is_empty() || name.contains(['/', '\\']) || name.contains("..") {
+            return Err(SchedError::validation(format!("Invalid template name: '{}'", name)).into());
         }
+        Ok(self.templates_dir().join(format!("{}.json", name)))
+    }
 
-        Ok(())
+    /// 주간 목표 파일 경로 ("goals/2025-W45.json")
+    fn goals_path(&self, week: &str) -> PathBuf {
+        self.data_dir.join("goals").join(format!("{}.json", week))
     }
 
-    fn load_schedule(&self, date: DateTime<Local>) -> anyhow::Result<Option<Schedule>> {
-        let path = self.schedule_path(date);
+    /// 이벤트 로그 파일 경로 (JSON Lines)
+    fn events_path(&self) -> PathBuf {
+        self.data_dir.join("events.log")
+    }
 
-        if !path.exists() {
-            return Ok(None);
-        }
+    /// history 디렉토리의 파일 목록 (이름순)
+    fn history_files(&self) -> anyhow::Result<Vec<PathBuf>> {
+        let mut files: Vec<PathBuf> = fs::read_dir(self.data_dir.join("history"))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("json"))
+            .collect();
+        files.sort();
+        Ok(files)
+    }
 
-        let content = fs::read_to_string(path)?;
-        let schedule: Schedule = serde_json::from_str(&content)?;
-        Ok(Some(schedule))
+    /// 지정한 형식으로 스케줄 직렬화
+    pub(super) fn encode_schedule(&self, schedule: &Schedule) -> anyhow::Result<Vec<u8>> {
+        match self.format {
+            StorageFormat::Json => Ok(serde_json::to_vec_pretty(schedule)?),
+            StorageFormat::MessagePack => {
+                let mut bytes = MSGPACK_MAGIC.to_vec();
+                bytes.extend(rmp_serde::to_vec_named(schedule)?);
+                Ok(bytes)
+            }
+        }
     }
 
-    fn load_today(&self) -> anyhow::Result<Option<Schedule>> {
-        let path = self.current_schedule_path();
+    /// 스케줄 파일 내용을 history에 쓰고, 오늘 날짜면 current.json에도 씀
+    pub(super) fn write_schedule_bytes(&self, schedule: &Schedule, bytes: &[u8]) -> anyhow::Result<PathBuf> {
+        let history_path = self.schedule_path(schedule.date);
+        log::debug!("Saving schedule to {}", history_path.display());
+        self.write_file(&history_path, bytes)?;
 
-        if !path.exists() {
-            return Ok(None);
+        if schedule.date.date_naive() == Local::now().date_naive() {
+            self.write_file(&self.current_schedule_path(), bytes)?;
         }
 
-        let content = fs::read_to_string(path)?;
-        let schedule: Schedule = serde_json::from_str(&content)?;
+        Ok(history_path)
+    }
 
-        // 날짜 검증 (오늘이 아니면 None)
-        let today = Local::now().date_naive();
-        if schedule.date.date_naive() != today {
-            return Ok(None);
-        }
+    /// 해당 날짜 스케줄 파일 내용 (없으면 None)
+    pub(super) fn read_schedule_bytes(&self, date: DateTime<Local>) -> anyhow::Result<Option<Vec<u8>>> {
+        let path = self.schedule_path(date);
+        log::debug!("Loading schedule from {}", path.display());
+        read_bytes(&path)
+    }
+
+    /// current.json 내용 (없으면 None)
+    pub(super) fn read_current_bytes(&self) -> anyhow::Result<Option<Vec<u8>>> {
+        read_bytes(&self.current_schedule_path())
+    }
 
-        Ok(Some(schedule))
+    /// recurring.json 쓰기
+    pub(super) fn write_recurring_bytes(&self, bytes: &[u8]) -> anyhow::Result<PathBuf> {
+        let path = self.recurring_path();
+        self.write_file(&path, bytes)?;
+        Ok(path)
     }
 
-    fn save_stats(&self, stats: &DailyStats) -> anyhow::Result<()> {
+    /// recurring.json 내용 (없으면 None)
+    pub(super) fn read_recurring_bytes(&self) -> anyhow::Result<Option<Vec<u8>>> {
+        read_bytes(&self.recurring_path())
+    }
+
+}
+
+/// current.json에서 읽은 스케줄을 오늘 것으로 정리
+///
+/// 오늘 날짜가 아니면 None, 어제부터 진행 중인 작업은 메모리에서만 일시정지합니다.
+/// 저장은 오늘을 바꾸는 명령이 맡습니다.
+pub(super) fn settle_today(mut schedule: Schedule) -> Option<Schedule> {
+    let today = Local::now().date_naive();
+    if schedule.date.date_naive() != today {
+        log::debug!("current.json is for {}, not today", schedule.date.date_naive());
+        return None;
+    }
+
+    flag_conflicts(&mut schedule);
+    schedule.pause_stale_tasks(today);
+    Some(schedule)
+}
+
+/// 파일 내용 (없으면 None)
+fn read_bytes(path: &Path) -> anyhow::Result<Option<Vec<u8>>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    Ok(Some(fs::read(path)?))
+}
+
+/// 스케줄 파일 내용 디코딩 (MessagePack 헤더가 없으면 JSON)
+fn decode_schedule<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> anyhow::Result<T> {
+    match bytes.strip_prefix(MSGPACK_MAGIC) {
+        Some(body) => Ok(rmp_serde::from_slice(body)?),
+        None => Ok(serde_json::from_slice(bytes)?),
+    }
+}
+
+/// 스케줄 파싱 후 충돌 표시, 오래된 Pomodoro 정리
+pub(super) fn parse_schedule(bytes: &[u8]) -> anyhow::Result<Schedule> {
+    let mut schedule: Schedule = decode_schedule(bytes)?;
+    flag_conflicts(&mut schedule);
+    for title in schedule.abandon_all_stale_pomodoros(Local::now()) {
+        log::warn!("Cleared a stale pomodoro on '{}' (left running after a crash?)", title);
+    }
+    Ok(schedule)
+}
+
+/// 스케줄 파일 바이트를 평문으로 바꾸는 함수 (암호화 Storage는 복호화)
+pub(super) type ScheduleDecoder<'a> = &'a dyn Fn(Vec<u8>) -> anyhow::Result<Vec<u8>>;
+
+/// 스케줄 파일을 `T`로 읽을 수 있는지 (없으면 None)
+fn read_schedule_file<T: serde::de::DeserializeOwned>(path: &Path, decoder: ScheduleDecoder) -> Option<Result<T, ()>> {
+    if !path.exists() {
+        return None;
+    }
+    Some(
+        fs::read(path)
+            .ok()
+            .and_then(|bytes| decoder(bytes).ok())
+            .and_then(|bytes| decode_schedule(&bytes).ok())
+            .ok_or(()),
+    )
+}
+
+/// JSON 파일을 `T`로 읽을 수 있는지 (없으면 None)
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Option<Result<T, ()>> {
+    if !path.exists() {
+        return None;
+    }
+    Some(
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .ok_or(()),
+    )
+}
+
+impl Storage for JsonStorage {
+    fn save_schedule(&self, schedule: &Schedule) -> anyhow::Result<PathBuf> {
+        self.write_schedule_bytes(schedule, &self.encode_schedule(schedule)?)
+    }
+
+    fn load_schedule(&self, date: DateTime<Local>) -> anyhow::Result<Option<Schedule>> {
+        self.read_schedule_bytes(date)?
+            .map(|json| parse_schedule(&json))
+            .transpose()
+    }
+
+    fn load_today(&self) -> anyhow::Result<Option<Schedule>> {
+        Ok(self.load_current()?.and_then(settle_today))
+    }
+
+    fn load_current(&self) -> anyhow::Result<Option<Schedule>> {
+        self.read_current_bytes()?
+            .map(|json| parse_schedule(&json))
+            .transpose()
+    }
+
+    fn save_stats(&self, stats: &DailyStats) -> anyhow::Result<PathBuf> {
         let path = self.stats_path(stats.date);
         let json = serde_json::to_string_pretty(stats)?;
-        fs::write(path, json)?;
-        Ok(())
+        self.write_file(&path, json)?;
+        Ok(path)
     }
 
     fn load_stats(&self, date: DateTime<Local>) -> anyhow::Result<Option<DailyStats>> {
@@ -133,11 +336,11 @@ impl Storage for JsonStorage {
         Ok(Some(stats))
     }
 
-    fn save_streak(&self, streak: &StreakInfo) -> anyhow::Result<()> {
+    fn save_streak(&self, streak: &StreakInfo) -> anyhow::Result<PathBuf> {
         let path = self.streak_path();
         let json = serde_json::to_string_pretty(streak)?;
-        fs::write(path, json)?;
-        Ok(())
+        self.write_file(&path, json)?;
+        Ok(path)
     }
 
     fn load_streak(&self) -> anyhow::Result<StreakInfo> {
@@ -151,14 +354,262 @@ impl Storage for JsonStorage {
         let streak: StreakInfo = serde_json::from_str(&content)?;
         Ok(streak)
     }
+
+    fn append_event(&self, event: ScheduleEvent) -> anyhow::Result<()> {
+        if self.skip_write("append to", &self.events_path()) {
+            return Ok(());
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.events_path())?;
+        writeln!(file, "{}", serde_json::to_string(&event)?)?;
+        Ok(())
+    }
+
+    fn load_events(&self) -> anyhow::Result<Vec<ScheduleEvent>> {
+        let path = self.events_path();
+
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(path)?;
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Ok(serde_json::from_str(line)?))
+            .collect()
+    }
+
+    fn save_template(&self, template: &ScheduleTemplate) -> anyhow::Result<PathBuf> {
+        let path = self.template_path(&template.name)?;
+        let json = serde_json::to_string_pretty(template)?;
+        self.write_file(&path, json)?;
+        Ok(path)
+    }
+
+    fn load_template(&self, name: &str) -> anyhow::Result<Option<ScheduleTemplate>> {
+        let path = self.template_path(name)?;
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path)?;
+        let template: ScheduleTemplate = serde_json::from_str(&content)?;
+        Ok(Some(template))
+    }
+
+    fn list_templates(&self) -> anyhow::Result<Vec<String>> {
+        let mut names: Vec<String> = fs::read_dir(self.templates_dir())?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+                if path.extension().and_then(|e| e.to_str()) == Some("json") {
+                    path.file_stem().and_then(|s| s.to_str()).map(String::from)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    fn save_goals(&self, goals: &WeeklyGoals) -> anyhow::Result<PathBuf> {
+        let path = self.goals_path(&goals.week);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(goals)?;
+        self.write_file(&path, json)?;
+        Ok(path)
+    }
+
+    fn load_goals(&self, week: &str) -> anyhow::Result<Option<WeeklyGoals>> {
+        let path = self.goals_path(week);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path)?;
+        let goals: WeeklyGoals = serde_json::from_str(&content)?;
+        Ok(Some(goals))
+    }
+
+    fn verify(&self) -> anyhow::Result<IntegrityReport> {
+        self.verify_with(&|bytes| Ok(bytes))
+    }
+
+    fn repair(&self) -> anyhow::Result<IntegrityReport> {
+        self.repair_with(&|bytes| Ok(bytes))
+    }
+
+    fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    fn save_recurring(&self, sources: &[Task]) -> anyhow::Result<PathBuf> {
+        self.write_recurring_bytes(serde_json::to_string_pretty(sources)?.as_bytes())
+    }
+
+    fn load_recurring(&self) -> anyhow::Result<Vec<Task>> {
+        match self.read_recurring_bytes()? {
+            Some(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn save_last_tick(&self, at: DateTime<Local>) -> anyhow::Result<PathBuf> {
+        let path = self.last_tick_path();
+        self.write_file(&path, serde_json::to_string(&at)?)?;
+        Ok(path)
+    }
+
+    fn load_last_tick(&self) -> anyhow::Result<Option<DateTime<Local>>> {
+        let path = self.last_tick_path();
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+}
+
+impl JsonStorage {
+    /// 데이터 디렉토리 점검 (스케줄 파일은 `decoder`를 거쳐 읽음)
+    pub(super) fn verify_with(&self, decoder: ScheduleDecoder) -> anyhow::Result<IntegrityReport> {
+        let mut report = IntegrityReport::default();
+
+        for path in self.history_files()? {
+            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+            let readable = match stem.strip_suffix("_stats") {
+                Some(day) => {
+                    if !path.with_file_name(format!("{}.json", day)).exists() {
+                        report.orphaned_stats.push(path.clone());
+                    }
+                    read_json::<DailyStats>(&path).is_some_and(|r| r.is_ok())
+                }
+                None => read_schedule_file::<Schedule>(&path, decoder).is_some_and(|r| r.is_ok()),
+            };
+            if !readable {
+                report.corrupt_files.push(path);
+            }
+        }
+
+        let current_path = self.current_schedule_path();
+        let history_path = self.schedule_path(Local::now());
+        let current = read_schedule_file::<serde_json::Value>(&current_path, decoder);
+        let history = read_schedule_file::<serde_json::Value>(&history_path, decoder);
+
+        if matches!(current, Some(Err(()))) {
+            report.corrupt_files.push(current_path.clone());
+        }
+        for path in [self.streak_path(), self.last_tick_path()] {
+            if matches!(read_json::<serde_json::Value>(&path), Some(Err(()))) {
+                report.corrupt_files.push(path);
+            }
+        }
+
+        // 오늘 history가 있으면 current.json과 같아야 하고, 없으면 current.json이 오늘 것이면 안 됨
+        let today = self.date_to_filename(Local::now());
+        report.current_out_of_sync = match (current, history) {
+            (current, Some(Ok(history))) => current.and_then(Result::ok) != Some(history),
+            (Some(Ok(current)), None) => current
+                .get("date")
+                .and_then(|d| d.as_str())
+                .is_some_and(|date| date.starts_with(&today)),
+            _ => false,
+        };
+
+        Ok(report)
+    }
+
+    /// 점검 후 자동으로 고칠 수 있는 문제 수정 (파일은 내용 그대로 복사)
+    pub(super) fn repair_with(&self, decoder: ScheduleDecoder) -> anyhow::Result<IntegrityReport> {
+        let report = self.verify_with(decoder)?;
+
+        if report.current_out_of_sync {
+            let current_path = self.current_schedule_path();
+            let history_path = self.schedule_path(Local::now());
+            if history_path.exists() {
+                log::info!("Re-syncing {} from {}", current_path.display(), history_path.display());
+                if !self.skip_write("overwrite", &current_path) {
+                    fs::copy(&history_path, &current_path)?;
+                }
+            } else {
+                log::info!("Restoring {} from {}", history_path.display(), current_path.display());
+                if !self.skip_write("write", &history_path) {
+                    fs::copy(&current_path, &history_path)?;
+                }
+            }
+        }
+
+        for path in &report.orphaned_stats {
+            log::info!("Removing orphaned stats file {}", path.display());
+            if !self.skip_write("remove", path) {
+                fs::remove_file(path)?;
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+/// 겹치는 작업이 있으면 `has_conflicts` 표시
+fn flag_conflicts(schedule: &mut Schedule) {
+    schedule.has_conflicts = schedule.has_overlaps();
+    if schedule.has_conflicts {
+        log::warn!("Schedule for {} has overlapping tasks", schedule.date.date_naive());
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{at, day_schedule};
     use crate::models::Task;
     use chrono::Duration;
 
+    #[test]
+    fn test_for_profile_uses_profile_dir() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let work_dir = temp_dir.path().join("work");
+        let mut config = Config::default();
+        config.profiles.insert("work".to_string(), work_dir.clone());
+
+        let storage = JsonStorage::for_profile(&config, Some("work")).unwrap();
+        assert_eq!(storage.data_dir(), work_dir.as_path());
+        assert!(work_dir.join("history").is_dir());
+
+        assert!(JsonStorage::for_profile(&config, Some("home")).is_err());
+    }
+
+    #[test]
+    fn test_template_names_stay_inside_templates_dir() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        for name in ["../../x", "a/b", "a\\b", "..", "  "] {
+            let template = ScheduleTemplate { name: name.to_string(), tasks: vec![] };
+            assert!(storage.save_template(&template).is_err(), "{}", name);
+            assert!(storage.load_template(name).is_err(), "{}", name);
+        }
+        assert!(!temp_dir.path().join("x.json").exists());
+
+        let template = ScheduleTemplate { name: "work".to_string(), tasks: vec![] };
+        storage.save_template(&template).unwrap();
+        assert!(storage.load_template("work").unwrap().is_some());
+    }
+
     #[test]
     fn test_json_storage_schedule() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -181,6 +632,175 @@ mod tests {
         assert_eq!(loaded_schedule.tasks[0].title, "Test");
     }
 
+    #[test]
+    fn test_save_returns_written_path() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        let schedule = Schedule::new(Local::now() - Duration::days(3));
+        let path = storage.save_schedule(&schedule).unwrap();
+        assert!(path.exists());
+        assert!(path.starts_with(temp_dir.path()));
+        let date = schedule.date.format("%Y-%m-%d").to_string();
+        assert!(path.file_name().unwrap().to_str().unwrap().contains(&date));
+
+        let streak_path = storage.save_streak(&StreakInfo::new()).unwrap();
+        assert!(streak_path.exists());
+
+        // dry run이어도 쓰려던 경로를 알려줌
+        let dry = JsonStorage::with_path(temp_dir.path().join("dry")).unwrap().with_dry_run(true);
+        let skipped = dry.save_schedule(&schedule).unwrap();
+        assert!(!skipped.exists());
+        assert!(dry.skipped_writes().contains(&skipped));
+    }
+
+    #[test]
+    fn test_load_range_returns_days_in_order_with_stats() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let today = Local::now();
+
+        for days_ago in [1, 3, 2] {
+            let mut schedule = Schedule::new(today - Duration::days(days_ago));
+            let start = schedule.date;
+            let mut task = Task::new(format!("Day {}", days_ago), start, start + Duration::hours(1));
+            task.complete();
+            schedule.add_task(task).unwrap();
+            storage.save_schedule(&schedule).unwrap();
+        }
+
+        let from = (today - Duration::days(3)).date_naive();
+        let to = today.date_naive();
+        let schedules = storage.load_range(from, to).unwrap();
+        let titles: Vec<&str> = schedules.iter().map(|s| s.tasks[0].title.as_str()).collect();
+        assert_eq!(titles, ["Day 3", "Day 2", "Day 1"]);
+        assert!(schedules.iter().all(|s| s.completion_rate.is_some() && s.total_wasted.is_some()));
+
+        assert!(storage.load_range(to, from).is_err());
+        assert!(storage.load_range(from - Duration::days(400), to).is_err());
+    }
+
+    #[test]
+    fn test_messagepack_round_trip_and_size() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let json = JsonStorage::with_path(temp_dir.path().join("json")).unwrap();
+        let binary = JsonStorage::with_path(temp_dir.path().join("binary"))
+            .unwrap()
+            .with_format(StorageFormat::MessagePack);
+
+        let mut schedule = Schedule::today();
+        let start = Local::now();
+        for i in 0..20 {
+            let at = start + Duration::minutes(30 * i);
+            let mut task = Task::new(format!("Task {}", i), at, at + Duration::minutes(25));
+            task.tags = vec!["work".to_string()];
+            schedule.add_task(task).unwrap();
+        }
+        json.save_schedule(&schedule).unwrap();
+        binary.save_schedule(&schedule).unwrap();
+
+        let json_size = fs::metadata(json.schedule_path(schedule.date)).unwrap().len();
+        let binary_bytes = fs::read(binary.schedule_path(schedule.date)).unwrap();
+        assert!(binary_bytes.starts_with(MSGPACK_MAGIC));
+        assert!((binary_bytes.len() as u64) < json_size);
+
+        let loaded = binary.load_today().unwrap().unwrap();
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&schedule).unwrap()
+        );
+        assert!(binary.verify().unwrap().is_clean());
+    }
+
+    #[test]
+    fn test_reads_json_after_switching_format() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let schedule = Schedule::today();
+        storage.save_schedule(&schedule).unwrap();
+
+        let storage = storage.with_format(StorageFormat::MessagePack);
+        assert!(storage.load_schedule(schedule.date).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_load_schedule_flags_overlapping_tasks() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        let mut schedule = day_schedule();
+        // add_task를 거치지 않은 (외부에서 편집된) 겹치는 작업
+        schedule.tasks.push(Task::new("Standup".to_string(), at(4, 9, 0), at(4, 9, 30)));
+        schedule.tasks.push(Task::new("Review".to_string(), at(4, 9, 15), at(4, 10, 0)));
+        storage.save_schedule(&schedule).unwrap();
+
+        let loaded = storage.load_schedule(at(4, 0, 0)).unwrap().unwrap();
+        assert!(loaded.has_conflicts);
+
+        let json = fs::read_to_string(storage.schedule_path(at(4, 0, 0))).unwrap();
+        assert!(!json.contains("has_conflicts"));
+    }
+
+    #[test]
+    fn test_load_today_pauses_stale_task_in_memory() {
+        use crate::models::{PomodoroSession, TaskStatus};
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        let yesterday = Local::now() - Duration::days(1);
+        let mut schedule = Schedule::today();
+        let mut task = Task::new("Overnight".to_string(), Local::now(), Local::now() + Duration::hours(1));
+        task.status = TaskStatus::InProgress;
+        task.actual_start_time = Some(yesterday);
+        let mut session = PomodoroSession::new(60);
+        session.current_start = Some(yesterday);
+        task.pomodoro = Some(session);
+        schedule.add_task(task).unwrap();
+        storage.save_schedule(&schedule).unwrap();
+
+        let loaded = storage.load_today().unwrap().unwrap();
+        let task = &loaded.tasks[0];
+        assert_eq!(task.status, TaskStatus::Paused);
+        assert!(task.pomodoro.as_ref().unwrap().current_start.is_none());
+        // 시작한 날 자정에 일시정지한 것으로 기록되어 밤사이는 작업 시간에 들어가지 않음
+        let paused_at = task.paused_at.unwrap();
+        assert_eq!(paused_at.date_naive(), Local::now().date_naive());
+        assert_eq!(paused_at.time(), chrono::NaiveTime::MIN);
+        let active = task.active_minutes_at(Local::now()).unwrap();
+        assert!(active.abs_diff((paused_at - yesterday).num_minutes()) <= 1);
+
+        // 읽기만 하는 호출은 아무것도 쓰지 않음
+        assert!(storage.load_events().unwrap().is_empty());
+        let on_disk = storage.load_current().unwrap().unwrap();
+        assert_eq!(on_disk.tasks[0].status, TaskStatus::InProgress);
+    }
+
+    #[test]
+    fn test_load_clears_stale_pomodoro() {
+        use crate::models::{PomodoroSession, TaskStatus};
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        let now = Local::now();
+        let mut schedule = Schedule::today();
+        let mut task = Task::new("Crashed".to_string(), now, now + Duration::hours(1));
+        task.status = TaskStatus::InProgress;
+        task.actual_start_time = Some(now);
+        let mut session = PomodoroSession::new(60);
+        session.current_start = Some(now - Duration::hours(3));
+        task.pomodoro = Some(session);
+        schedule.add_task(task).unwrap();
+        storage.save_schedule(&schedule).unwrap();
+
+        let loaded = storage.load_schedule(schedule.date).unwrap().unwrap();
+        let session = loaded.tasks[0].pomodoro.as_ref().unwrap();
+        assert!(session.current_start.is_none());
+        assert!(session.remaining_minutes().is_none());
+        assert_eq!(loaded.tasks[0].status, TaskStatus::InProgress);
+    }
+
     #[test]
     fn test_json_storage_streak() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -197,6 +817,39 @@ mod tests {
         assert_eq!(loaded.current_streak, 1);
     }
 
+    #[test]
+    fn test_repair_resyncs_current_from_history() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        let mut schedule = Schedule::today();
+        let start = Local::now();
+        schedule
+            .add_task(Task::new("Kept".to_string(), start, start + Duration::hours(1)))
+            .unwrap();
+        storage.save_schedule(&schedule).unwrap();
+
+        // current.json만 다른 내용으로 덮어씀
+        let mut stale = Schedule::today();
+        stale
+            .add_task(Task::new("Stale".to_string(), start, start + Duration::hours(1)))
+            .unwrap();
+        fs::write(storage.current_schedule_path(), serde_json::to_string(&stale).unwrap()).unwrap();
+
+        let orphan = temp_dir.path().join("history").join("2020-01-01_stats.json");
+        fs::write(&orphan, serde_json::to_string(&DailyStats::new(Local::now())).unwrap()).unwrap();
+
+        let report = storage.verify().unwrap();
+        assert!(report.current_out_of_sync);
+        assert_eq!(report.orphaned_stats, vec![orphan.clone()]);
+        assert!(report.corrupt_files.is_empty());
+
+        storage.repair().unwrap();
+        assert_eq!(storage.load_today().unwrap().unwrap().tasks[0].title, "Kept");
+        assert!(!orphan.exists());
+        assert!(storage.verify().unwrap().is_clean());
+    }
+
     #[test]
     fn test_json_storage_stats() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -218,4 +871,56 @@ mod tests {
         assert_eq!(loaded_stats.completion_rate, 75.0);
         assert_eq!(loaded_stats.total_tasks, 4);
     }
+
+    #[test]
+    fn test_known_tags_by_frequency() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        let save_day = |days_ago: i64, tags: &[&[&str]]| {
+            let date = Local::now() - Duration::days(days_ago);
+            let mut schedule = Schedule::new(date);
+            for (i, task_tags) in tags.iter().enumerate() {
+                let start = date + Duration::minutes(i as i64 * 30);
+                let mut task = Task::new(format!("Task {}", i), start, start + Duration::minutes(30));
+                task.tags = task_tags.iter().map(|t| t.to_string()).collect();
+                schedule.tasks.push(task);
+            }
+            storage.save_schedule(&schedule).unwrap();
+        };
+
+        save_day(0, &[&["work", "deep"], &["email"]]);
+        save_day(1, &[&["work"], &["health"]]);
+        save_day(2, &[&["work", "email"]]);
+        save_day(10, &[&["old"], &["old"], &["old"], &["old"]]);
+
+        let tags = storage.known_tags(7).unwrap();
+        assert_eq!(tags[0], ("work".to_string(), 3));
+        assert_eq!(tags[1], ("email".to_string(), 2));
+        assert_eq!(tags.len(), 4);
+        assert!(tags.iter().all(|(tag, _)| tag != "old"));
+    }
+
+    #[test]
+    fn test_json_storage_events() {
+        use crate::models::EventType;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+
+        assert!(storage.load_events().unwrap().is_empty());
+
+        storage
+            .append_event(ScheduleEvent::new(EventType::TaskAdded, "task-1", "Write"))
+            .unwrap();
+        storage
+            .append_event(ScheduleEvent::new(EventType::TaskStarted, "task-1", "Write"))
+            .unwrap();
+
+        let events = storage.load_events().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_type, EventType::TaskAdded);
+        assert_eq!(events[1].event_type, EventType::TaskStarted);
+        assert_eq!(events[1].task_id, "task-1");
+    }
 }
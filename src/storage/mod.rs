@@ -1,17 +1,137 @@
+pub mod backup;
+pub mod encrypted;
+pub mod ics;
 pub mod json_storage;
 
-use chrono::{DateTime, Local};
+use std::path::{Path, PathBuf};
 
-use crate::models::{DailyStats, Schedule, StreakInfo};
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::error::SchedError;
+use crate::models::{
+    DailyStats, Schedule, ScheduleEvent, ScheduleTemplate, StreakInfo, Task, WeeklyGoals,
+};
+use crate::time::resolve_local;
+
+/// `load_range`로 한 번에 읽을 수 있는 최대 일수
+pub const MAX_RANGE_DAYS: i64 = 366;
 
 pub trait Storage {
-    fn save_schedule(&self, schedule: &Schedule) -> anyhow::Result<()>;
+    /// `save_*`는 기록한 파일 경로를 반환 (스케줄은 날짜별 파일)
+    fn save_schedule(&self, schedule: &Schedule) -> anyhow::Result<PathBuf>;
     fn load_schedule(&self, date: DateTime<Local>) -> anyhow::Result<Option<Schedule>>;
     fn load_today(&self) -> anyhow::Result<Option<Schedule>>;
-    fn save_stats(&self, stats: &DailyStats) -> anyhow::Result<()>;
+    /// 날짜와 상관없이 마지막으로 저장한 current.json 스케줄
+    fn load_current(&self) -> anyhow::Result<Option<Schedule>>;
+    fn save_stats(&self, stats: &DailyStats) -> anyhow::Result<PathBuf>;
     fn load_stats(&self, date: DateTime<Local>) -> anyhow::Result<Option<DailyStats>>;
-    fn save_streak(&self, streak: &StreakInfo) -> anyhow::Result<()>;
+    fn save_streak(&self, streak: &StreakInfo) -> anyhow::Result<PathBuf>;
     fn load_streak(&self) -> anyhow::Result<StreakInfo>;
+    fn append_event(&self, event: ScheduleEvent) -> anyhow::Result<()>;
+    fn load_events(&self) -> anyhow::Result<Vec<ScheduleEvent>>;
+    fn save_template(&self, template: &ScheduleTemplate) -> anyhow::Result<PathBuf>;
+    fn load_template(&self, name: &str) -> anyhow::Result<Option<ScheduleTemplate>>;
+    fn list_templates(&self) -> anyhow::Result<Vec<String>>;
+    fn save_goals(&self, goals: &WeeklyGoals) -> anyhow::Result<PathBuf>;
+    fn load_goals(&self, week: &str) -> anyhow::Result<Option<WeeklyGoals>>;
+    /// 반복 작업 원본 (`recurrence_id`마다 하나)
+    fn save_recurring(&self, sources: &[Task]) -> anyhow::Result<PathBuf>;
+    fn load_recurring(&self) -> anyhow::Result<Vec<Task>>;
+    fn save_last_tick(&self, at: DateTime<Local>) -> anyhow::Result<PathBuf>;
+    fn load_last_tick(&self) -> anyhow::Result<Option<DateTime<Local>>>;
+    fn verify(&self) -> anyhow::Result<IntegrityReport>;
+    fn repair(&self) -> anyhow::Result<IntegrityReport>;
+
+    /// 데이터 디렉토리 경로
+    fn data_dir(&self) -> &Path;
+
+    /// 파일을 쓰지 않고 알리기만 하는지 (`--dry-run`)
+    fn is_dry_run(&self) -> bool;
+
+    /// 저장 파일을 암호화하는지 (평문 캐시를 남기면 안 됨)
+    fn is_encrypted(&self) -> bool {
+        false
+    }
+
+    /// `from`부터 `to`까지 (양 끝 포함) 저장된 스케줄, 날짜순이며 통계 계산됨
+    fn load_range(&self, from: NaiveDate, to: NaiveDate) -> anyhow::Result<Vec<Schedule>> {
+        if from > to {
+            return Err(SchedError::validation(format!("Range start {} is after end {}", from, to)).into());
+        }
+        let days = (to - from).num_days() + 1;
+        if days > MAX_RANGE_DAYS {
+            return Err(SchedError::validation(format!(
+                "Range of {} days exceeds the limit of {} days",
+                days, MAX_RANGE_DAYS
+            ))
+            .into());
+        }
+
+        let mut schedules = Vec::new();
+        for date in from.iter_days().take(days as usize) {
+            let midnight = resolve_local(date.and_hms_opt(0, 0, 0).unwrap());
+            let Some(mut schedule) = self.load_schedule(midnight)? else {
+                continue;
+            };
+            schedule.calculate_stats();
+            // total_wasted는 현재 시간 기준이므로 매번 계산
+            schedule.total_wasted = Some(schedule.total_wasted());
+            schedules.push(schedule);
+        }
+        Ok(schedules)
+    }
+
+    /// 오늘을 포함한 최근 `days`일 동안 쓰인 태그와 횟수 (많은 순, 같으면 이름순)
+    fn known_tags(&self, days: i64) -> anyhow::Result<Vec<(String, usize)>> {
+        let today = Local::now();
+        let mut counts: HashMap<String, usize> = HashMap::new();
+
+        for days_ago in 0..days {
+            let Some(schedule) = self.load_schedule(today - Duration::days(days_ago))? else {
+                continue;
+            };
+            for tag in schedule.tasks.iter().flat_map(|t| &t.tags) {
+                *counts.entry(tag.clone()).or_default() += 1;
+            }
+        }
+
+        let mut tags: Vec<(String, usize)> = counts.into_iter().collect();
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(tags)
+    }
+}
+
+/// 스케줄 파일 저장 형식 (읽을 때는 헤더로 구분하므로 섞여 있어도 됨)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageFormat {
+    /// 사람이 읽기 쉬운 pretty JSON
+    #[default]
+    Json,
+    /// 작고 빠른 MessagePack
+    MessagePack,
+}
+
+/// 데이터 디렉토리 점검 결과
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IntegrityReport {
+    /// current.json이 오늘 history 파일과 다름
+    pub current_out_of_sync: bool,
+    /// 같은 날짜의 스케줄이 없는 통계 파일
+    pub orphaned_stats: Vec<PathBuf>,
+    /// 읽을 수 없는 파일 (자동으로 고치지 않음)
+    pub corrupt_files: Vec<PathBuf>,
+}
+
+impl IntegrityReport {
+    /// 문제가 하나도 없는지
+    pub fn is_clean(&self) -> bool {
+        !self.current_out_of_sync && self.orphaned_stats.is_empty() && self.corrupt_files.is_empty()
+    }
 }
 
+pub use encrypted::EncryptedStorage;
 pub use json_storage::JsonStorage;
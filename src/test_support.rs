@@ -0,0 +1,18 @@
+//! 테스트용 고정 날짜 픽스처 (2025년 11월)
+
+use chrono::{DateTime, Local, TimeZone};
+
+use crate::models::Schedule;
+
+/// 하루짜리 스케줄 테스트의 기준일 (2025-11-04, 화요일)
+pub const FIXTURE_DAY: u32 = 4;
+
+/// 2025-11-`day` `hour`:`minute`
+pub fn at(day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+    Local.with_ymd_and_hms(2025, 11, day, hour, minute, 0).unwrap()
+}
+
+/// 기준일의 빈 스케줄
+pub fn day_schedule() -> Schedule {
+    Schedule::new(at(FIXTURE_DAY, 0, 0))
+}
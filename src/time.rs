@@ -0,0 +1,64 @@
+use chrono::{DateTime, Duration, Local, LocalResult, NaiveDateTime, TimeZone};
+
+/// 로컬 날짜/시각을 `DateTime<Local>`로 변환 (DST 전환 처리 포함)
+///
+/// `from_local_datetime(...).single()`과 달리 실패하지 않습니다.
+/// - 중복되는 시각 (서머타임 종료): 더 이른 시각을 선택
+/// - 존재하지 않는 시각 (서머타임 시작): 다음 유효한 시각으로 이동
+pub fn resolve_local(naive: NaiveDateTime) -> DateTime<Local> {
+    resolve_in(&Local, naive)
+}
+
+/// 임의의 타임존에서 `resolve_local`과 같은 규칙으로 변환
+pub fn resolve_in<Tz: TimeZone>(tz: &Tz, naive: NaiveDateTime) -> DateTime<Tz> {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => dt,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => {
+            // 건너뛴 구간: 1분씩 앞으로 가며 첫 유효 시각 찾기 (최대 하루)
+            let mut candidate = naive;
+            for _ in 0..(24 * 60) {
+                candidate += Duration::minutes(1);
+                if let Some(dt) = tz.from_local_datetime(&candidate).earliest() {
+                    return dt;
+                }
+            }
+            tz.from_utc_datetime(&naive)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use chrono_tz::America::New_York;
+
+    fn naive(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_resolve_normal_time() {
+        let dt = resolve_in(&New_York, naive(2025, 6, 1, 9, 0));
+        assert_eq!(dt.naive_local(), naive(2025, 6, 1, 9, 0));
+    }
+
+    #[test]
+    fn test_resolve_skipped_time() {
+        // 2025-03-09 02:00 → 03:00 (02:30은 존재하지 않음)
+        let dt = resolve_in(&New_York, naive(2025, 3, 9, 2, 30));
+        assert_eq!(dt.naive_local(), naive(2025, 3, 9, 3, 0));
+    }
+
+    #[test]
+    fn test_resolve_ambiguous_time() {
+        // 2025-11-02 01:30은 EDT/EST 두 번 존재 → 더 이른 EDT(-04:00)
+        let dt = resolve_in(&New_York, naive(2025, 11, 2, 1, 30));
+        assert_eq!(dt.naive_local(), naive(2025, 11, 2, 1, 30));
+        assert_eq!(dt.naive_utc(), naive(2025, 11, 2, 5, 30));
+    }
+}
@@ -2,31 +2,47 @@ use chrono::Local;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
 use ratatui::{
     backend::Backend,
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame, Terminal,
 };
 
-use crate::models::Schedule;
-use crate::storage::{JsonStorage, Storage};
+use crate::cli::output::{accessible_symbols, truncate_title};
+use crate::models::{Priority, Schedule, Task, TaskStatus};
+use crate::storage::Storage;
+
+/// 도움말 팝업에 표시할 키 목록 (키, 설명)
+const KEY_BINDINGS: &[(&str, &str)] = &[
+    ("↑ / k", "Select previous task"),
+    ("↓ / j", "Select next task"),
+    ("r", "Reload schedule"),
+    ("f", "Toggle focus mode"),
+    ("?", "Toggle this help"),
+    ("q / Esc", "Quit"),
+];
 
 pub struct App {
-    storage: JsonStorage,
+    storage: Box<dyn Storage>,
     schedule: Option<Schedule>,
     selected_index: usize,
     should_quit: bool,
+    show_help: bool,
+    /// 현재 작업만 크게 보여주는 집중 모드
+    focus_mode: bool,
 }
 
 impl App {
-    pub fn new(storage: JsonStorage) -> anyhow::Result<Self> {
+    pub fn new(storage: Box<dyn Storage>) -> anyhow::Result<Self> {
         let schedule = storage.load_today()?;
         Ok(Self {
             storage,
             schedule,
             selected_index: 0,
             should_quit: false,
+            show_help: false,
+            focus_mode: false,
         })
     }
 
@@ -51,8 +67,20 @@ impl App {
     }
 
     fn handle_key(&mut self, key: KeyEvent) {
+        // 도움말이 열려 있으면 닫기 키만 처리
+        if self.show_help {
+            match key.code {
+                KeyCode::Char('?') | KeyCode::Esc => self.show_help = false,
+                KeyCode::Char('q') => self.should_quit = true,
+                _ => {}
+            }
+            return;
+        }
+
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Char('?') => self.show_help = true,
+            KeyCode::Char('f') => self.focus_mode = !self.focus_mode,
             KeyCode::Up | KeyCode::Char('k') => self.select_previous(),
             KeyCode::Down | KeyCode::Char('j') => self.select_next(),
             KeyCode::Char('r') => {
@@ -80,6 +108,20 @@ impl App {
     }
 
     fn ui(&mut self, f: &mut Frame) {
+        if self.focus_mode {
+            let current = self.schedule.as_ref().and_then(|s| s.get_current_task());
+            render_focus(f, f.size(), current);
+        } else {
+            self.render_panels(f);
+        }
+
+        if self.show_help {
+            render_help(f, f.size());
+        }
+    }
+
+    /// 기본 화면: 타임라인, 상세, 통계 패널
+    fn render_panels(&mut self, f: &mut Frame) {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
@@ -111,12 +153,15 @@ impl App {
                         task.end_time.format("%H:%M")
                     );
 
+                    let accessible = accessible_symbols();
                     let status_icon = match task.status {
+                        status if accessible => status.symbol(),
                         crate::models::TaskStatus::Completed => "✓",
                         crate::models::TaskStatus::InProgress => "▶",
                         crate::models::TaskStatus::Paused => "⏸",
                         crate::models::TaskStatus::Pending => "○",
                         crate::models::TaskStatus::Skipped => "⊘",
+                        crate::models::TaskStatus::Blocked => "⊗",
                     };
 
                     let status_color = match task.status {
@@ -125,14 +170,25 @@ impl App {
                         crate::models::TaskStatus::Paused => Color::Cyan,
                         crate::models::TaskStatus::Pending => Color::Gray,
                         crate::models::TaskStatus::Skipped => Color::Red,
+                        crate::models::TaskStatus::Blocked => Color::Magenta,
                     };
 
+                    let marker = priority_span(task.priority);
+                    let marker_width = marker.width();
                     let line = Line::from(vec![
                         Span::styled(status_icon, Style::default().fg(status_color)),
                         Span::raw(" "),
                         Span::styled(time_str, Style::default().fg(Color::Cyan)),
                         Span::raw(" "),
-                        Span::raw(&task.title),
+                        marker,
+                        // 테두리, 선택 표시, 아이콘, 시간, 우선순위, 마감 표시를 뺀 폭
+                        Span::raw(truncate_title(
+                            &task.title,
+                            (area.width as usize)
+                                .saturating_sub(if accessible { 26 } else { 24 })
+                                .saturating_sub(marker_width),
+                        )),
+                        super::widget::urgent_marker(task),
                     ]);
 
                     ListItem::new(line)
@@ -168,65 +224,7 @@ impl App {
 
         if let Some(ref schedule) = self.schedule {
             if let Some(task) = schedule.tasks.get(self.selected_index) {
-                let mut lines = vec![
-                    Line::from(vec![
-                        Span::styled("Title: ", Style::default().fg(Color::Cyan)),
-                        Span::raw(&task.title),
-                    ]),
-                    Line::from(vec![
-                        Span::styled("Time: ", Style::default().fg(Color::Cyan)),
-                        Span::raw(format!(
-                            "{} - {}",
-                            task.start_time.format("%H:%M"),
-                            task.end_time.format("%H:%M")
-                        )),
-                    ]),
-                    Line::from(vec![
-                        Span::styled("Duration: ", Style::default().fg(Color::Cyan)),
-                        Span::raw(format!("{}m", task.estimated_duration_minutes)),
-                    ]),
-                    Line::from(vec![
-                        Span::styled("Status: ", Style::default().fg(Color::Cyan)),
-                        Span::raw(format!("{:?}", task.status)),
-                    ]),
-                    Line::from(""),
-                ];
-
-                if let Some(elapsed) = task.elapsed_minutes() {
-                    let progress =
-                        (elapsed as f64 / task.estimated_duration_minutes as f64 * 100.0) as u32;
-                    lines.push(Line::from(vec![
-                        Span::styled("Progress: ", Style::default().fg(Color::Cyan)),
-                        Span::raw(format!("{}m / {}m ({}%)", elapsed, task.estimated_duration_minutes, progress)),
-                    ]));
-                }
-
-                if let Some(ref pomodoro) = task.pomodoro {
-                    lines.push(Line::from(""));
-                    lines.push(Line::from(vec![
-                        Span::styled("Pomodoro: ", Style::default().fg(Color::Cyan)),
-                        Span::raw(format!(
-                            "{}/{}",
-                            pomodoro.completed_pomodoros, pomodoro.total_pomodoros
-                        )),
-                    ]));
-                }
-
-                if !task.tags.is_empty() {
-                    lines.push(Line::from(""));
-                    lines.push(Line::from(vec![
-                        Span::styled("Tags: ", Style::default().fg(Color::Cyan)),
-                        Span::raw(task.tags.join(", ")),
-                    ]));
-                }
-
-                if let Some(ref notes) = task.notes {
-                    lines.push(Line::from(""));
-                    lines.push(Line::from(vec![
-                        Span::styled("Notes: ", Style::default().fg(Color::Cyan)),
-                    ]));
-                    lines.push(Line::from(notes.as_str()));
-                }
+                let lines = details_lines(task);
 
                 let paragraph = Paragraph::new(lines).block(block);
                 f.render_widget(paragraph, area);
@@ -269,6 +267,7 @@ impl App {
                 .count();
 
             let completion_rate = schedule.completion_rate();
+            let remaining = remaining_label(schedule);
 
             let current_time = Local::now().format("%H:%M:%S").to_string();
 
@@ -299,18 +298,16 @@ impl App {
                     Span::styled("Completion: ", Style::default().fg(Color::Cyan)),
                     Span::raw(format!("{:.1}%", completion_rate * 100.0)),
                 ]),
+                Line::from(vec![
+                    Span::styled("Remaining: ", Style::default().fg(Color::Cyan)),
+                    Span::raw(remaining),
+                ]),
                 Line::from(""),
                 Line::from(""),
-                Line::from(vec![Span::styled(
-                    "Controls:",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                )]),
-                Line::from("↑/k - Up"),
-                Line::from("↓/j - Down"),
-                Line::from("r - Reload"),
-                Line::from("q/Esc - Quit"),
+                Line::from(vec![
+                    Span::styled("?", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::raw(" - Help"),
+                ]),
             ];
 
             let paragraph = Paragraph::new(lines).block(block);
@@ -323,3 +320,343 @@ impl App {
         }
     }
 }
+
+/// 타임라인 제목 앞 우선순위 표시 (보통이면 빈 칸 없음)
+fn priority_span(priority: Priority) -> Span<'static> {
+    let color = match priority {
+        Priority::Critical => Color::Red,
+        Priority::High => Color::Yellow,
+        Priority::Medium | Priority::Low => Color::DarkGray,
+    };
+    match priority.marker() {
+        "" => Span::raw(""),
+        marker => Span::styled(format!("{} ", marker), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+    }
+}
+
+/// 집중 모드: 현재 작업 제목, 경과/남은 시간, Pomodoro 진행만 가운데에 표시
+fn render_focus(f: &mut Frame, area: Rect, current: Option<&Task>) {
+    let lines = focus_lines(current);
+    let width = area.width.saturating_sub(4).min(70);
+    let panel = centered_rect(width, lines.len() as u16 + 2, area);
+    let block = Block::default()
+        .title(" Focus ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Green));
+
+    f.render_widget(Paragraph::new(lines).block(block).alignment(Alignment::Center), panel);
+}
+
+/// 집중 모드에 표시할 줄 (현재 작업이 없으면 안내 문구만)
+fn focus_lines(current: Option<&Task>) -> Vec<Line<'_>> {
+    let Some(task) = current else {
+        return vec![
+            Line::from(Span::styled("No active task.", Style::default().fg(Color::Gray))),
+            Line::from(""),
+            Line::from(Span::styled("f - Exit focus mode", Style::default().fg(Color::DarkGray))),
+        ];
+    };
+
+    let elapsed = task.elapsed_minutes().unwrap_or(0);
+    let remaining = (task.estimated_duration_minutes - elapsed).max(0);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            task.title.as_str(),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Elapsed ", Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{}m", elapsed)),
+            Span::raw("  ·  "),
+            Span::styled("Remaining ", Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{}m", remaining)),
+        ]),
+    ];
+
+    if let Some(ref pomodoro) = task.pomodoro {
+        let mut progress = format!("{}/{}", pomodoro.completed_pomodoros, pomodoro.total_pomodoros);
+        if let Some(left) = pomodoro.remaining_minutes() {
+            progress.push_str(&format!(" ({}m left)", left));
+        }
+        lines.push(Line::from(vec![
+            Span::styled("Pomodoro ", Style::default().fg(Color::Cyan)),
+            Span::raw(progress),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("f - Exit focus mode", Style::default().fg(Color::DarkGray))));
+    lines
+}
+
+/// 배경을 어둡게 하고 가운데에 키 도움말 팝업 표시
+fn render_help(f: &mut Frame, area: Rect) {
+    f.buffer_mut()
+        .set_style(area, Style::default().fg(Color::DarkGray).add_modifier(Modifier::DIM));
+
+    let key_width = KEY_BINDINGS.iter().map(|(key, _)| key.chars().count()).max().unwrap_or(0);
+    let mut lines: Vec<Line> = KEY_BINDINGS
+        .iter()
+        .map(|(key, action)| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:<width$}  ", key, width = key_width),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(*action),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Press ? or Esc to close",
+        Style::default().fg(Color::Gray),
+    )));
+
+    let popup = centered_rect(40, lines.len() as u16 + 2, area);
+    let block = Block::default()
+        .title(" Keys ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Green));
+
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+/// `area` 가운데에 놓인 `width` x `height` 영역 (화면보다 크면 화면에 맞춤)
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// 상세 패널에 표시할 작업 정보
+fn details_lines(task: &Task) -> Vec<Line<'_>> {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Title: ", Style::default().fg(Color::Cyan)),
+            Span::raw(&task.title),
+        ]),
+        Line::from(vec![
+            Span::styled("Time: ", Style::default().fg(Color::Cyan)),
+            Span::raw(format!(
+                "{} - {}",
+                task.start_time.format("%H:%M"),
+                task.end_time.format("%H:%M")
+            )),
+        ]),
+        Line::from(vec![
+            Span::styled("Duration: ", Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{}m", task.estimated_duration_minutes)),
+        ]),
+        Line::from(vec![
+            Span::styled("Status: ", Style::default().fg(Color::Cyan)),
+            Span::raw(if accessible_symbols() {
+                format!("{} {}", task.status.symbol(), task.status.label())
+            } else {
+                format!("{:?}", task.status)
+            }),
+        ]),
+        Line::from(""),
+    ];
+
+    if let Some(ref reason) = task.blocked_reason {
+        lines.insert(
+            4,
+            Line::from(vec![
+                Span::styled("Blocked: ", Style::default().fg(Color::Magenta)),
+                Span::raw(reason),
+            ]),
+        );
+    }
+
+    if task.status == TaskStatus::Completed {
+        if let Some(actual) = task.plausible_actual_minutes() {
+            let (label, color) = variance_label(task.estimated_duration_minutes, actual);
+            lines.insert(
+                lines.len() - 1,
+                Line::from(vec![
+                    Span::styled("Actual: ", Style::default().fg(Color::Cyan)),
+                    Span::styled(label, Style::default().fg(color)),
+                ]),
+            );
+        }
+    }
+
+    if let Some(elapsed) = task.elapsed_minutes() {
+        let progress = (task.progress_ratio() * 100.0).round() as u32;
+        lines.push(Line::from(vec![
+            Span::styled("Progress: ", Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{}m / {}m ({}%)", elapsed, task.estimated_duration_minutes, progress)),
+        ]));
+    }
+
+    if let Some(ref pomodoro) = task.pomodoro {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Pomodoro: ", Style::default().fg(Color::Cyan)),
+            Span::raw(format!(
+                "{}/{}",
+                pomodoro.completed_pomodoros, pomodoro.total_pomodoros
+            )),
+        ]));
+    }
+
+    if !task.tags.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Tags: ", Style::default().fg(Color::Cyan)),
+            Span::raw(task.tags.join(", ")),
+        ]));
+    }
+
+    if let Some(ref link) = task.link {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Link: ", Style::default().fg(Color::Cyan)),
+            Span::styled(link.as_str(), Style::default().add_modifier(Modifier::UNDERLINED)),
+        ]));
+    }
+
+    if let Some(ref notes) = task.notes {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Notes: ", Style::default().fg(Color::Cyan)),
+        ]));
+        lines.push(Line::from(notes.as_str()));
+    }
+
+    lines
+}
+
+/// 예상 대비 실제 시간 표시와 색 (예: "72m (+12m over)" 빨강)
+fn variance_label(estimated: i64, actual: i64) -> (String, Color) {
+    let diff = actual - estimated;
+    match diff {
+        0 => (format!("{}m (on estimate)", actual), Color::Green),
+        d if d > 0 => (format!("{}m (+{}m over)", actual, d), Color::Red),
+        d => (format!("{}m ({}m under)", actual, d), Color::Green),
+    }
+}
+
+/// 남은 계획 시간 표시 문자열 (예: "3h 20m")
+fn remaining_label(schedule: &Schedule) -> String {
+    let minutes = schedule.remaining_planned_minutes();
+    format!("{}h {}m", minutes / 60, minutes % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::JsonStorage;
+    use chrono::Duration;
+
+    #[test]
+    fn test_remaining_label() {
+        let mut schedule = Schedule::today();
+        let start = Local::now();
+
+        let mut done = Task::new("Done".to_string(), start, start + Duration::hours(1));
+        done.complete();
+        let pending = Task::new(
+            "Pending".to_string(),
+            start + Duration::hours(1),
+            start + Duration::minutes(260),
+        );
+
+        schedule.add_task(done).unwrap();
+        schedule.add_task(pending).unwrap();
+
+        assert_eq!(remaining_label(&schedule), "3h 20m");
+    }
+
+    #[test]
+    fn test_variance_label() {
+        assert_eq!(variance_label(60, 72), ("72m (+12m over)".to_string(), Color::Red));
+        assert_eq!(variance_label(60, 48), ("48m (-12m under)".to_string(), Color::Green));
+        assert_eq!(variance_label(60, 60), ("60m (on estimate)".to_string(), Color::Green));
+    }
+
+    #[test]
+    fn test_help_toggle() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let mut app = App::new(Box::new(storage)).unwrap();
+        let press = |code| KeyEvent::from(code);
+
+        app.handle_key(press(KeyCode::Char('?')));
+        assert!(app.show_help);
+        app.handle_key(press(KeyCode::Char('?')));
+        assert!(!app.show_help);
+
+        // 도움말이 열려 있을 때 Esc는 종료가 아니라 닫기
+        app.handle_key(press(KeyCode::Char('?')));
+        app.handle_key(press(KeyCode::Esc));
+        assert!(!app.show_help);
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_focus_mode_toggle_and_layout() {
+        use ratatui::backend::TestBackend;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::with_path(temp_dir.path().to_path_buf()).unwrap();
+        let mut schedule = Schedule::today();
+        let start = Local::now() - Duration::minutes(10);
+        let mut task = Task::new("Deep work".to_string(), start, start + Duration::hours(1));
+        task.start();
+        schedule.add_task(task).unwrap();
+        storage.save_schedule(&schedule).unwrap();
+
+        let mut app = App::new(Box::new(storage)).unwrap();
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        let mut screen = |app: &mut App| -> String {
+            terminal.draw(|f| app.ui(f)).unwrap();
+            terminal.backend().buffer().content.iter().map(|cell| cell.symbol()).collect()
+        };
+
+        assert!(screen(&mut app).contains("Timeline"));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('f')));
+        assert!(app.focus_mode);
+        let focused = screen(&mut app);
+        assert!(focused.contains("Focus"));
+        assert!(focused.contains("Deep work"));
+        assert!(!focused.contains("Timeline"));
+
+        app.schedule = None;
+        assert!(screen(&mut app).contains("No active task."));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('f')));
+        assert!(!app.focus_mode);
+        assert!(screen(&mut app).contains("Timeline"));
+    }
+
+    #[test]
+    fn test_centered_rect() {
+        let area = Rect::new(0, 0, 100, 30);
+        assert_eq!(centered_rect(40, 10, area), Rect::new(30, 10, 40, 10));
+        assert_eq!(centered_rect(40, 10, Rect::new(0, 0, 20, 5)), Rect::new(0, 0, 20, 5));
+    }
+
+    #[test]
+    fn test_details_lines_include_link() {
+        let start = Local::now();
+        let mut task = Task::new("Test".to_string(), start, start + Duration::hours(1));
+        task.link = Some("https://example.com/doc".to_string());
+
+        let rendered: Vec<String> = details_lines(&task)
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+
+        assert!(rendered.contains(&"Link: https://example.com/doc".to_string()));
+    }
+}
@@ -1,7 +1,7 @@
 use std::io;
 use std::time::Duration;
 
-use chrono::Local;
+use chrono::{DateTime, Local};
 use crossterm::{
     event::{self, Event, KeyCode},
     execute,
@@ -16,22 +16,22 @@ use ratatui::{
     Frame, Terminal,
 };
 
-use crate::models::TaskStatus;
-use crate::storage::{JsonStorage, Storage};
+use crate::cli::output::{accessible_symbols, overdue_grace_minutes, truncate_title};
+use crate::models::{Schedule, Task, TaskStatus};
+use crate::storage::Storage;
 
-pub fn run_widget() -> anyhow::Result<()> {
+pub fn run_widget(storage: Box<dyn Storage>) -> anyhow::Result<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let storage = JsonStorage::new()?;
     let mut should_quit = false;
 
     while !should_quit {
         terminal.draw(|f| {
-            if let Err(e) = ui(f, &storage) {
+            if let Err(e) = ui(f, storage.as_ref()) {
                 log::error!("UI draw error: {}", e);
             }
         })?;
@@ -52,7 +52,7 @@ pub fn run_widget() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn ui(f: &mut Frame, storage: &JsonStorage) -> anyhow::Result<()> {
+fn ui(f: &mut Frame, storage: &dyn Storage) -> anyhow::Result<()> {
     let size = f.size();
 
     let chunks = Layout::default()
@@ -75,7 +75,7 @@ fn ui(f: &mut Frame, storage: &JsonStorage) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn render_widget(f: &mut Frame, storage: &JsonStorage, area: Rect) -> anyhow::Result<()> {
+fn render_widget(f: &mut Frame, storage: &dyn Storage, area: Rect) -> anyhow::Result<()> {
     let schedule = storage.load_today()?;
 
     if let Some(schedule) = schedule {
@@ -102,16 +102,11 @@ fn render_widget(f: &mut Frame, storage: &JsonStorage, area: Rect) -> anyhow::Re
             ])
             .split(content_area);
 
-        let now = Local::now().format("%H:%M").to_string();
-        let header = Line::from(vec![
-            Span::raw(now),
-            Span::raw("  "),
-            Span::styled(
-                format!("{:.0}%", completion),
-                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
-            ),
-        ]);
-        f.render_widget(Paragraph::new(header), inner_chunks[0]);
+        // 앞 아이콘/들여쓰기와 마감 표시를 뺀 제목 폭
+        let icon_width = if accessible_symbols() { 6 } else { 4 };
+        let title_width = (content_area.width as usize).saturating_sub(icon_width);
+
+        f.render_widget(Paragraph::new(header_line(&schedule, Local::now())), inner_chunks[0]);
 
         let gauge = Gauge::default()
             .gauge_style(Style::default().fg(Color::Green))
@@ -121,19 +116,28 @@ fn render_widget(f: &mut Frame, storage: &JsonStorage, area: Rect) -> anyhow::Re
         if let Some(task) = current {
             let elapsed = task.elapsed_minutes().unwrap_or(0);
             let status_icon = match task.status {
+                status if accessible_symbols() => status.symbol(),
                 TaskStatus::InProgress => "▶",
                 _ => " ",
             };
             
             let current_text = vec![
-                Line::from(Span::styled(
-                    format!("{} {}", status_icon, task.title),
-                    Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
-                )),
-                Line::from(Span::raw(format!(
-                    "  {}m / {}m",
-                    elapsed, task.estimated_duration_minutes
-                ))),
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} {}", status_icon, truncate_title(&task.title, title_width)),
+                        Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                    ),
+                    urgent_marker(task),
+                ]),
+                Line::from(vec![
+                    Span::raw(format!(
+                        "  {:.0}% · {}m / {}m",
+                        task.progress_ratio() * 100.0,
+                        elapsed,
+                        task.estimated_duration_minutes
+                    )),
+                    overdue_marker(task),
+                ]),
             ];
             let current_widget = Paragraph::new(current_text);
             f.render_widget(current_widget, inner_chunks[2]);
@@ -146,7 +150,10 @@ fn render_widget(f: &mut Frame, storage: &JsonStorage, area: Rect) -> anyhow::Re
         if let Some(task) = next {
             let next_text = vec![
                 Line::from(Span::styled("⏱  Next:", Style::default().fg(Color::Yellow))),
-                Line::from(Span::raw(format!("  {}", task.title))),
+                Line::from(vec![
+                    Span::raw(format!("  {}", truncate_title(&task.title, title_width))),
+                    urgent_marker(task),
+                ]),
             ];
             let next_widget = Paragraph::new(next_text);
             f.render_widget(next_widget, inner_chunks[3]);
@@ -168,3 +175,65 @@ fn render_widget(f: &mut Frame, storage: &JsonStorage, area: Rect) -> anyhow::Re
 
     Ok(())
 }
+
+/// 헤더: 현재 시각, 완료율, 지금까지의 효율 점수
+fn header_line(schedule: &Schedule, now: DateTime<Local>) -> Line<'static> {
+    let mut spans = vec![
+        Span::raw(now.format("%H:%M").to_string()),
+        Span::raw("  "),
+        Span::styled(
+            format!("{:.0}%", schedule.completion_rate()),
+            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+        ),
+    ];
+
+    if let Some(score) = schedule.efficiency_score_so_far(now) {
+        spans.push(Span::raw(format!("  eff {:.0}%", score)));
+        spans.push(Span::styled(" (so far)", Style::default().fg(Color::DarkGray)));
+    }
+
+    Line::from(spans)
+}
+
+/// 예상 시간 + 여유를 넘긴 진행 중 작업 표시
+fn overdue_marker(task: &Task) -> Span<'static> {
+    if task.manual_progress.is_none() && task.is_overdue_with_grace(overdue_grace_minutes()) {
+        Span::styled(" OVERDUE", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+    } else {
+        Span::raw("")
+    }
+}
+
+/// 마감이 임박했거나 지난 작업 표시
+pub(crate) fn urgent_marker(task: &Task) -> Span<'static> {
+    if task.is_deadline_urgent() {
+        Span::styled(" !", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+    } else {
+        Span::raw("")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{at, day_schedule};
+
+    #[test]
+    fn test_header_shows_so_far_efficiency() {
+        let mut schedule = day_schedule();
+        let mut done = Task::new("Code".to_string(), at(4, 9, 0), at(4, 10, 0));
+        done.status = TaskStatus::Completed;
+        done.actual_duration_minutes = Some(60);
+        schedule.add_task(done).unwrap();
+        schedule.add_task(Task::new("Docs".to_string(), at(4, 10, 0), at(4, 11, 0))).unwrap();
+        schedule.add_task(Task::new("Later".to_string(), at(4, 15, 0), at(4, 17, 0))).unwrap();
+
+        let text: String = header_line(&schedule, at(4, 12, 0))
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+
+        assert_eq!(text, "12:00  33%  eff 50% (so far)");
+    }
+}
@@ -5,7 +5,7 @@ import { TimelineView } from './components/TimelineView';
 import { DashboardView } from './components/DashboardView';
 import { api } from './services/api';
 import { notificationService } from './services/notificationService';
-import type { Schedule, TaskInput, ViewMode } from './types';
+import type { AddTaskError, Schedule, TaskInput, ViewMode } from './types';
 import './App.css';
 
 function App() {
@@ -62,7 +62,8 @@ function App() {
       }
       await loadSchedule();
     } catch (err) {
-      setError(err instanceof Error ? err.message : 'Failed to add task');
+      const message = err instanceof Error ? err.message : (err as AddTaskError)?.message;
+      setError(message ?? 'Failed to add task');
       console.error('Failed to add task:', err);
     }
   };
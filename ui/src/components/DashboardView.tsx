@@ -208,6 +208,7 @@ export function DashboardView({ schedule, selectedDate }: DashboardViewProps) {
                     Pending: 'bg-yellow-500',
                     Paused: 'bg-orange-500',
                     Skipped: 'bg-gray-500',
+                    Blocked: 'bg-purple-500',
                   };
                   return (
                     <div key={status}>
@@ -1,10 +1,14 @@
 import { useState } from 'react';
-import type { Schedule, Task, TaskInput } from '../types';
+import type { AiCommandError, Schedule, Task, TaskInput } from '../types';
 import { PomodoroTimer } from './PomodoroTimer';
 import { ClaudeModal } from './ClaudeModal';
 import { api } from '../services/api';
 import ReactMarkdown from 'react-markdown';
 
+// AI 명령 에러 메시지 (구조화된 에러 또는 일반 에러)
+const aiErrorMessage = (err: unknown) =>
+  err instanceof Error ? err.message : (err as AiCommandError)?.message ?? String(err);
+
 interface ScheduleViewProps {
   schedule: Schedule | null;
   selectedDate: string;
@@ -53,7 +57,11 @@ export function ScheduleView({
       console.error(`${newProvider} not available:`, error);
       setAiProviderStatus(prev => ({ ...prev, [newProvider]: false }));
 
-      // 설치 안내 표시
+      // 설치되지 않은 경우에만 설치 안내 표시
+      if ((error as AiCommandError)?.kind !== 'not_installed') {
+        alert(aiErrorMessage(error));
+        return;
+      }
       try {
         const guide = await api.getAIInstallationGuide(newProvider);
         setInstallGuideText(guide);
@@ -93,7 +101,7 @@ export function ScheduleView({
       const response = await api.askAI(prompt, aiProvider);
       setClaudeResponse(response);
     } catch (err) {
-      setClaudeResponse(`오류가 발생했습니다: ${err instanceof Error ? err.message : String(err)}`);
+      setClaudeResponse(`오류가 발생했습니다: ${aiErrorMessage(err)}`);
     } finally {
       setIsEvaluating(false);
     }
@@ -420,7 +428,7 @@ function TaskModal({ onClose, onSave, selectedDate, aiProvider }: TaskModalProps
       setShowAutoCompleteInput(false);
       setAutoCompleteInput('');
     } catch (err) {
-      alert(`자동 완성 실패: ${err instanceof Error ? err.message : String(err)}`);
+      alert(`자동 완성 실패: ${aiErrorMessage(err)}`);
     } finally {
       setIsAutoCompleting(false);
     }
@@ -455,7 +463,7 @@ function TaskModal({ onClose, onSave, selectedDate, aiProvider }: TaskModalProps
       const advice = await api.getTaskAdvice(title, duration, aiProvider);
       setClaudeAdvice(advice);
     } catch (err) {
-      setClaudeAdvice(`오류가 발생했습니다: ${err instanceof Error ? err.message : String(err)}`);
+      setClaudeAdvice(`오류가 발생했습니다: ${aiErrorMessage(err)}`);
     } finally {
       setIsGettingAdvice(false);
     }
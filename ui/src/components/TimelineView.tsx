@@ -170,6 +170,7 @@ export function TimelineView({ schedule, selectedDate, onUpdateTask, onShiftSche
       Paused: 'bg-yellow-500 border-yellow-600',
       Pending: 'bg-gray-400 border-gray-500',
       Skipped: 'bg-red-400 border-red-500',
+      Blocked: 'bg-purple-400 border-purple-500',
     };
     return statusColors[task.status] || 'bg-gray-400 border-gray-500';
   };
@@ -181,6 +182,7 @@ export function TimelineView({ schedule, selectedDate, onUpdateTask, onShiftSche
       Paused: '⏸',
       Pending: '○',
       Skipped: '✕',
+      Blocked: '⊗',
     };
     return icons[task.status] || '○';
   };
@@ -1,6 +1,6 @@
 // Tauri IPC API service
 import { invoke } from '@tauri-apps/api/tauri';
-import type { Schedule, TaskInput, TaskSuggestion } from '../types';
+import type { ConflictPolicy, Schedule, TaskInput, TaskSuggestion } from '../types';
 
 export const api = {
   // Get schedule for a specific date
@@ -8,19 +8,24 @@ export const api = {
     return await invoke('get_schedule', { date });
   },
 
+  // Get schedules for an inclusive date range (YYYY-MM-DD)
+  async getSchedulesRange(from: string, to: string): Promise<Schedule[]> {
+    return await invoke('get_schedules_range', { from, to });
+  },
+
   // Get today's schedule
   async getTodaySchedule(): Promise<Schedule | null> {
     return await invoke('get_today_schedule');
   },
 
   // Create a new schedule
-  async createSchedule(date: string, tasks: TaskInput[]): Promise<void> {
-    return await invoke('create_schedule', { date, tasks });
+  async createSchedule(date: string, tasks: TaskInput[], conflictPolicy?: ConflictPolicy): Promise<void> {
+    return await invoke('create_schedule', { date, tasks, conflictPolicy });
   },
 
   // Add a task to existing schedule
-  async addTask(date: string, taskInput: TaskInput): Promise<void> {
-    return await invoke('add_task', { date, taskInput });
+  async addTask(date: string, taskInput: TaskInput, conflictPolicy?: ConflictPolicy): Promise<void> {
+    return await invoke('add_task', { date, taskInput, conflictPolicy });
   },
 
   // Update a task
@@ -73,6 +78,16 @@ export const api = {
     return await invoke('get_ai_installation_guide', { provider });
   },
 
+  // Remember the AI provider used when none is passed
+  async setAIProvider(provider: string): Promise<void> {
+    return await invoke('set_ai_provider', { provider });
+  },
+
+  // Get the saved AI provider preference
+  async getAIProvider(): Promise<string> {
+    return await invoke('get_ai_provider');
+  },
+
   // Ask AI for advice (supports multiple providers: claude, copilot)
   async askAI(prompt: string, provider?: string): Promise<string> {
     return await invoke('ask_ai', { prompt, provider });
@@ -98,6 +113,16 @@ export const api = {
     return await invoke('suggest_task_completion', { date, userInput, provider });
   },
 
+  // Suggest a whole day plan around the existing tasks
+  async suggestDayPlan(date: string, goals: string, provider?: string): Promise<TaskSuggestion[]> {
+    return await invoke('suggest_day_plan', { date, goals, provider });
+  },
+
+  // Tags used in recent days, most frequent first
+  async getKnownTags(days?: number): Promise<{ tag: string; count: number }[]> {
+    return await invoke('get_known_tags', { days });
+  },
+
   // Send notification
   async sendNotification(title: string, body: string): Promise<void> {
     return await invoke('send_notification', { title, body });
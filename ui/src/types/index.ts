@@ -1,6 +1,8 @@
 // Types matching the Rust backend structures
 
-export type TaskStatus = 'Pending' | 'InProgress' | 'Completed' | 'Paused' | 'Skipped';
+export type TaskStatus = 'Pending' | 'InProgress' | 'Completed' | 'Paused' | 'Skipped' | 'Blocked';
+
+export type Priority = 'Low' | 'Medium' | 'High' | 'Critical';
 
 export type ChangeType =
   | 'TaskCreated'
@@ -26,6 +28,7 @@ export interface PomodoroSession {
   pomodoro_duration: number;
   short_break: number;
   long_break: number;
+  long_break_every: number;
 }
 
 // Full Task from backend (read-only)
@@ -37,13 +40,28 @@ export interface Task {
   estimated_duration_minutes: number;
   actual_duration_minutes?: number;
   status: TaskStatus;
+  priority: Priority;
   tags: string[];
   notes?: string;
+  blocked_reason?: string;
+  manual_progress?: number; // 0-1
+  focus_score?: number; // 1-10, set on completion
+  focused?: boolean; // pinned as the current task
+  fixed?: boolean; // immovable block (lunch, commute)
+  paused_at?: string;
+  paused_minutes?: number;
   actual_start_time?: string;
   actual_end_time?: string;
+  sessions?: { start: string; end?: string }[]; // work sessions, one per start/resume
+  interruptions?: { at: string; reason: string }[]; // logged with `sched interrupt`
   pomodoro?: PomodoroSession;
+  recurrence?: Recurrence;
+  recurrence_id?: string;
+  recurrence_until?: string; // YYYY-MM-DD, inclusive
 }
 
+export type Recurrence = 'daily' | 'weekdays' | { weekly: string[] };
+
 // Simple TaskInput for creating/updating tasks
 export interface TaskInput {
   title: string;
@@ -52,12 +70,14 @@ export interface TaskInput {
   tags: string[];
   notes?: string;
   pomodoro_duration?: number; // Optional: 5, 10, 15, 20, 25, 30, 45, 60, 90 minutes
+  long_break_every?: number; // Optional: long break after every N pomodoros (default 4)
 }
 
 export interface Schedule {
   date: string; // ISO datetime string
   tasks: Task[];
   changes: ScheduleChange[]; // Schedule change history
+  skipped_recurrences?: string[]; // Recurring tasks deleted from this day (not recreated)
   completion_rate?: number;
   efficiency_score?: number;
   total_earned?: number;
@@ -74,6 +94,30 @@ export interface DailySummary {
   total_wasted: number;
 }
 
+// How add/create treat overlapping tasks (defaults to 'reject')
+export type ConflictPolicy = 'reject' | 'shift' | 'allow';
+
+// Error from add_task/create_schedule
+export type AddTaskError =
+  | {
+      kind: 'conflict';
+      message: string;
+      conflict: {
+        task_title: string;
+        task_time: string;
+        existing_id: string;
+        existing_title: string;
+        existing_time: string;
+      };
+    }
+  | { kind: 'invalid'; message: string };
+
+// Error from the AI commands (ask_ai, get_task_advice, suggest_*, check_ai_provider)
+export interface AiCommandError {
+  kind: 'not_installed' | 'auth_required' | 'timeout' | 'rate_limited' | 'exec_failed' | 'parse_failed' | 'invalid';
+  message: string;
+}
+
 export interface TaskSuggestion {
   suggested_title: string;
   suggested_start_time: string;